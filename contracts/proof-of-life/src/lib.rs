@@ -6,17 +6,22 @@
 //! The verifier contract must be deployed with the circuit's VK.
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, Address, Bytes,
-    BytesN, Env, Vec, symbol_short,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address, Bytes,
+    BytesN, Env, Symbol, Vec, symbol_short, xdr::ToXdr,
 };
 
-#[cfg(test)]
+// The main suite drives most flows through insecure mode, which `strict` removes.
+#[cfg(all(test, not(feature = "strict")))]
 mod test;
 
+#[cfg(all(test, feature = "strict"))]
+mod strict_test;
+
 #[cfg(test)]
 mod encoding_test;
 
 mod floorplan;
+mod utils;
 
 mod ultrahonk_verifier {
     soroban_sdk::contractimport!(
@@ -45,6 +50,14 @@ pub trait GameHub {
     fn end_game(env: Env, session_id: u32, player1_won: bool);
 }
 
+/// VK metadata getters exposed by the UltraHonk verifier contract.
+#[contractclient(name = "VerifierMetaClient")]
+pub trait VerifierMeta {
+    fn vk_log_n(env: Env) -> u64;
+    fn vk_public_inputs(env: Env) -> u64;
+    fn expected_proof_len(env: Env) -> u64;
+}
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -85,6 +98,28 @@ pub enum Error {
     SessionKeyMethodNotAllowed = 30,
     SessionKeyWriteLimitExceeded = 31,
     SessionKeyRoleMismatch = 32,
+    NotSessionPlayer = 33,
+    CommitmentAlreadySet = 34,
+    InvalidProofKind = 38,
+    TimeoutNotReached = 39,
+    DistanceInconsistent = 40,
+    InvalidStartPosition = 41,
+    MoveCountMismatch = 42,
+    PublicInputArityMismatch = 43,
+    IllegalStep = 44,
+    StaleMoveCommitment = 45,
+    InvalidMethodMask = 46,
+    InsecureModeDisabled = 47,
+    FreeActionUsed = 48,
+    DistanceOutOfRange = 49,
+    NotReady = 50,
+    PingModeMismatch = 51,
+    VerifierCircuitMismatch = 52,
+    FloorplanMismatch = 53,
+    GameNotEnded = 54,
+    VerifierNotApproved = 55,
+    GameAlreadyStarted = 56,
+    OutcomeUnknown = 57,
 }
 
 // ============================================================================
@@ -98,6 +133,38 @@ pub enum TurnPhase {
     Assassin = 1,
 }
 
+/// Byte order of `u32` values inside 32-byte public inputs. bb emits big-endian;
+/// some circom/snarkjs pipelines emit little-endian.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Endian {
+    Big = 0,
+    Little = 1,
+}
+
+/// Why a game ended. Stored by `end_game_internal`; see `get_end_reason`.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EndReason {
+    Kill = 0,
+    AlphaDepleted = 1,
+    BatteryDepleted = 2,
+    Forfeit = 3,
+    Timeout = 4,
+    Draw = 5,
+    AdminForced = 6,
+}
+
+/// Result of a session as seen by clients; see `game_outcome`.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Ongoing = 0,
+    DispatcherWon = 1,
+    AssassinWon = 2,
+    Draw = 3,
+}
+
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Role {
@@ -105,6 +172,59 @@ pub enum Role {
     Assassin = 1,
 }
 
+/// Chad path trail for indexers, emitted after every Chad command (dispatch or
+/// recharge). Topics `("chad", "moved")`; data is the vec
+/// `[session_id, turn, chad_x, chad_y, command_tag]` with command_tag
+/// 0=Stay, 1=Hide, 2=GoRoom, 3=WalkGarden, 4=RunGarden. Keep this schema stable.
+#[contractevent(topics = ["chad", "moved"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChadMoved {
+    pub session_id: u32,
+    pub turn: u32,
+    pub chad_x: u32,
+    pub chad_y: u32,
+    pub command_tag: u32,
+}
+
+/// Mirror of a `GameHub::start_game` call, so integrations can be checked from
+/// events without a stateful hub. Topics `("hub_call", "start_game")`.
+#[contractevent(topics = ["hub_call", "start_game"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubStartGame {
+    pub session_id: u32,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+/// Mirror of a `GameHub::end_game` call. Topics `("hub_call", "end_game")`.
+#[contractevent(topics = ["hub_call", "end_game"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubEndGame {
+    pub session_id: u32,
+    pub player1_won: bool,
+}
+
+/// Reason `session_ready_for_secure_play` returned false: the verifier kinds
+/// (`ping`, `turn`, `move`) that secure play needs but cannot resolve.
+/// Topics `("secure", "not_ready")`.
+#[contractevent(topics = ["secure", "not_ready"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SecurePlayNotReady {
+    pub session_id: u32,
+    pub missing: Vec<Symbol>,
+}
+
+/// A `submit_multi_move_proof` batch stopped at `index` because that entry
+/// repeats the current commitment. Topics `("multi_move", "stale")`.
+#[contractevent(topics = ["multi_move", "stale"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiMoveStale {
+    pub session_id: u32,
+    pub index: u32,
+}
+
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ChadCommand {
@@ -112,6 +232,7 @@ pub enum ChadCommand {
     Hide,
     GoRoom(u32),
     WalkGarden(u32), // 0=N, 1=E, 2=S, 3=W
+    RunGarden(u32),  // two tiles, same directions as WalkGarden
 }
 
 #[contracttype]
@@ -141,7 +262,23 @@ pub struct Session {
     pub recharge_amount: u32,
     pub chad_hidden: bool,
     pub chad_hide_streak: u32,
+    pub free_action_used: bool,
+    pub consecutive_recharges: u32,
     pub insecure_mode: bool,
+    pub turn_timeout_ledgers: u32,
+    pub last_action_ledger: u32,
+    pub max_hide_streak: u32,
+    pub ready_check: bool,
+    pub dispatcher_ready: bool,
+    pub assassin_ready: bool,
+    pub distance_tolerance: u32,
+    pub commit_grace_turns: u32,
+    pub coarse_ping: bool,
+    pub ping_in_radius: Option<bool>,
+    pub walled_moves: bool,
+    pub endianness: Endian,
+    pub commitment_locked: bool,
+    pub alpha_regen: u32,
 }
 
 // Compact storage layout:
@@ -161,6 +298,32 @@ pub struct SessionCore {
     pub init_chad_x: u32,
     pub init_chad_y: u32,
     pub runtime_initialized: bool,
+    // 0 = disabled. See `claim_timeout`.
+    pub turn_timeout_ledgers: u32,
+    // Consecutive hides allowed. 0 disables hiding entirely (it does not mean unlimited).
+    pub max_hide_streak: u32,
+    // Battery spent per `grant_alpha` call.
+    pub grant_alpha_cost: u32,
+    // When set, the dispatcher may not act until both players called `mark_ready`.
+    pub ready_check: bool,
+    pub dispatcher_ready: bool,
+    pub assassin_ready: bool,
+    // Slack (in tiles) for the ping / turn-status triangle check. 0 disables it.
+    pub distance_tolerance: u32,
+    // Turns during which `commit_location` may replace the commitment. 0 = locked at once.
+    pub commit_grace_turns: u32,
+    // Pings reveal only whether the assassin is within `strong_radius_sq`.
+    pub coarse_ping: bool,
+    // Move proofs must come from the wall-aware circuit bound to `FLOORPLAN_ID`.
+    pub walled_moves: bool,
+    // How `u32` public inputs (session id, turn, coordinates, distances) are encoded.
+    pub endianness: Endian,
+    // Set by the first verified ping, move or turn-status proof, or by
+    // `finalize_commitment`; the assassin can no longer pick a new starting
+    // commitment (move proofs still update it).
+    pub commitment_locked: bool,
+    // Alpha regained on a weak turn-status signal (clamped to `alpha_max`). 0 = none.
+    pub alpha_regen: u32,
 }
 
 #[contracttype]
@@ -178,6 +341,20 @@ pub struct SessionRuntime {
     pub assassin_moves_this_turn: u32,
     pub chad_hidden: bool,
     pub chad_hide_streak: u32,
+    // Ledger sequence of the last phase change.
+    pub last_action_ledger: u32,
+    // Last accepted ping / turn-status distances (public outputs).
+    pub last_d2: Option<u32>,
+    pub last_d2_chad: Option<u32>,
+    // Tower and turn of the last accepted ping; its distance is `last_d2`.
+    pub last_ping_tower: Option<u32>,
+    pub last_ping_turn: u32,
+    // One battery-free dispatch per game. See `free_dispatch`.
+    pub free_action_used: bool,
+    // Recharges since the last dispatch; each one halves the next gain.
+    pub consecutive_recharges: u32,
+    // Result of the last coarse ping (no exact distance in that mode).
+    pub last_ping_in_radius: Option<bool>,
 }
 
 #[contracttype]
@@ -224,6 +401,57 @@ pub struct MoveProofEntry {
     pub public_inputs: Vec<BytesN<32>>,
 }
 
+/// Per-session verifier overrides; take precedence over the global `set_verifiers`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionVerifiers {
+    pub ping_v: Address,
+    pub turn_v: Address,
+    pub move_v: Address,
+    // Coarse-ping verifier; `None` falls back to the global `set_coarse_ping_verifier`.
+    pub coarse_ping_v: Option<Address>,
+}
+
+/// Board/scoring parameters accepted by `start_game_secure` (same as `start_game_ext`),
+/// plus the session's verifiers. Each must be approved by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub chad_x: u32,
+    pub chad_y: u32,
+    pub alpha_max: u32,
+    pub strong_radius_sq: u32,
+    pub verifiers: SessionVerifiers,
+}
+
+/// Entry of a player's session index. `ended_ledger` is set once the game ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerSession {
+    pub session_id: u32,
+    pub ended_ledger: Option<u32>,
+}
+
+/// A single owner action executed by `do_turn`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    CommitLocation(BytesN<32>),
+    Dispatch(u32, ChadCommand),
+    Recharge(ChadCommand),
+    AssassinTick,
+}
+
+/// A proof submission replayed by `submit_proof_once`. The first field is the
+/// acting assassin (owner or delegate); the rest mirror the direct entrypoints.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProofSubmission {
+    Ping(Address, u32, u32, Bytes, Vec<BytesN<32>>),
+    Move(Address, BytesN<32>, Bytes, Vec<BytesN<32>>),
+    TurnStatus(Address, u32, Bytes, Vec<BytesN<32>>),
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -234,7 +462,33 @@ pub enum DataKey {
     PingVerifier,
     TurnStatusVerifier,
     MoveVerifier,
+    // Verifier for the in-radius-only ping circuit (`submit_ping_proof_coarse`).
+    CoarsePingVerifier,
+    // Verifier for the wall-aware move circuit (sessions with `walled_moves`).
+    WalledMoveVerifier,
     SessionKeyScope(u32, Address, u32),
+    // Reverse index: delegate -> Vec<(session_id, role_u32)>.
+    DelegateScopes(Address),
+    // Reverse index: player -> Vec<PlayerSession>, either role.
+    PlayerSessions(Address),
+    SessionVerifiers(u32),
+    // Per-session tower snapshot; sessions without one follow `Towers`.
+    SessionTowers(u32),
+    EndReason(u32),
+    // Winner reported to the hub by `end_game_internal`.
+    DispatcherWon(u32),
+    // Audit trail: (session_id, turn, kind) -> verified.
+    ProofLog(u32, u32, u32),
+    // Client nonces applied by `submit_proof_once`: session_id -> (turn, nonces).
+    SubmissionNonces(u32),
+    // Result fingerprint left behind by `archive_session`.
+    SessionArchive(u32),
+    // Per-session bitset of closed doors: bit N closes `floorplan::DOORS_OPEN[N]`.
+    ClosedDoors(u32),
+    // Verifiers whose circuits absorb the proof kind as a transcript domain tag.
+    DomainTaggedVerifier(Address),
+    // Verifiers a `start_game_secure` session may use.
+    ApprovedVerifier(Address),
 }
 
 // ============================================================================
@@ -242,9 +496,43 @@ pub enum DataKey {
 // ============================================================================
 
 const PING_COST: u32 = 20;
+// Extra battery per room crossed beyond the first on a paid `GoRoom`.
+const ROOM_STEP_COST: u32 = 5;
+// Extra battery for a paid `RunGarden` on top of the ping.
+const RUN_EXTRA_COST: u32 = 10;
 const RECHARGE_AMOUNT: u32 = 10;
 const BATTERY_MAX: u32 = 100;
 const INITIAL_BATTERY: u32 = 100;
+const DEFAULT_MAX_HIDE_STREAK: u32 = 2;
+const DEFAULT_GRANT_ALPHA_COST: u32 = 20;
+const DEFAULT_ALPHA_REGEN: u32 = 1;
+// Nonces remembered per turn by `submit_proof_once`; the oldest is dropped first.
+const MAX_SUBMISSION_NONCES: u32 = 8;
+// Ended games stay in a player's session index this long (~1 day), then are pruned.
+const PLAYER_SESSION_TTL_LEDGERS: u32 = 17_280;
+
+// Proof kinds. A verifier opted in with `set_verifier_domain_tagged` also gets
+// the kind as its circuit domain, so one verifier can serve several slots
+// without a ping proof passing as a turn-status proof. Others verify in domain
+// 0, which is what the stock bb prover produces.
+const VERIFIER_PING: u32 = 0;
+const VERIFIER_TURN_STATUS: u32 = 1;
+const VERIFIER_MOVE: u32 = 2;
+
+// Public inputs each circuit exposes (see the layouts in the proof handlers).
+const PING_PUBLIC_INPUTS: u64 = 6;
+const TURN_STATUS_PUBLIC_INPUTS: u64 = 6;
+const MOVE_PUBLIC_INPUTS: u64 = 7;
+const COARSE_PING_PUBLIC_INPUTS: u64 = 7;
+// A VK may also count the 16-field pairing point object.
+const PAIRING_POINT_INPUTS: u64 = 16;
+// Move public input: the assassin's move count this turn, including the proven
+// step. Follows the floorplan slot (index 4).
+const MOVE_STEPS_CLAIMED_INDEX: u32 = 5;
+// Move public input: the Manhattan length of the proven step. Must be 1, or at
+// most the remaining move budget while Chad is hidden; the step costs that much.
+const MOVE_STEP_DISTANCE_INDEX: u32 = 6;
+
 const SESSION_METHOD_DISPATCH: u32 = 1 << 0;
 const SESSION_METHOD_RECHARGE: u32 = 1 << 1;
 const SESSION_METHOD_COMMIT_LOCATION: u32 = 1 << 2;
@@ -253,6 +541,27 @@ const SESSION_METHOD_SUBMIT_MOVE_PROOF: u32 = 1 << 4;
 const SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF: u32 = 1 << 5;
 const SESSION_METHOD_ASSASSIN_TICK: u32 = 1 << 6;
 const SESSION_METHOD_LOCK_SECURE_MODE: u32 = 1 << 7;
+const SESSION_METHOD_GRANT_ALPHA: u32 = 1 << 9;
+// Every defined method bit; a session key mask with any other bit set is rejected.
+const VALID_METHOD_MASK: u32 = SESSION_METHOD_DISPATCH
+    | SESSION_METHOD_RECHARGE
+    | SESSION_METHOD_COMMIT_LOCATION
+    | SESSION_METHOD_SUBMIT_PING_PROOF
+    | SESSION_METHOD_SUBMIT_MOVE_PROOF
+    | SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF
+    | SESSION_METHOD_ASSASSIN_TICK
+    | SESSION_METHOD_LOCK_SECURE_MODE
+    | SESSION_METHOD_GRANT_ALPHA;
+
+// Default masks for `authorize_dispatcher_key` / `authorize_assassin_key`: every
+// gameplay method of the role. `lock_secure_mode` stays with the owner.
+const DISPATCHER_DEFAULT_MASK: u32 =
+    SESSION_METHOD_DISPATCH | SESSION_METHOD_RECHARGE | SESSION_METHOD_GRANT_ALPHA;
+const ASSASSIN_DEFAULT_MASK: u32 = SESSION_METHOD_COMMIT_LOCATION
+    | SESSION_METHOD_SUBMIT_PING_PROOF
+    | SESSION_METHOD_SUBMIT_MOVE_PROOF
+    | SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF
+    | SESSION_METHOD_ASSASSIN_TICK;
 const DEFAULT_HUB_POINTS_DISPATCHER: i128 = 0;
 const DEFAULT_HUB_POINTS_ASSASSIN: i128 = 0;
 
@@ -294,24 +603,161 @@ impl ProofOfLife {
         env.storage().instance().get(&DataKey::Towers).unwrap()
     }
 
+    /// Coordinates of one global tower by id (0=N, 1=E, 2=S, 3=W), using the
+    /// same mapping as the ping path.
+    pub fn get_tower_coords(env: Env, tower_id: u32) -> Result<(u32, u32), Error> {
+        Self::tower_coords(&Self::get_towers(env), tower_id).ok_or(Error::UnexpectedTower)
+    }
+
+    /// Towers in effect for a session: its snapshot if it has one, else the global towers.
+    pub fn get_session_towers(env: Env, session_id: u32) -> Result<Towers, Error> {
+        Self::load_session_core(&env, session_id)?;
+        Ok(Self::session_towers(&env, session_id))
+    }
+
+    /// Admin upgrade path: snapshots the current global towers into a session
+    /// created without one. Later `set_towers` calls no longer affect it.
+    /// No-op when the session already has a snapshot.
+    pub fn backfill_session_towers(env: Env, session_id: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        Self::load_session_core(&env, session_id)?;
+        let key = DataKey::SessionTowers(session_id);
+        if env.storage().instance().has(&key) {
+            return Ok(());
+        }
+        env.storage().instance().set(&key, &Self::get_towers(env.clone()));
+        Ok(())
+    }
+
     pub fn set_towers(env: Env, towers: Towers) {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
         env.storage().instance().set(&DataKey::Towers, &towers);
     }
 
+    /// Rejects with `VerifierCircuitMismatch` a verifier whose VK reports a public
+    /// input count that does not fit the circuit it is wired as.
     pub fn set_verifiers(
         env: Env,
         ping_v: Address,
         turn_v: Address,
         move_v: Address,
-    ) {
+    ) -> Result<(), Error> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
+        Self::check_verifier_layout(&env, &ping_v, PING_PUBLIC_INPUTS)?;
+        Self::check_verifier_layout(&env, &turn_v, TURN_STATUS_PUBLIC_INPUTS)?;
+        Self::check_verifier_layout(&env, &move_v, MOVE_PUBLIC_INPUTS)?;
+
         env.storage().instance().set(&DataKey::PingVerifier, &ping_v);
         env.storage().instance().set(&DataKey::TurnStatusVerifier, &turn_v);
         env.storage().instance().set(&DataKey::MoveVerifier, &move_v);
+        Ok(())
+    }
+
+    /// Rotates only the ping verifier; the others are left as they are.
+    pub fn set_ping_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::set_single_verifier(&env, DataKey::PingVerifier, &verifier, PING_PUBLIC_INPUTS)
+    }
+
+    /// Rotates only the turn-status verifier; the others are left as they are.
+    pub fn set_turn_status_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::set_single_verifier(
+            &env,
+            DataKey::TurnStatusVerifier,
+            &verifier,
+            TURN_STATUS_PUBLIC_INPUTS,
+        )
+    }
+
+    /// Rotates only the move verifier; the others are left as they are.
+    pub fn set_move_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::set_single_verifier(&env, DataKey::MoveVerifier, &verifier, MOVE_PUBLIC_INPUTS)
+    }
+
+    /// Marks `verifier` as checking proofs in a per-kind circuit domain. Only
+    /// enable this for circuits whose prover absorbs the domain tag.
+    pub fn set_verifier_domain_tagged(env: Env, verifier: Address, enabled: bool) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let key = DataKey::DomainTaggedVerifier(verifier);
+        if enabled {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+    }
+
+    /// Allows or disallows `verifier` in `start_game_secure` sessions. Sessions
+    /// already started with it keep it.
+    pub fn set_verifier_approved(env: Env, verifier: Address, approved: bool) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let key = DataKey::ApprovedVerifier(verifier);
+        if approved {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+    }
+
+    pub fn is_verifier_approved(env: Env, verifier: Address) -> bool {
+        env.storage().instance().has(&DataKey::ApprovedVerifier(verifier))
+    }
+
+    /// Verifier for coarse pings. Public inputs:
+    /// `[tower_x, tower_y, session_id, turn, commitment, strong_radius_sq, in_radius]`.
+    pub fn set_coarse_ping_verifier(env: Env, verifier: Address) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::CoarsePingVerifier, &verifier);
+    }
+
+    /// Verifier for the wall-aware move circuit, which also proves the new tile is
+    /// walkable and reachable from the old one. Public inputs:
+    /// `[session_id, turn, commitment_old, commitment_new, floorplan_id, steps_claimed, step_distance]`.
+    pub fn set_walled_move_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::set_single_verifier(&env, DataKey::WalledMoveVerifier, &verifier, MOVE_PUBLIC_INPUTS)
+    }
+
+    /// Requires the session's move proofs to respect the floorplan walls.
+    pub fn set_walled_moves(env: Env, session_id: u32, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.walled_moves = enabled;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Byte order the session's provers use for `u32` public inputs.
+    pub fn set_endianness(env: Env, session_id: u32, endianness: Endian) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.endianness = endianness;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Switches the session between exact (`submit_ping_proof`) and coarse
+    /// (`submit_ping_proof_coarse`) pings.
+    pub fn set_coarse_ping(env: Env, session_id: u32, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.coarse_ping = enabled;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
     }
 
     pub fn authorize_session_key(
@@ -326,42 +772,103 @@ impl ProofOfLife {
     ) -> Result<(), Error> {
         owner.require_auth();
         let c = Self::load_session_core(&env, session_id)?;
-        let expires_ledger = env.ledger().sequence().saturating_add(ttl_ledgers);
+        let params = SessionKeyParams {
+            delegate,
+            ttl_ledgers,
+            max_writes,
+            dispatcher_allow_mask,
+            assassin_allow_mask,
+        };
+        Self::check_allow_masks(&params)?;
+        Self::store_owner_scopes(&env, &owner, &c, &params);
+        Ok(())
+    }
 
-        if owner == c.dispatcher && dispatcher_allow_mask != 0 {
-            let scope = SessionKeyScope {
-                owner: owner.clone(),
-                delegate: delegate.clone(),
-                session_id,
-                role: Role::Dispatcher,
-                expires_ledger,
-                max_writes,
-                writes_used: 0,
-                allow_mask: dispatcher_allow_mask,
-            };
-            Self::store_session_key_scope(&env, session_id, &owner, Role::Dispatcher, &scope);
+    /// `authorize_session_key` for the dispatcher with all dispatcher gameplay methods.
+    pub fn authorize_dispatcher_key(
+        env: Env,
+        owner: Address,
+        session_id: u32,
+        delegate: Address,
+        ttl_ledgers: u32,
+        max_writes: u32,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        let c = Self::load_session_core(&env, session_id)?;
+        if owner != c.dispatcher {
+            return Err(Error::NotDispatcher);
         }
+        let params = SessionKeyParams {
+            delegate,
+            ttl_ledgers,
+            max_writes,
+            dispatcher_allow_mask: DISPATCHER_DEFAULT_MASK,
+            assassin_allow_mask: 0,
+        };
+        Self::store_owner_scopes(&env, &owner, &c, &params);
+        Ok(())
+    }
 
-        if owner == c.assassin && assassin_allow_mask != 0 {
-            let scope = SessionKeyScope {
-                owner: owner.clone(),
-                delegate: delegate.clone(),
-                session_id,
-                role: Role::Assassin,
-                expires_ledger,
-                max_writes,
-                writes_used: 0,
-                allow_mask: assassin_allow_mask,
-            };
-            Self::store_session_key_scope(&env, session_id, &owner, Role::Assassin, &scope);
+    /// `authorize_session_key` for the assassin with all assassin gameplay methods.
+    pub fn authorize_assassin_key(
+        env: Env,
+        owner: Address,
+        session_id: u32,
+        delegate: Address,
+        ttl_ledgers: u32,
+        max_writes: u32,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        let c = Self::load_session_core(&env, session_id)?;
+        if owner != c.assassin {
+            return Err(Error::NotAssassin);
         }
+        let params = SessionKeyParams {
+            delegate,
+            ttl_ledgers,
+            max_writes,
+            dispatcher_allow_mask: 0,
+            assassin_allow_mask: ASSASSIN_DEFAULT_MASK,
+        };
+        Self::store_owner_scopes(&env, &owner, &c, &params);
+        Ok(())
+    }
 
+    /// Authorizes session keys for several sessions with a single owner signature.
+    /// Sessions where `owner` is neither player are skipped.
+    pub fn batch_authorize_session_keys(
+        env: Env,
+        owner: Address,
+        params: Vec<(u32, SessionKeyParams)>,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        for (session_id, p) in params.iter() {
+            let c = Self::load_session_core(&env, session_id)?;
+            if owner != c.dispatcher && owner != c.assassin {
+                continue;
+            }
+            Self::check_allow_masks(&p)?;
+            Self::store_owner_scopes(&env, &owner, &c, &p);
+        }
         Ok(())
     }
 
     pub fn revoke_session_key(env: Env, owner: Address, session_id: u32, role: Role) -> Result<(), Error> {
         owner.require_auth();
-        env.storage().instance().remove(&DataKey::SessionKeyScope(session_id, owner, Self::role_to_u32(role)));
+        let key = DataKey::SessionKeyScope(session_id, owner, Self::role_to_u32(role));
+        if let Some(old) = env.storage().instance().get::<_, SessionKeyScope>(&key) {
+            Self::unindex_delegate_scope(&env, &old.delegate, session_id, Self::role_to_u32(role));
+        }
+        env.storage().instance().remove(&key);
+        Ok(())
+    }
+
+    /// Restores a scope's write budget. Expiry and allowed methods are left as is.
+    pub fn reset_session_key_writes(env: Env, owner: Address, session_id: u32, role: Role) -> Result<(), Error> {
+        owner.require_auth();
+        let mut scope = Self::load_session_key_scope(&env, session_id, &owner, role)?;
+        scope.writes_used = 0;
+        Self::store_session_key_scope(&env, session_id, &owner, role, &scope);
         Ok(())
     }
 
@@ -369,6 +876,57 @@ impl ProofOfLife {
         env.storage().instance().get(&DataKey::SessionKeyScope(session_id, owner, Self::role_to_u32(role)))
     }
 
+    /// Like `get_session_key_scope`, but returns `None` once the scope has expired.
+    /// Read-only: expired entries stay in storage until revoked or swept.
+    pub fn get_active_session_key_scope(env: Env, owner: Address, session_id: u32, role: Role) -> Option<SessionKeyScope> {
+        let scope = Self::get_session_key_scope(env.clone(), owner, session_id, role)?;
+        if env.ledger().sequence() > scope.expires_ledger {
+            return None;
+        }
+        Some(scope)
+    }
+
+    /// Removes the owner's scope if it has expired. Returns whether anything was removed.
+    pub fn sweep_expired_session_key(env: Env, owner: Address, session_id: u32, role: Role) -> bool {
+        let key = DataKey::SessionKeyScope(session_id, owner, Self::role_to_u32(role));
+        let Some(scope) = env.storage().instance().get::<_, SessionKeyScope>(&key) else {
+            return false;
+        };
+        if env.ledger().sequence() <= scope.expires_ledger {
+            return false;
+        }
+        Self::unindex_delegate_scope(&env, &scope.delegate, session_id, Self::role_to_u32(role));
+        env.storage().instance().remove(&key);
+        true
+    }
+
+    /// Sessions `player` takes part in (either role), oldest first, so a client
+    /// can offer to resume them. Ended games are listed only with
+    /// `include_ended` and are pruned a while after they end.
+    pub fn get_player_sessions(env: Env, player: Address, include_ended: bool) -> Vec<u32> {
+        let entries: Vec<PlayerSession> = env
+            .storage()
+            .instance()
+            .get(&DataKey::PlayerSessions(player))
+            .unwrap_or(Vec::new(&env));
+        let mut out = Vec::new(&env);
+        for e in entries.iter() {
+            if include_ended || e.ended_ledger.is_none() {
+                out.push_back(e.session_id);
+            }
+        }
+        out
+    }
+
+    /// Lists `(session_id, role_u32)` pairs the delegate currently holds a scope for.
+    /// Expired scopes are still listed until revoked.
+    pub fn get_delegate_scopes(env: Env, delegate: Address) -> Vec<(u32, u32)> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DelegateScopes(delegate))
+            .unwrap_or(Vec::new(&env))
+    }
+
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -376,7 +934,7 @@ impl ProofOfLife {
         assassin: Address,
         alpha_max: i128,
         strong_radius_sq: i128,
-    ) {
+    ) -> Result<(), Error> {
         Self::start_game_ext(
             env,
             session_id,
@@ -386,9 +944,34 @@ impl ProofOfLife {
             7,
             alpha_max as u32,
             strong_radius_sq as u32,
-        );
+        )
+    }
+
+    /// `start_game` with the dispatcher's starting battery set explicitly
+    /// (clamped to `BATTERY_MAX`), for handicap games.
+    pub fn start_game_handicap(
+        env: Env,
+        session_id: u32,
+        dispatcher: Address,
+        assassin: Address,
+        alpha_max: i128,
+        strong_radius_sq: i128,
+        initial_battery: u32,
+    ) -> Result<(), Error> {
+        Self::start_game_internal(
+            env,
+            session_id,
+            dispatcher,
+            assassin,
+            4,
+            7,
+            alpha_max as u32,
+            strong_radius_sq as u32,
+            initial_battery.min(BATTERY_MAX),
+        )
     }
 
+    /// Rejects a start tile that is off the floorplan grid or blocked.
     pub fn start_game_ext(
         env: Env,
         session_id: u32,
@@ -398,48 +981,67 @@ impl ProofOfLife {
         chad_y: u32,
         alpha_max: u32,
         strong_radius_sq: u32,
-    ) {
-        // Hackathon requirement: register each session in the shared Game Hub.
-        let game_hub_addr: Address = env.storage().instance().get(&DataKey::GameHub).unwrap();
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &dispatcher,
-            &assassin,
-            &DEFAULT_HUB_POINTS_DISPATCHER,
-            &DEFAULT_HUB_POINTS_ASSASSIN,
-        );
-
-        let core = SessionCore {
+    ) -> Result<(), Error> {
+        Self::start_game_internal(
+            env,
             session_id,
             dispatcher,
             assassin,
-            commitment: None,
-            alpha_max,
-            strong_radius_sq,
-            insecure_mode: false, // 100% ZK verification enforced by default
-            init_chad_x: chad_x,
-            init_chad_y: chad_y,
-            runtime_initialized: true, // runtime initialized immediately
-        };
-        // Initialize runtime immediately so callers don't need a separate initialize_session_runtime call.
-        let runtime = SessionRuntime {
             chad_x,
             chad_y,
-            battery: INITIAL_BATTERY,
-            turn: 0,
-            phase: TurnPhase::Dispatcher,
-            ended: false,
-            alpha: alpha_max,
-            pending_ping_tower: None,
-            moved_this_turn: false,
-            assassin_moves_this_turn: 0,
-            chad_hidden: false,
-            chad_hide_streak: 0,
-        };
-        Self::store_session_runtime(&env, session_id, &runtime);
-        Self::store_session_core(&env, session_id, &core);
+            alpha_max,
+            strong_radius_sq,
+            INITIAL_BATTERY,
+        )
+    }
+
+    /// Starts a game with its own verifiers so it is playable in secure mode
+    /// without a global `set_verifiers`. Both players sign since the verifier
+    /// choice decides what counts as a valid proof for either side. Only
+    /// verifiers approved with `set_verifier_approved` are accepted, and each
+    /// must fit the circuit it is wired as.
+    pub fn start_game_secure(
+        env: Env,
+        session_id: u32,
+        dispatcher: Address,
+        assassin: Address,
+        config: GameConfig,
+    ) -> Result<(), Error> {
+        dispatcher.require_auth();
+        if assassin != dispatcher {
+            assassin.require_auth();
+        }
+
+        let v = &config.verifiers;
+        for (verifier, expected) in [
+            (&v.ping_v, PING_PUBLIC_INPUTS),
+            (&v.turn_v, TURN_STATUS_PUBLIC_INPUTS),
+            (&v.move_v, MOVE_PUBLIC_INPUTS),
+        ] {
+            if !Self::is_verifier_approved(env.clone(), verifier.clone()) {
+                return Err(Error::VerifierNotApproved);
+            }
+            Self::check_verifier_layout(&env, verifier, expected)?;
+        }
+        if let Some(verifier) = v.coarse_ping_v.as_ref() {
+            if !Self::is_verifier_approved(env.clone(), verifier.clone()) {
+                return Err(Error::VerifierNotApproved);
+            }
+            Self::check_verifier_layout(&env, verifier, COARSE_PING_PUBLIC_INPUTS)?;
+        }
+
+        Self::start_game_ext(
+            env.clone(),
+            session_id,
+            dispatcher,
+            assassin,
+            config.chad_x,
+            config.chad_y,
+            config.alpha_max,
+            config.strong_radius_sq,
+        )?;
+        env.storage().instance().set(&DataKey::SessionVerifiers(session_id), &config.verifiers);
+        Ok(())
     }
 
     /// Atomically creates a game session and authorizes a session key in one transaction.
@@ -450,9 +1052,10 @@ impl ProofOfLife {
         dispatcher: Address,
         assassin: Address,
         sk_params: SessionKeyParams,
-    ) {
+    ) -> Result<(), Error> {
         // Only the dispatcher needs to sign (one wallet popup).
         dispatcher.require_auth();
+        Self::check_allow_masks(&sk_params)?;
 
         // Create the game session (same as start_game_ext with defaults).
         Self::start_game_ext(
@@ -464,7 +1067,7 @@ impl ProofOfLife {
             7,
             5,  // alpha_max
             4,  // strong_radius_sq
-        );
+        )?;
 
         // Authorize the session key for the dispatcher role.
         let expires_ledger = env.ledger().sequence().saturating_add(sk_params.ttl_ledgers);
@@ -480,7 +1083,7 @@ impl ProofOfLife {
                 writes_used: 0,
                 allow_mask: sk_params.dispatcher_allow_mask,
             };
-            Self::store_session_key_scope(&env, session_id, &dispatcher, Role::Dispatcher, &scope);
+            Self::put_session_key_scope(&env, session_id, &dispatcher, Role::Dispatcher, &scope);
         }
 
         // If dispatcher == assassin (solo play), also authorize assassin role.
@@ -495,8 +1098,9 @@ impl ProofOfLife {
                 writes_used: 0,
                 allow_mask: sk_params.assassin_allow_mask,
             };
-            Self::store_session_key_scope(&env, session_id, &assassin, Role::Assassin, &scope);
+            Self::put_session_key_scope(&env, session_id, &assassin, Role::Assassin, &scope);
         }
+        Ok(())
     }
 
     pub fn initialize_session_runtime(
@@ -505,50 +1109,209 @@ impl ProofOfLife {
         dispatcher: Address,
     ) -> Result<(), Error> {
         let mut core = Self::load_session_core(&env, session_id)?;
-        Self::require_owner_or_delegate(
-            &env,
-            session_id,
-            &core.dispatcher,
-            &dispatcher,
-            Role::Dispatcher,
-            SESSION_METHOD_DISPATCH,
-        )?;
-        // require_owner_or_delegate already verified the actor is the registered dispatcher.
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if dispatcher == admin {
+            // Recovery path: the admin may initialize regardless of role.
+            admin.require_auth();
+        } else {
+            Self::require_owner_or_delegate(
+                &env,
+                session_id,
+                &core.dispatcher,
+                &dispatcher,
+                Role::Dispatcher,
+                SESSION_METHOD_DISPATCH,
+            )?;
+            // require_owner_or_delegate already verified the actor is the registered dispatcher.
+        }
         if core.runtime_initialized {
             return Ok(());
         }
 
-        let runtime = SessionRuntime {
-            chad_x: core.init_chad_x,
-            chad_y: core.init_chad_y,
-            battery: INITIAL_BATTERY,
-            turn: 0,
-            phase: TurnPhase::Dispatcher,
-            ended: false,
-            alpha: core.alpha_max,
-            pending_ping_tower: None,
-            moved_this_turn: false,
-            assassin_moves_this_turn: 0,
-            chad_hidden: false,
-            chad_hide_streak: 0,
-        };
+        Self::store_session_runtime(&env, session_id, &Self::fresh_runtime(&env, &core));
+        core.runtime_initialized = true;
+        Self::store_session_core(&env, session_id, &core);
+        Ok(())
+    }
 
-        Self::store_session_runtime(&env, session_id, &runtime);
+    /// Admin recovery: rebuilds a missing runtime from the core's initial state.
+    /// No-op when the runtime exists.
+    pub fn admin_repair_runtime(env: Env, session_id: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut core = Self::load_session_core(&env, session_id)?;
+        if Self::load_session_runtime_opt(&env, session_id).is_some() {
+            return Ok(());
+        }
+        Self::store_session_runtime(&env, session_id, &Self::fresh_runtime(&env, &core));
         core.runtime_initialized = true;
         Self::store_session_core(&env, session_id, &core);
         Ok(())
     }
 
+    /// Turning verification off needs both players' consent on top of the
+    /// admin's; turning it back on is admin-only.
     pub fn set_insecure_mode(env: Env, session_id: u32, enabled: bool) -> Result<(), Error> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
         let mut c = Self::load_session_core(&env, session_id)?;
+        if enabled && cfg!(feature = "strict") {
+            return Err(Error::InsecureModeDisabled);
+        }
+        if enabled {
+            c.dispatcher.require_auth();
+            if c.assassin != c.dispatcher {
+                c.assassin.require_auth();
+            }
+        }
         c.insecure_mode = enabled;
         Self::store_session_core(&env, session_id, &c);
         Ok(())
     }
 
+    /// Admin live-balancing knob: radius (squared) under which a turn-status
+    /// distance counts as strong. Takes effect from the next turn-status proof.
+    pub fn set_strong_radius_sq(env: Env, session_id: u32, value: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let (mut c, s) = Self::load_session_pair(&env, session_id)?;
+        Self::ensure_not_ended(&s)?;
+        c.strong_radius_sq = value;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Admin difficulty knob: alpha regained per weak turn-status signal.
+    /// 0 turns regeneration off.
+    pub fn set_alpha_regen(env: Env, session_id: u32, value: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let (mut c, s) = Self::load_session_pair(&env, session_id)?;
+        Self::ensure_not_ended(&s)?;
+        c.alpha_regen = value;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Sets the battery cost of `grant_alpha` for a session.
+    pub fn set_grant_alpha_cost(env: Env, session_id: u32, cost: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.grant_alpha_cost = cost;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Requires both players to `mark_ready` before the dispatcher can act.
+    pub fn set_ready_check(env: Env, session_id: u32, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.ready_check = enabled;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Marks `player` ready. The assassin must have committed a location first.
+    pub fn mark_ready(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let mut c = Self::load_session_core(&env, session_id)?;
+        if player != c.dispatcher && player != c.assassin {
+            return Err(Error::NotSessionPlayer);
+        }
+        player.require_auth();
+
+        if player == c.assassin {
+            if c.commitment.is_none() {
+                return Err(Error::CommitmentNotSet);
+            }
+            c.assassin_ready = true;
+        }
+        if player == c.dispatcher {
+            c.dispatcher_ready = true;
+        }
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Enables the ping / turn-status consistency check with `tolerance` tiles of
+    /// slack (covers the assassin's moves after the ping and sqrt rounding). 0 disables it.
+    pub fn set_distance_tolerance(env: Env, session_id: u32, tolerance: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.distance_tolerance = tolerance;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Lets `commit_location` overwrite the commitment while `turn < turns`.
+    pub fn set_commit_grace_turns(env: Env, session_id: u32, turns: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.commit_grace_turns = turns;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Sets the per-phase deadline (in ledgers) used by `claim_timeout`. 0 disables it.
+    pub fn set_turn_timeout(env: Env, session_id: u32, timeout_ledgers: u32) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut c = Self::load_session_core(&env, session_id)?;
+        c.turn_timeout_ledgers = timeout_ledgers;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Ends the game in the claimant's favor when the opponent has held the
+    /// current phase for more than `turn_timeout_ledgers` ledgers.
+    pub fn claim_timeout(env: Env, session_id: u32, claimant: Address) -> Result<(), Error> {
+        let (c, mut s) = Self::load_session_pair(&env, session_id)?;
+        let opponent_phase = if claimant == c.dispatcher {
+            TurnPhase::Assassin
+        } else if claimant == c.assassin {
+            TurnPhase::Dispatcher
+        } else {
+            return Err(Error::NotSessionPlayer);
+        };
+        claimant.require_auth();
+        Self::ensure_not_ended(&s)?;
+
+        let elapsed = env.ledger().sequence().saturating_sub(s.last_action_ledger);
+        if c.turn_timeout_ledgers == 0 || s.phase != opponent_phase || elapsed <= c.turn_timeout_ledgers {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let dispatcher_won = opponent_phase == TurnPhase::Assassin;
+        Self::end_game_internal(&env, session_id, &mut s, dispatcher_won, EndReason::Timeout)?;
+        Self::store_session_runtime(&env, session_id, &s);
+        Ok(())
+    }
+
+    /// Emergency escape hatch: the admin ends a stuck session and reports
+    /// `dispatcher_won` to the hub.
+    pub fn admin_force_end(env: Env, session_id: u32, dispatcher_won: bool) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let (_c, mut s) = Self::load_session_pair(&env, session_id)?;
+        Self::ensure_not_ended(&s)?;
+        Self::end_game_internal(&env, session_id, &mut s, dispatcher_won, EndReason::AdminForced)?;
+        Self::store_session_runtime(&env, session_id, &s);
+        Ok(())
+    }
+
     /// Dispatcher can permanently lock a session into secure mode.
     /// This is safe because it only allows disabling insecure mode (never enabling it).
     pub fn lock_secure_mode(env: Env, session_id: u32, dispatcher: Address) -> Result<(), Error> {
@@ -568,9 +1331,53 @@ impl ProofOfLife {
         Ok(())
     }
 
+    /// Dispatcher closes door `door_index` of `floorplan::DOORS_OPEN`, or reopens
+    /// it if already closed. `GoRoom` routes and runs avoid closed doors.
+    pub fn toggle_door(env: Env, session_id: u32, dispatcher: Address, door_index: u32) -> Result<(), Error> {
+        let (c, s) = Self::load_session_pair(&env, session_id)?;
+        if dispatcher != c.dispatcher {
+            return Err(Error::NotDispatcher);
+        }
+        dispatcher.require_auth();
+        Self::ensure_not_ended(&s)?;
+        if door_index as usize >= floorplan::DOORS_OPEN.len() {
+            return Err(Error::InvalidRoomTransition);
+        }
+
+        let closed = Self::closed_doors(&env, session_id) ^ (1 << door_index);
+        env.storage().instance().set(&DataKey::ClosedDoors(session_id), &closed);
+        Ok(())
+    }
+
+    /// Bitset of closed doors (bit N closes `floorplan::DOORS_OPEN[N]`).
+    pub fn get_closed_doors(env: Env, session_id: u32) -> u32 {
+        Self::closed_doors(&env, session_id)
+    }
+
+    /// Dispatcher sets how many consecutive turns Chad may hide. 0 disables hiding.
+    /// Only before the first dispatch, so the limit cannot shift mid-game.
+    pub fn set_max_hide_streak(env: Env, session_id: u32, dispatcher: Address, max_hide_streak: u32) -> Result<(), Error> {
+        let (mut c, s) = Self::load_session_pair(&env, session_id)?;
+        if dispatcher != c.dispatcher {
+            return Err(Error::NotDispatcher);
+        }
+        dispatcher.require_auth();
+        Self::ensure_not_ended(&s)?;
+        if s.turn != 0 || s.phase != TurnPhase::Dispatcher {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        c.max_hide_streak = max_hide_streak;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
     pub fn hide(env: Env, session_id: u32, commitment: BytesN<32>) -> Result<(), Error> {
         let (mut c, r) = Self::load_session_pair(&env, session_id)?;
         Self::ensure_not_ended(&r)?;
+        if c.commitment_locked {
+            return Err(Error::CommitmentMismatch);
+        }
 
         if c.commitment.is_some() {
             return Err(Error::InvalidHide);
@@ -596,27 +1403,88 @@ impl ProofOfLife {
             Role::Dispatcher,
             SESSION_METHOD_DISPATCH,
         )?;
-        Self::ensure_not_ended(&s)?;
-
         // require_owner_or_delegate already verified the actor is the registered dispatcher.
+        let closed = Self::closed_doors(&env, session_id);
+        Self::apply_dispatch(&c, &mut s, closed, tower_id, command, PING_COST)?;
+        Self::emit_chad_moved(&env, session_id, &s, command);
+        if s.ended {
+            Self::record_end_reason(&env, session_id, EndReason::BatteryDepleted);
+        }
+        s.last_action_ledger = env.ledger().sequence();
+        Self::store_session_runtime(&env, session_id, &s);
+        Ok(())
+    }
+
+    /// Same as `dispatch` but spends no battery. Usable once per game.
+    pub fn free_dispatch(
+        env: Env,
+        session_id: u32,
+        dispatcher: Address,
+        tower_id: u32,
+        command: ChadCommand,
+    ) -> Result<(), Error> {
+        let (c, mut s) = Self::load_session_pair(&env, session_id)?;
+        Self::require_owner_or_delegate(
+            &env,
+            session_id,
+            &c.dispatcher,
+            &dispatcher,
+            Role::Dispatcher,
+            SESSION_METHOD_DISPATCH,
+        )?;
+        if s.free_action_used {
+            return Err(Error::FreeActionUsed);
+        }
+        let closed = Self::closed_doors(&env, session_id);
+        Self::apply_dispatch(&c, &mut s, closed, tower_id, command, 0)?;
+        Self::emit_chad_moved(&env, session_id, &s, command);
+        if s.ended {
+            Self::record_end_reason(&env, session_id, EndReason::BatteryDepleted);
+        }
+        s.free_action_used = true;
+        s.last_action_ledger = env.ledger().sequence();
+        Self::store_session_runtime(&env, session_id, &s);
+        Ok(())
+    }
+
+    /// Where `command` would put Chad if dispatched now. Read-only: no battery
+    /// is spent and nothing is stored.
+    pub fn preview_dispatch(env: Env, session_id: u32, command: ChadCommand) -> Result<(u32, u32), Error> {
+        let (c, mut s) = Self::load_session_pair(&env, session_id)?;
+        let closed = Self::closed_doors(&env, session_id);
+        Self::apply_chad_command(&mut s, c.max_hide_streak, closed, command)?;
+        Ok((s.chad_x, s.chad_y))
+    }
+
+    /// Paid pings the current battery still covers, so a UI can warn before
+    /// `BatteryDepleted`. 0 once the battery is below one ping's cost.
+    pub fn battery_turns_remaining(env: Env, session_id: u32) -> Result<u32, Error> {
+        let (_, s) = Self::load_session_pair(&env, session_id)?;
+        Ok(s.battery / PING_COST)
+    }
+
+    /// Dispatcher power-up: spends `grant_alpha_cost` battery to restore
+    /// `amount` alpha, clamped to `alpha_max`. Only during the dispatcher phase.
+    pub fn grant_alpha(env: Env, session_id: u32, dispatcher: Address, amount: u32) -> Result<(), Error> {
+        let (c, mut s) = Self::load_session_pair(&env, session_id)?;
+        Self::require_owner_or_delegate(
+            &env,
+            session_id,
+            &c.dispatcher,
+            &dispatcher,
+            Role::Dispatcher,
+            SESSION_METHOD_GRANT_ALPHA,
+        )?;
+        Self::ensure_not_ended(&s)?;
         if s.phase != TurnPhase::Dispatcher {
             return Err(Error::NotDispatcherTurn);
         }
-        
-        if s.battery < PING_COST {
+        if s.battery < c.grant_alpha_cost {
             return Err(Error::BatteryTooLow);
         }
-        s.battery -= PING_COST;
 
-        Self::apply_chad_command(&mut s, command)?;
-
-        s.pending_ping_tower = Some(tower_id);
-        s.phase = TurnPhase::Assassin;
-        s.moved_this_turn = false;
-        s.assassin_moves_this_turn = 0;
-        if s.battery == 0 {
-            s.ended = true;
-        }
+        s.battery -= c.grant_alpha_cost;
+        s.alpha = s.alpha.saturating_add(amount).min(c.alpha_max);
         Self::store_session_runtime(&env, session_id, &s);
         Ok(())
     }
@@ -648,128 +1516,152 @@ impl ProofOfLife {
         if s.pending_ping_tower != Some(tower_id) {
             return Err(Error::UnexpectedTower);
         }
+        if c.coarse_ping {
+            return Err(Error::PingModeMismatch);
+        }
+        // A secure ping locks in the proof's commitment; without a proof there
+        // is nothing to lock, and the first move proof would need one.
+        if Self::is_insecure(&c) && c.commitment.is_none() {
+            return Err(Error::CommitmentNotSet);
+        }
 
-        if !c.insecure_mode {
+        if !Self::is_insecure(&c) {
             // Cheap tower_id range check before any PI parsing.
-            let towers: Towers = env.storage().instance().get(&DataKey::Towers).unwrap();
-            let (tx, ty) = match tower_id {
-                0 => (towers.n_x, towers.n_y),
-                1 => (towers.e_x, towers.e_y),
-                2 => (towers.s_x, towers.s_y),
-                3 => (towers.w_x, towers.w_y),
-                _ => return Err(Error::UnexpectedTower),
-            };
+            let towers = Self::session_towers(&env, session_id);
+            let (tx, ty) = Self::tower_coords(&towers, tower_id).ok_or(Error::UnexpectedTower)?;
 
             // v3 layout: [tower_x, tower_y, session_id, turn, commitment, d2]
-            Self::verify_session_turn(&public_inputs, session_id, s.turn, 2, 3)?;
-            Self::verify_u32_field(&public_inputs, 0, tx, Error::UnexpectedTower)?;
-            Self::verify_u32_field(&public_inputs, 1, ty, Error::UnexpectedTower)?;
+            Self::check_arity(&public_inputs, PING_PUBLIC_INPUTS)?;
+            Self::verify_session_turn(&public_inputs, c.endianness, session_id, s.turn, 2, 3)?;
+            Self::verify_u32_field(&public_inputs, 0, tx, c.endianness, Error::UnexpectedTower)?;
+            Self::verify_u32_field(&public_inputs, 1, ty, c.endianness, Error::UnexpectedTower)?;
 
             // Commitment is a public output. The first verified ping locks it in for the session.
             let pi_cmt = public_inputs.get(4).ok_or(Error::CommitmentMismatch)?;
             if let Some(existing) = c.commitment.as_ref() {
-                if pi_cmt != *existing {
+                if !utils::ct_eq_bytes32(&pi_cmt, existing) {
                     return Err(Error::CommitmentMismatch);
                 }
             }
 
-            Self::verify_u32_field(&public_inputs, 5, d2, Error::D2Mismatch)?;
-            let verifier_addr: Address = env.storage().instance().get(&DataKey::PingVerifier).ok_or(Error::VerifierNotSet)?;
-            let verifier = UltraHonkClient::new(&env, &verifier_addr);
-            let mut pis = Bytes::new(&env);
-            for pi in public_inputs.iter() { pis.append(&pi.into()); }
-            let vr = verifier.try_verify_proof(&pis, &proof);
-            match vr {
-                Ok(Ok(())) => {}
-                _ => return Err(Error::InvalidProof),
+            Self::verify_u32_field(&public_inputs, 5, d2, c.endianness, Error::D2Mismatch)?;
+            let verifier_addr = Self::load_verifier(&env, session_id, VERIFIER_PING)?;
+            if !Self::call_verifier(&env, &verifier_addr, &public_inputs, &proof, VERIFIER_PING) {
+                return Err(Error::InvalidProof);
             }
 
             if c.commitment.is_none() {
                 c.commitment = Some(pi_cmt);
             }
+            c.commitment_locked = true;
         }
 
         s.pending_ping_tower = None;
+        s.last_d2 = Some(d2);
+        s.last_ping_in_radius = None;
+        s.last_ping_tower = Some(tower_id);
+        s.last_ping_turn = s.turn;
         Self::store_session_runtime(&env, session_id, &s);
         Self::store_session_core(&env, session_id, &c);
         Ok(d2)
     }
 
-    pub fn submit_turn_status_proof(
+    /// Answers the pending ping with only whether the assassin is within
+    /// `strong_radius_sq` of the tower. Requires `coarse_ping` on the session.
+    pub fn submit_ping_proof_coarse(
         env: Env,
         session_id: u32,
         assassin: Address,
-        d2_chad: u32,
+        tower_id: u32,
+        in_radius: bool,
         proof: Bytes,
         public_inputs: Vec<BytesN<32>>,
-    ) -> Result<u32, Error> {
-        let (c, mut s) = Self::load_session_pair(&env, session_id)?;
+    ) -> Result<bool, Error> {
+        let (mut c, mut s) = Self::load_session_pair(&env, session_id)?;
         Self::require_owner_or_delegate(
             &env,
             session_id,
             &c.assassin,
             &assassin,
             Role::Assassin,
-            SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF,
+            SESSION_METHOD_SUBMIT_PING_PROOF,
         )?;
         Self::ensure_not_ended(&s)?;
 
-        // require_owner_or_delegate already verified the actor is the registered assassin.
         if s.phase != TurnPhase::Assassin {
             return Err(Error::NotAssassinTurn);
         }
-        if s.assassin_moves_this_turn == 0 {
-            return Err(Error::AssassinMustMove);
+        if s.pending_ping_tower != Some(tower_id) {
+            return Err(Error::UnexpectedTower);
+        }
+        if !c.coarse_ping {
+            return Err(Error::PingModeMismatch);
+        }
+        if Self::is_insecure(&c) && c.commitment.is_none() {
+            return Err(Error::CommitmentNotSet);
         }
 
-        if !c.insecure_mode {
-            let commitment = c.commitment.as_ref().ok_or(Error::CommitmentNotSet)?;
-            // v3 layout: [cx, cy, session_id, turn, commitment, d2_chad]
-            Self::verify_session_turn(&public_inputs, session_id, s.turn, 2, 3)?;
+        if !Self::is_insecure(&c) {
+            let towers = Self::session_towers(&env, session_id);
+            let (tx, ty) = Self::tower_coords(&towers, tower_id).ok_or(Error::UnexpectedTower)?;
+
+            // Coarse layout: [tower_x, tower_y, session_id, turn, commitment, strong_radius_sq, in_radius]
+            Self::check_arity(&public_inputs, COARSE_PING_PUBLIC_INPUTS)?;
+            Self::verify_session_turn(&public_inputs, c.endianness, session_id, s.turn, 2, 3)?;
+            Self::verify_u32_field(&public_inputs, 0, tx, c.endianness, Error::UnexpectedTower)?;
+            Self::verify_u32_field(&public_inputs, 1, ty, c.endianness, Error::UnexpectedTower)?;
 
-            // Bind the statement to the on-chain Chad location so the assassin can't choose a fake (cx,cy).
-            Self::verify_u32_field(&public_inputs, 0, s.chad_x, Error::ChadCoordMismatch)?;
-            Self::verify_u32_field(&public_inputs, 1, s.chad_y, Error::ChadCoordMismatch)?;
             let pi_cmt = public_inputs.get(4).ok_or(Error::CommitmentMismatch)?;
-            if pi_cmt != *commitment {
-                return Err(Error::CommitmentMismatch);
+            if let Some(existing) = c.commitment.as_ref() {
+                if !utils::ct_eq_bytes32(&pi_cmt, existing) {
+                    return Err(Error::CommitmentMismatch);
+                }
+            }
+
+            Self::verify_u32_field(&public_inputs, 5, c.strong_radius_sq, c.endianness, Error::D2Mismatch)?;
+            Self::verify_u32_field(&public_inputs, 6, in_radius as u32, c.endianness, Error::D2Mismatch)?;
+            let verifier_addr = Self::load_coarse_ping_verifier(&env, session_id)?;
+            if !Self::call_verifier(&env, &verifier_addr, &public_inputs, &proof, VERIFIER_PING) {
+                return Err(Error::InvalidProof);
             }
-            Self::verify_u32_field(&public_inputs, 5, d2_chad, Error::D2ChadMismatch)?;
-            let verifier_addr: Address = env.storage().instance().get(&DataKey::TurnStatusVerifier).ok_or(Error::VerifierNotSet)?;
-            let verifier = UltraHonkClient::new(&env, &verifier_addr);
-            let mut pis = Bytes::new(&env);
-            for pi in public_inputs.iter() { pis.append(&pi.into()); }
-            let vr = verifier.try_verify_proof(&pis, &proof);
-            match vr {
-                Ok(Ok(())) => {}
-                _ => return Err(Error::InvalidProof),
+
+            if c.commitment.is_none() {
+                c.commitment = Some(pi_cmt);
             }
+            c.commitment_locked = true;
         }
 
-        if d2_chad == 0 {
-            Self::end_game_internal(&env, session_id, &mut s, false)?;
-        } else {
-            if d2_chad <= c.strong_radius_sq {
-                s.alpha = s.alpha.saturating_sub(1);
-            } else {
-                s.alpha = (s.alpha + 1).min(c.alpha_max);
-            }
-
-            if s.alpha == 0 {
-                Self::end_game_internal(&env, session_id, &mut s, false)?;
-            } else {
-                if s.battery == 0 {
-                    Self::end_game_internal(&env, session_id, &mut s, true)?;
-                } else {
-                    s.turn = s.turn.saturating_add(1);
-                    s.phase = TurnPhase::Dispatcher;
-                    s.moved_this_turn = false;
-                    s.assassin_moves_this_turn = 0;
-                }
-            }
-        }
+        s.pending_ping_tower = None;
+        s.last_d2 = None;
+        s.last_ping_in_radius = Some(in_radius);
+        s.last_ping_tower = Some(tower_id);
+        s.last_ping_turn = s.turn;
+        Self::store_session_runtime(&env, session_id, &s);
+        Self::store_session_core(&env, session_id, &c);
+        Ok(in_radius)
+    }
 
+    pub fn submit_turn_status_proof(
+        env: Env,
+        session_id: u32,
+        assassin: Address,
+        d2_chad: u32,
+        proof: Bytes,
+        public_inputs: Vec<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        let (mut c, mut s) = Self::load_session_pair(&env, session_id)?;
+        Self::require_owner_or_delegate(
+            &env,
+            session_id,
+            &c.assassin,
+            &assassin,
+            Role::Assassin,
+            SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF,
+        )?;
+        // require_owner_or_delegate already verified the actor is the registered assassin.
+        Self::apply_turn_status(&env, &mut c, &mut s, session_id, d2_chad, &proof, &public_inputs)?;
         Self::store_session_runtime(&env, session_id, &s);
+        Self::store_session_core(&env, session_id, &c);
         Ok(d2_chad)
     }
 
@@ -790,52 +1682,8 @@ impl ProofOfLife {
             Role::Assassin,
             SESSION_METHOD_SUBMIT_MOVE_PROOF,
         )?;
-        Self::ensure_not_ended(&s)?;
-
         // require_owner_or_delegate already verified the actor is the registered assassin.
-        if s.phase != TurnPhase::Assassin {
-            return Err(Error::NotAssassinTurn);
-        }
-        if s.pending_ping_tower.is_some() {
-            return Err(Error::PendingPingExists);
-        }
-        let max_moves = if s.chad_hidden { 6 } else { 1 };
-        if s.assassin_moves_this_turn >= max_moves {
-            return Err(Error::AlreadyMovedThisTurn);
-        }
-
-        if !c.insecure_mode {
-            let old_commitment = c.commitment.as_ref().ok_or(Error::CommitmentNotSet)?;
-            // UltraHonk (`bb --output_format bytes_and_fields`) public field ordering:
-            // [session_id, turn, commitment_old, commitment_new]
-            Self::verify_session_turn(&public_inputs, session_id, s.turn, 0, 1)?;
-
-            let pi_old: BytesN<32> = public_inputs.get_unchecked(2);
-            if pi_old != *old_commitment {
-                return Err(Error::CommitmentMismatch);
-            }
-            let pi_new: BytesN<32> = public_inputs.get_unchecked(3);
-            if pi_new != new_commitment {
-                return Err(Error::CommitmentMismatch);
-            }
-
-            let verifier_addr: Address = env.storage().instance().get(&DataKey::MoveVerifier).ok_or(Error::VerifierNotSet)?;
-            let verifier = UltraHonkClient::new(&env, &verifier_addr);
-            
-            let mut pis = Bytes::new(&env);
-            for pi in public_inputs.iter() {
-                pis.append(&pi.into());
-            }
-            let vr = verifier.try_verify_proof(&pis, &proof);
-            match vr {
-                Ok(Ok(())) => {}
-                _ => return Err(Error::InvalidProof),
-            }
-        }
-
-        c.commitment = Some(new_commitment);
-        s.moved_this_turn = true;
-        s.assassin_moves_this_turn = s.assassin_moves_this_turn.saturating_add(1);
+        Self::apply_move_proof(&env, &mut c, &mut s, session_id, new_commitment, &proof, &public_inputs)?;
         Self::store_session_runtime(&env, session_id, &s);
         Self::store_session_core(&env, session_id, &c);
         Ok(())
@@ -844,12 +1692,21 @@ impl ProofOfLife {
     /// Submit multiple move proofs in a single transaction.
     /// Each entry chains: the first entry uses the session's current commitment,
     /// and each subsequent entry uses the previous entry's new_commitment.
+    ///
+    /// Entries are applied (and persisted) one by one. Processing stops without
+    /// erroring at the first entry that fails verification or once the move budget
+    /// is exhausted. Returns the number of applied entries; callers resume from
+    /// `entries[count]`.
+    ///
+    /// An entry that repeats the current commitment is not a move: as the first
+    /// entry it fails with `StaleMoveCommitment`, later it ends the batch and
+    /// publishes `MultiMoveStale` with its index.
     pub fn submit_multi_move_proof(
         env: Env,
         session_id: u32,
         assassin: Address,
         entries: Vec<MoveProofEntry>,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         let (mut c, mut s) = Self::load_session_pair(&env, session_id)?;
         Self::require_owner_or_delegate(
             &env,
@@ -868,59 +1725,186 @@ impl ProofOfLife {
             return Err(Error::PendingPingExists);
         }
 
-        let max_moves = if s.chad_hidden { 6 } else { 1 };
-        let n_entries = entries.len();
+        let max_moves = Self::max_moves_this_turn(&s);
+        if s.assassin_moves_this_turn >= max_moves {
+            return Err(Error::AlreadyMovedThisTurn);
+        }
 
-        for i in 0..n_entries {
+        let mut applied = 0u32;
+        for entry in entries.iter() {
             if s.assassin_moves_this_turn >= max_moves {
-                return Err(Error::AlreadyMovedThisTurn);
+                break;
+            }
+            // An entry that lands on the current commitment is not a move.
+            if c.commitment.as_ref() == Some(&entry.new_commitment) {
+                if applied == 0 {
+                    return Err(Error::StaleMoveCommitment);
+                }
+                MultiMoveStale { session_id, index: applied }.publish(&env);
+                break;
             }
 
-            let entry: MoveProofEntry = entries.get_unchecked(i);
+            let checked = if Self::is_insecure(&c) {
+                Self::check_move_step(&c, &s, &entry.public_inputs)
+            } else {
+                Self::verify_move_proof(
+                    &env,
+                    &c,
+                    &s,
+                    session_id,
+                    &entry.new_commitment,
+                    &entry.proof,
+                    &entry.public_inputs,
+                )
+            };
+            let distance = match checked {
+                Ok(d) => d,
+                // Misconfiguration is not entry-specific; surface it.
+                Err(e @ (Error::CommitmentNotSet | Error::VerifierNotSet)) => return Err(e),
+                Err(_) => break,
+            };
 
-            if !c.insecure_mode {
-                let old_commitment = c.commitment.as_ref().ok_or(Error::CommitmentNotSet)?;
-                Self::verify_session_turn(&entry.public_inputs, session_id, s.turn, 0, 1)?;
+            // Chain: update commitment for the next entry.
+            if !Self::is_insecure(&c) {
+                c.commitment_locked = true;
+            }
+            c.commitment = Some(entry.new_commitment);
+            s.moved_this_turn = true;
+            s.assassin_moves_this_turn = s.assassin_moves_this_turn.saturating_add(distance);
+            Self::store_session_runtime(&env, session_id, &s);
+            Self::store_session_core(&env, session_id, &c);
+            applied += 1;
+        }
 
-                let pi_old: BytesN<32> = entry.public_inputs.get_unchecked(2);
-                if pi_old != *old_commitment {
-                    return Err(Error::CommitmentMismatch);
-                }
-                let pi_new: BytesN<32> = entry.public_inputs.get_unchecked(3);
-                if pi_new != entry.new_commitment {
-                    return Err(Error::CommitmentMismatch);
-                }
+        Ok(applied)
+    }
 
-                let verifier_addr: Address = env.storage().instance().get(&DataKey::MoveVerifier).ok_or(Error::VerifierNotSet)?;
-                let verifier = UltraHonkClient::new(&env, &verifier_addr);
+    /// Idempotent proof submission for clients that retry on flaky RPC.
+    /// Returns `Ok(false)` without re-applying when `nonce` was already applied
+    /// this turn, `Ok(true)` when the submission was applied now.
+    /// A turn-status proof records its nonce under the turn it advanced to, so
+    /// its retry is still recognised.
+    pub fn submit_proof_once(env: Env, session_id: u32, nonce: u64, submission: ProofSubmission) -> Result<bool, Error> {
+        let (_c, s) = Self::load_session_pair(&env, session_id)?;
+        let key = DataKey::SubmissionNonces(session_id);
+        let seen: Option<(u32, Vec<u64>)> = env.storage().instance().get(&key);
+        if let Some((turn, nonces)) = seen.as_ref() {
+            if *turn == s.turn && nonces.contains(nonce) {
+                return Ok(false);
+            }
+        }
 
-                let mut pis = Bytes::new(&env);
-                for pi in entry.public_inputs.iter() {
-                    pis.append(&pi.into());
-                }
-                let vr = verifier.try_verify_proof(&pis, &entry.proof);
-                match vr {
-                    Ok(Ok(())) => {}
-                    _ => return Err(Error::InvalidProof),
-                }
+        match submission {
+            ProofSubmission::Ping(assassin, tower_id, d2, proof, public_inputs) => {
+                Self::submit_ping_proof(env.clone(), session_id, assassin, tower_id, d2, proof, public_inputs)?;
+            }
+            ProofSubmission::Move(assassin, new_commitment, proof, public_inputs) => {
+                Self::submit_move_proof(env.clone(), session_id, assassin, new_commitment, proof, public_inputs)?;
+            }
+            ProofSubmission::TurnStatus(assassin, d2_chad, proof, public_inputs) => {
+                Self::submit_turn_status_proof(env.clone(), session_id, assassin, d2_chad, proof, public_inputs)?;
             }
+        }
 
-            // Chain: update commitment for the next entry.
-            c.commitment = Some(entry.new_commitment);
-            s.moved_this_turn = true;
-            s.assassin_moves_this_turn = s.assassin_moves_this_turn.saturating_add(1);
+        let (_c, s) = Self::load_session_pair(&env, session_id)?;
+        let mut nonces = match seen {
+            Some((turn, nonces)) if turn == s.turn => nonces,
+            _ => Vec::new(&env),
+        };
+        nonces.push_back(nonce);
+        if nonces.len() > MAX_SUBMISSION_NONCES {
+            nonces.pop_front();
         }
+        env.storage().instance().set(&key, &(s.turn, nonces));
+        Ok(true)
+    }
 
-        // Single write at the end.
-        Self::store_session_runtime(&env, session_id, &s);
-        Self::store_session_core(&env, session_id, &c);
-        Ok(())
+    /// Runs the session's verifier for `kind` (0=ping, 1=turn status, 2=move)
+    /// against the current turn and records the outcome for auditors.
+    /// A recorded success is never overwritten by a later failure.
+    /// Only a player of a live session can record, and only for its current
+    /// `turn`.
+    pub fn verify_and_record(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        turn: u32,
+        kind: u32,
+        proof: Bytes,
+        public_inputs: Vec<BytesN<32>>,
+    ) -> Result<bool, Error> {
+        if kind > VERIFIER_MOVE {
+            return Err(Error::InvalidProofKind);
+        }
+        let (c, s) = Self::load_session_pair(&env, session_id)?;
+        if player != c.dispatcher && player != c.assassin {
+            return Err(Error::NotSessionPlayer);
+        }
+        player.require_auth();
+        Self::ensure_not_ended(&s)?;
+        if turn != s.turn {
+            return Err(Error::ProofTurnMismatch);
+        }
+        let verifier_addr = Self::load_verifier(&env, session_id, kind)?;
+        let ok = Self::call_verifier(&env, &verifier_addr, &public_inputs, &proof, kind);
+
+        let key = DataKey::ProofLog(session_id, s.turn, kind);
+        let prev: bool = env.storage().instance().get(&key).unwrap_or(false);
+        env.storage().instance().set(&key, &(prev || ok));
+        Ok(ok)
+    }
+
+    pub fn get_proof_log(env: Env, session_id: u32, turn: u32, kind: u32) -> Option<bool> {
+        env.storage().instance().get(&DataKey::ProofLog(session_id, turn, kind))
     }
 
     // --- Aliases for test compatibility ---
 
     pub fn commit_location(env: Env, session_id: u32, assassin: Address, commitment: BytesN<32>) -> Result<(), Error> {
-        let c = Self::load_session_core(&env, session_id)?;
+        let (mut c, r) = Self::load_session_pair(&env, session_id)?;
+        Self::require_owner_or_delegate(
+            &env,
+            session_id,
+            &c.assassin,
+            &assassin,
+            Role::Assassin,
+            SESSION_METHOD_COMMIT_LOCATION,
+        )?;
+        Self::apply_commit_location(&mut c, &r, commitment)?;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Locks the assassin's commitment without waiting for a verified ping, which
+    /// never happens in insecure demos. Afterwards `hide` and `commit_location`
+    /// fail with `CommitmentMismatch`. Idempotent.
+    pub fn finalize_commitment(env: Env, session_id: u32, assassin: Address) -> Result<(), Error> {
+        let mut c = Self::load_session_core(&env, session_id)?;
+        Self::require_owner_or_delegate(
+            &env,
+            session_id,
+            &c.assassin,
+            &assassin,
+            Role::Assassin,
+            SESSION_METHOD_COMMIT_LOCATION,
+        )?;
+        if c.commitment.is_none() {
+            return Err(Error::CommitmentNotSet);
+        }
+        c.commitment_locked = true;
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Commits the assassin's initial location and reports whether the session is
+    /// ready for play: runtime initialized and, in secure mode, all verifiers set.
+    pub fn commit_and_prepare(
+        env: Env,
+        session_id: u32,
+        assassin: Address,
+        commitment: BytesN<32>,
+    ) -> Result<bool, Error> {
+        let mut c = Self::load_session_core(&env, session_id)?;
         Self::require_owner_or_delegate(
             &env,
             session_id,
@@ -929,7 +1913,22 @@ impl ProofOfLife {
             Role::Assassin,
             SESSION_METHOD_COMMIT_LOCATION,
         )?;
-        Self::hide(env, session_id, commitment)
+        if c.commitment.is_some() {
+            return Err(Error::CommitmentAlreadySet);
+        }
+        let runtime = Self::load_session_runtime_opt(&env, session_id);
+        if let Some(r) = runtime.as_ref() {
+            Self::ensure_not_ended(r)?;
+        }
+
+        c.commitment = Some(commitment);
+        Self::store_session_core(&env, session_id, &c);
+
+        let verifiers_ready = Self::is_insecure(&c)
+            || (Self::load_verifier(&env, session_id, VERIFIER_PING).is_ok()
+                && Self::load_verifier(&env, session_id, VERIFIER_TURN_STATUS).is_ok()
+                && Self::load_verifier(&env, session_id, VERIFIER_MOVE).is_ok());
+        Ok(c.runtime_initialized && runtime.is_some() && verifiers_ready)
     }
 
     pub fn request_ping(
@@ -942,6 +1941,8 @@ impl ProofOfLife {
         Self::dispatch(env, session_id, _dispatcher, tower_id, ChadCommand::Stay)
     }
 
+    /// Tops up the battery instead of pinging. Like `dispatch`, only callable
+    /// in the dispatcher phase (`NotDispatcherTurn` otherwise).
     pub fn recharge(env: Env, session_id: u32, _dispatcher: Address) -> Result<(), Error> {
         Self::recharge_with_command(env, session_id, _dispatcher, ChadCommand::Stay)
     }
@@ -961,16 +1962,10 @@ impl ProofOfLife {
             Role::Dispatcher,
             SESSION_METHOD_RECHARGE,
         )?;
-        Self::ensure_not_ended(&s)?;
-        if s.phase != TurnPhase::Dispatcher {
-            return Err(Error::NotDispatcherTurn);
-        }
-        s.battery = (s.battery + RECHARGE_AMOUNT).min(BATTERY_MAX);
-        Self::apply_chad_command(&mut s, command)?;
-        s.pending_ping_tower = None;
-        s.phase = TurnPhase::Assassin;
-        s.moved_this_turn = false;
-        s.assassin_moves_this_turn = 0;
+        let closed = Self::closed_doors(&env, session_id);
+        Self::apply_recharge(&c, &mut s, closed, command)?;
+        Self::emit_chad_moved(&env, session_id, &s, command);
+        s.last_action_ledger = env.ledger().sequence();
         Self::store_session_runtime(&env, session_id, &s);
         Ok(())
     }
@@ -990,25 +1985,106 @@ impl ProofOfLife {
             Role::Assassin,
             SESSION_METHOD_ASSASSIN_TICK,
         )?;
-        Self::ensure_not_ended(&s)?;
         // require_owner_or_delegate already verified the actor is the registered assassin.
-        if s.phase != TurnPhase::Assassin {
-            return Err(Error::NotAssassinTurn);
+        Self::apply_assassin_tick(&c, &mut s)?;
+        s.last_action_ledger = env.ledger().sequence();
+        Self::store_session_runtime(&env, session_id, &s);
+        Ok(())
+    }
+
+    /// Closes the assassin's turn in one transaction: applies the optional move
+    /// proof, then the turn-status proof. Saves a wallet round trip on recharge
+    /// turns. Rejected while a ping is pending; a failure in either half rolls
+    /// back the whole call.
+    pub fn assassin_end_turn(
+        env: Env,
+        session_id: u32,
+        assassin: Address,
+        move_entry: Option<MoveProofEntry>,
+        d2_chad: u32,
+        status_proof: Bytes,
+        status_pis: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let (mut c, mut s) = Self::load_session_pair(&env, session_id)?;
+        // A delegate needs both methods in its mask when a move is bundled.
+        let methods = if move_entry.is_some() {
+            SESSION_METHOD_SUBMIT_MOVE_PROOF | SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF
+        } else {
+            SESSION_METHOD_SUBMIT_TURN_STATUS_PROOF
+        };
+        Self::require_owner_or_delegate(&env, session_id, &c.assassin, &assassin, Role::Assassin, methods)?;
+        if s.pending_ping_tower.is_some() {
+            return Err(Error::PendingPingExists);
         }
 
-        // In secure mode, the assassin must have submitted at least one move proof
-        // before the turn can advance. This prevents the on-chain commitment from
-        // going stale during recharge turns (which would cause CommitmentMismatch
-        // on the next ping).
-        if !c.insecure_mode && s.assassin_moves_this_turn == 0 {
-            return Err(Error::AssassinMustMove);
+        if let Some(entry) = move_entry {
+            Self::apply_move_proof(
+                &env,
+                &mut c,
+                &mut s,
+                session_id,
+                entry.new_commitment,
+                &entry.proof,
+                &entry.public_inputs,
+            )?;
         }
+        Self::apply_turn_status(&env, &mut c, &mut s, session_id, d2_chad, &status_proof, &status_pis)?;
 
-        s.turn = s.turn.saturating_add(1);
-        s.phase = TurnPhase::Dispatcher;
-        s.moved_this_turn = false;
-        s.assassin_moves_this_turn = 0;
         Self::store_session_runtime(&env, session_id, &s);
+        Self::store_session_core(&env, session_id, &c);
+        Ok(())
+    }
+
+    /// Executes a sequence of owner actions under a single `require_auth`.
+    /// Actions are applied in order with the usual phase rules; the first failure
+    /// aborts the call and rolls back every action in the batch.
+    /// Only the registered players may batch (delegates already use session keys).
+    pub fn do_turn(env: Env, session_id: u32, caller: Address, actions: Vec<Action>) -> Result<(), Error> {
+        let (mut c, mut s) = Self::load_session_pair(&env, session_id)?;
+        if caller != c.dispatcher && caller != c.assassin {
+            return Err(Error::NotSessionPlayer);
+        }
+        caller.require_auth();
+        let closed = Self::closed_doors(&env, session_id);
+
+        for action in actions.iter() {
+            match action {
+                Action::CommitLocation(commitment) => {
+                    // Only owners reach here, so there is no session-key scope to charge.
+                    if caller != c.assassin {
+                        return Err(Error::NotAssassin);
+                    }
+                    Self::apply_commit_location(&mut c, &s, commitment)?;
+                }
+                Action::Dispatch(tower_id, command) => {
+                    if caller != c.dispatcher {
+                        return Err(Error::NotDispatcher);
+                    }
+                    Self::apply_dispatch(&c, &mut s, closed, tower_id, command, PING_COST)?;
+                    Self::emit_chad_moved(&env, session_id, &s, command);
+                    if s.ended {
+                        Self::record_end_reason(&env, session_id, EndReason::BatteryDepleted);
+                    }
+                }
+                Action::Recharge(command) => {
+                    if caller != c.dispatcher {
+                        return Err(Error::NotDispatcher);
+                    }
+                    Self::apply_recharge(&c, &mut s, closed, command)?;
+                    Self::emit_chad_moved(&env, session_id, &s, command);
+                }
+                Action::AssassinTick => {
+                    if caller != c.assassin {
+                        return Err(Error::NotAssassin);
+                    }
+                    Self::apply_assassin_tick(&c, &mut s)?;
+                }
+            }
+        }
+
+        s.last_action_ledger = env.ledger().sequence();
+        Self::store_session_runtime(&env, session_id, &s);
+        Self::store_session_core(&env, session_id, &c);
         Ok(())
     }
 
@@ -1027,6 +2103,195 @@ impl ProofOfLife {
         Ok(Self::session_view(&c, runtime.as_ref()))
     }
 
+    /// Raw stored `SessionCore`, for checking the storage split directly.
+    pub fn get_session_core(env: Env, session_id: u32) -> Result<SessionCore, Error> {
+        Self::load_session_core(&env, session_id)
+    }
+
+    /// Raw stored `SessionRuntime`; `RuntimeNotInitialized` if that half was
+    /// never written.
+    pub fn get_session_runtime(env: Env, session_id: u32) -> Result<SessionRuntime, Error> {
+        Self::load_session_runtime_opt(&env, session_id).ok_or(Error::RuntimeNotInitialized)
+    }
+
+    /// Session view for spectator/demo UIs. Same as `get_session`; `d2` and
+    /// `d2_chad` carry the last accepted distances so proximity can be drawn.
+    /// These are public proof outputs, so secure sessions expose them too.
+    pub fn spectator_view(env: Env, session_id: u32) -> Result<Session, Error> {
+        Self::get_session(env, session_id)
+    }
+
+    /// Number of move proofs the assassin may still submit this turn.
+    /// Returns 0 outside the assassin phase.
+    pub fn moves_remaining_this_turn(env: Env, session_id: u32) -> Result<u32, Error> {
+        let (_c, s) = Self::load_session_pair(&env, session_id)?;
+        if s.phase != TurnPhase::Assassin {
+            return Ok(0);
+        }
+        Ok(Self::max_moves_this_turn(&s).saturating_sub(s.assassin_moves_this_turn))
+    }
+
+    /// How the per-turn move cap is made up: `(base_cap, hidden_bonus, total_cap)`.
+    pub fn move_budget(env: Env, session_id: u32) -> Result<(u32, u32, u32), Error> {
+        let (_c, s) = Self::load_session_pair(&env, session_id)?;
+        let (base, bonus) = Self::move_budget_parts(&s);
+        Ok((base, bonus, base + bonus))
+    }
+
+    /// Why the game ended; `None` while it is still running.
+    pub fn get_end_reason(env: Env, session_id: u32) -> Option<EndReason> {
+        env.storage().instance().get(&DataKey::EndReason(session_id))
+    }
+
+    /// Who won, from `ended`, the stored `EndReason` and the winner reported to
+    /// the hub. Kills and alpha depletion go to the assassin, battery depletion
+    /// to the dispatcher; timeouts and forced ends use the reported winner.
+    /// `OutcomeUnknown` if the game ended but no winner was recorded.
+    pub fn game_outcome(env: Env, session_id: u32) -> Result<Outcome, Error> {
+        let (_c, s) = Self::load_session_pair(&env, session_id)?;
+        if !s.ended {
+            return Ok(Outcome::Ongoing);
+        }
+        let reason: Option<EndReason> = env.storage().instance().get(&DataKey::EndReason(session_id));
+        let dispatcher_won = match reason {
+            Some(EndReason::Draw) => return Ok(Outcome::Draw),
+            Some(EndReason::Kill) | Some(EndReason::AlphaDepleted) => false,
+            Some(EndReason::BatteryDepleted) => true,
+            _ => env
+                .storage()
+                .instance()
+                .get(&DataKey::DispatcherWon(session_id))
+                .ok_or(Error::OutcomeUnknown)?,
+        };
+        Ok(if dispatcher_won { Outcome::DispatcherWon } else { Outcome::AssassinWon })
+    }
+
+    /// Replaces an ended game's state with a result fingerprint to stop paying
+    /// rent on it. Either player or the admin may archive. The digest is sha256
+    /// over the XDR of `(session_id, dispatcher, assassin, end_reason, outcome,
+    /// turn, battery, alpha, chad_x, chad_y, last_d2_chad)`.
+    /// Afterwards `get_session` returns `SessionNotFound`; `get_end_reason` still works.
+    /// Proof logs, session-key scopes, the recorded winner and both players'
+    /// `get_player_sessions` entries go with the session.
+    pub fn archive_session(env: Env, session_id: u32, caller: Address) -> Result<BytesN<32>, Error> {
+        let (c, s) = Self::load_session_pair(&env, session_id)?;
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != c.dispatcher && caller != c.assassin && caller != admin {
+            return Err(Error::NotSessionPlayer);
+        }
+        caller.require_auth();
+        if !s.ended {
+            return Err(Error::GameNotEnded);
+        }
+        let record = (
+            session_id,
+            c.dispatcher.clone(),
+            c.assassin.clone(),
+            Self::get_end_reason(env.clone(), session_id),
+            Self::game_outcome(env.clone(), session_id)?,
+            s.turn,
+            s.battery,
+            s.alpha,
+            s.chad_x,
+            s.chad_y,
+            s.last_d2_chad,
+        );
+        let digest: BytesN<32> = env.crypto().sha256(&record.to_xdr(&env)).into();
+
+        let storage = env.storage().instance();
+        storage.set(&DataKey::SessionArchive(session_id), &digest);
+        storage.remove(&DataKey::SessionCore(session_id));
+        storage.remove(&DataKey::SessionRuntime(session_id));
+        storage.remove(&DataKey::SessionVerifiers(session_id));
+        storage.remove(&DataKey::SessionTowers(session_id));
+        storage.remove(&DataKey::SubmissionNonces(session_id));
+        storage.remove(&DataKey::ClosedDoors(session_id));
+        storage.remove(&DataKey::DispatcherWon(session_id));
+        for turn in 0..=s.turn {
+            for kind in VERIFIER_PING..=VERIFIER_MOVE {
+                storage.remove(&DataKey::ProofLog(session_id, turn, kind));
+            }
+        }
+        for owner in [&c.dispatcher, &c.assassin] {
+            for role in [Role::Dispatcher, Role::Assassin] {
+                let role_u32 = Self::role_to_u32(role);
+                let key = DataKey::SessionKeyScope(session_id, owner.clone(), role_u32);
+                if let Some(scope) = storage.get::<_, SessionKeyScope>(&key) {
+                    Self::unindex_delegate_scope(&env, &scope.delegate, session_id, role_u32);
+                    storage.remove(&key);
+                }
+            }
+        }
+        Self::unindex_player_session(&env, &c.dispatcher, session_id);
+        Self::unindex_player_session(&env, &c.assassin, session_id);
+        Ok(digest)
+    }
+
+    pub fn get_session_archive(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::SessionArchive(session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    pub fn get_alpha_max(env: Env, session_id: u32) -> Result<u32, Error> {
+        Ok(Self::load_session_core(&env, session_id)?.alpha_max)
+    }
+
+    /// Pre-submission check: does `candidate` equal the on-chain commitment?
+    pub fn commitment_matches(env: Env, session_id: u32, candidate: BytesN<32>) -> Result<bool, Error> {
+        let c = Self::load_session_core(&env, session_id)?;
+        let commitment = c.commitment.ok_or(Error::CommitmentNotSet)?;
+        Ok(utils::ct_eq_bytes32(&candidate, &commitment))
+    }
+
+    /// Lightweight lobby view: `(dispatcher, assassin, ended, turn)`.
+    /// Skips building the full `Session` view.
+    pub fn get_session_summary(env: Env, session_id: u32) -> Result<(Address, Address, bool, u32), Error> {
+        let c = Self::load_session_core(&env, session_id)?;
+        let (ended, turn) = match Self::load_session_runtime_opt(&env, session_id) {
+            Some(r) => (r.ended, r.turn),
+            None => (false, 0),
+        };
+        Ok((c.dispatcher, c.assassin, ended, turn))
+    }
+
+    pub fn get_turn_phase(env: Env, session_id: u32) -> Result<TurnPhase, Error> {
+        Self::load_session_core(&env, session_id)?;
+        Ok(Self::load_session_runtime_opt(&env, session_id).map_or(TurnPhase::Dispatcher, |r| r.phase))
+    }
+
+    /// Address of the player expected to act in the current phase.
+    pub fn whose_turn(env: Env, session_id: u32) -> Result<Address, Error> {
+        let c = Self::load_session_core(&env, session_id)?;
+        let phase = Self::load_session_runtime_opt(&env, session_id).map_or(TurnPhase::Dispatcher, |r| r.phase);
+        Ok(match phase {
+            TurnPhase::Dispatcher => c.dispatcher,
+            TurnPhase::Assassin => c.assassin,
+        })
+    }
+
+    /// Primitive-only view for thin clients:
+    /// `(turn, phase, battery, alpha, alpha_max, chad_x, ended, chad_y, assassin_moves_this_turn)`
+    /// where `phase` is 0 = Dispatcher, 1 = Assassin.
+    #[allow(clippy::type_complexity)]
+    pub fn get_session_flat(env: Env, session_id: u32) -> Result<(u32, u32, u32, u32, u32, u32, bool, u32, u32), Error> {
+        let c = Self::load_session_core(&env, session_id)?;
+        let runtime = Self::load_session_runtime_opt(&env, session_id);
+        let v = Self::session_view(&c, runtime.as_ref());
+        Ok((
+            v.turn,
+            v.phase as u32,
+            v.battery,
+            v.alpha,
+            v.alpha_max,
+            v.chad_x,
+            v.ended,
+            v.chad_y,
+            v.assassin_moves_this_turn,
+        ))
+    }
+
     pub fn get_admin(env: Env) -> Address {
         env.storage().instance().get(&DataKey::Admin).unwrap()
     }
@@ -1042,7 +2307,77 @@ impl ProofOfLife {
         (p, t, m)
     }
 
-    // --- Internals ---
+    /// Whether `PingVerifier`, `TurnStatusVerifier` and `MoveVerifier` are
+    /// actually stored. `get_verifiers` substitutes this contract's address for
+    /// unset ones, which looks like a configured verifier.
+    pub fn verifiers_configured(env: Env) -> (bool, bool, bool) {
+        let storage = env.storage().instance();
+        (
+            storage.has(&DataKey::PingVerifier),
+            storage.has(&DataKey::TurnStatusVerifier),
+            storage.has(&DataKey::MoveVerifier),
+        )
+    }
+
+    /// Whether every proof this session will ask for has a verifier, so clients can
+    /// warn before the assassin gets stuck on `VerifierNotSet`. Always true in
+    /// insecure mode. When false, a `SecurePlayNotReady` event names the gaps.
+    pub fn session_ready_for_secure_play(env: Env, session_id: u32) -> Result<bool, Error> {
+        let c = Self::load_session_core(&env, session_id)?;
+        if Self::is_insecure(&c) {
+            return Ok(true);
+        }
+        let ping_ready = if c.coarse_ping {
+            Self::load_coarse_ping_verifier(&env, session_id).is_ok()
+        } else {
+            Self::load_verifier(&env, session_id, VERIFIER_PING).is_ok()
+        };
+        let mut missing = Vec::new(&env);
+        if !ping_ready {
+            missing.push_back(symbol_short!("ping"));
+        }
+        if Self::load_verifier(&env, session_id, VERIFIER_TURN_STATUS).is_err() {
+            missing.push_back(symbol_short!("turn"));
+        }
+        let move_ready = if c.walled_moves {
+            env.storage().instance().has(&DataKey::WalledMoveVerifier)
+        } else {
+            Self::load_verifier(&env, session_id, VERIFIER_MOVE).is_ok()
+        };
+        if !move_ready {
+            missing.push_back(symbol_short!("move"));
+        }
+        if missing.is_empty() {
+            return Ok(true);
+        }
+        SecurePlayNotReady { session_id, missing }.publish(&env);
+        Ok(false)
+    }
+
+    /// Encoding probe for prover harnesses: `(big_endian, little_endian)` 32-byte
+    /// encodings of `sample`. Public inputs are checked against the one matching the
+    /// session's `endianness` (big-endian unless `set_endianness` says otherwise).
+    pub fn probe_field_encoding(env: Env, sample: u32) -> (BytesN<32>, BytesN<32>) {
+        (Self::bytes32_from_u32(&env, sample), Self::bytes32_from_u32_le(&env, sample))
+    }
+
+    /// `(vk_log_n, vk_public_inputs, expected_proof_len)` of the global move verifier,
+    /// so a frontend can check the deployed circuit against its artifacts.
+    pub fn get_move_verifier_metadata(env: Env) -> Result<(u64, u64, u64), Error> {
+        Self::verifier_metadata(&env, &DataKey::MoveVerifier)
+    }
+
+    /// Same as `get_move_verifier_metadata` for the ping verifier.
+    pub fn get_ping_verifier_metadata(env: Env) -> Result<(u64, u64, u64), Error> {
+        Self::verifier_metadata(&env, &DataKey::PingVerifier)
+    }
+
+    /// Same as `get_move_verifier_metadata` for the turn-status verifier.
+    pub fn get_turn_verifier_metadata(env: Env) -> Result<(u64, u64, u64), Error> {
+        Self::verifier_metadata(&env, &DataKey::TurnStatusVerifier)
+    }
+
+    // --- Internals ---
     fn session_view(core: &SessionCore, runtime_opt: Option<&SessionRuntime>) -> Session {
         let default_runtime = SessionRuntime {
             chad_x: core.init_chad_x,
@@ -1057,6 +2392,14 @@ impl ProofOfLife {
             assassin_moves_this_turn: 0,
             chad_hidden: false,
             chad_hide_streak: 0,
+            last_action_ledger: 0,
+            last_d2: None,
+            last_d2_chad: None,
+            last_ping_tower: None,
+            last_ping_turn: 0,
+            free_action_used: false,
+            consecutive_recharges: 0,
+            last_ping_in_radius: None,
         };
         let r = runtime_opt.unwrap_or(&default_runtime);
 
@@ -1074,16 +2417,56 @@ impl ProofOfLife {
             alpha: r.alpha,
             alpha_max: core.alpha_max,
             pending_ping_tower: r.pending_ping_tower,
-            d2: None,
-            d2_chad: None,
+            d2: r.last_d2,
+            d2_chad: r.last_d2_chad,
             moved_this_turn: r.moved_this_turn,
             assassin_moves_this_turn: r.assassin_moves_this_turn,
             strong_radius_sq: core.strong_radius_sq,
             ping_cost: PING_COST,
-            recharge_amount: RECHARGE_AMOUNT,
+            recharge_amount: Self::recharge_gain(r),
             chad_hidden: r.chad_hidden,
             chad_hide_streak: r.chad_hide_streak,
-            insecure_mode: core.insecure_mode,
+            free_action_used: r.free_action_used,
+            consecutive_recharges: r.consecutive_recharges,
+            insecure_mode: Self::is_insecure(core),
+            turn_timeout_ledgers: core.turn_timeout_ledgers,
+            max_hide_streak: core.max_hide_streak,
+            last_action_ledger: r.last_action_ledger,
+            ready_check: core.ready_check,
+            dispatcher_ready: core.dispatcher_ready,
+            assassin_ready: core.assassin_ready,
+            distance_tolerance: core.distance_tolerance,
+            commit_grace_turns: core.commit_grace_turns,
+            coarse_ping: core.coarse_ping,
+            ping_in_radius: r.last_ping_in_radius,
+            walled_moves: core.walled_moves,
+            endianness: core.endianness,
+            commitment_locked: core.commitment_locked,
+            alpha_regen: core.alpha_regen,
+        }
+    }
+    fn fresh_runtime(env: &Env, core: &SessionCore) -> SessionRuntime {
+        SessionRuntime {
+            chad_x: core.init_chad_x,
+            chad_y: core.init_chad_y,
+            battery: INITIAL_BATTERY,
+            turn: 0,
+            phase: TurnPhase::Dispatcher,
+            ended: false,
+            alpha: core.alpha_max,
+            pending_ping_tower: None,
+            moved_this_turn: false,
+            assassin_moves_this_turn: 0,
+            chad_hidden: false,
+            chad_hide_streak: 0,
+            last_action_ledger: env.ledger().sequence(),
+            last_d2: None,
+            last_d2_chad: None,
+            last_ping_tower: None,
+            last_ping_turn: 0,
+            free_action_used: false,
+            consecutive_recharges: 0,
+            last_ping_in_radius: None,
         }
     }
     fn load_session_core(env: &Env, session_id: u32) -> Result<SessionCore, Error> {
@@ -1103,6 +2486,60 @@ impl ProofOfLife {
         let runtime = Self::load_session_runtime_opt(env, session_id).ok_or(Error::RuntimeNotInitialized)?;
         Ok((core, runtime))
     }
+    // Verifiers without the metadata getters (older deployments) are not checked.
+    fn check_verifier_layout(env: &Env, verifier: &Address, expected: u64) -> Result<(), Error> {
+        let Ok(Ok(n)) = VerifierMetaClient::new(env, verifier).try_vk_public_inputs() else {
+            return Ok(());
+        };
+        if n != expected && n != expected + PAIRING_POINT_INPUTS {
+            return Err(Error::VerifierCircuitMismatch);
+        }
+        Ok(())
+    }
+    fn set_single_verifier(
+        env: &Env,
+        key: DataKey,
+        verifier: &Address,
+        expected_inputs: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        Self::check_verifier_layout(env, verifier, expected_inputs)?;
+        env.storage().instance().set(&key, verifier);
+        Ok(())
+    }
+    fn verifier_metadata(env: &Env, key: &DataKey) -> Result<(u64, u64, u64), Error> {
+        let addr: Address = env.storage().instance().get(key).ok_or(Error::VerifierNotSet)?;
+        let v = VerifierMetaClient::new(env, &addr);
+        Ok((v.vk_log_n(), v.vk_public_inputs(), v.expected_proof_len()))
+    }
+    /// Resolves the verifier for a session: per-session override first, then the global one.
+    fn load_verifier(env: &Env, session_id: u32, kind: u32) -> Result<Address, Error> {
+        let storage = env.storage().instance();
+        if let Some(v) = storage.get::<_, SessionVerifiers>(&DataKey::SessionVerifiers(session_id)) {
+            return Ok(match kind {
+                VERIFIER_PING => v.ping_v,
+                VERIFIER_TURN_STATUS => v.turn_v,
+                _ => v.move_v,
+            });
+        }
+        let key = match kind {
+            VERIFIER_PING => DataKey::PingVerifier,
+            VERIFIER_TURN_STATUS => DataKey::TurnStatusVerifier,
+            _ => DataKey::MoveVerifier,
+        };
+        storage.get(&key).ok_or(Error::VerifierNotSet)
+    }
+    fn load_coarse_ping_verifier(env: &Env, session_id: u32) -> Result<Address, Error> {
+        let storage = env.storage().instance();
+        if let Some(v) = storage.get::<_, SessionVerifiers>(&DataKey::SessionVerifiers(session_id)) {
+            if let Some(addr) = v.coarse_ping_v {
+                return Ok(addr);
+            }
+        }
+        storage.get(&DataKey::CoarsePingVerifier).ok_or(Error::VerifierNotSet)
+    }
     fn role_to_u32(role: Role) -> u32 {
         match role {
             Role::Dispatcher => 0,
@@ -1120,6 +2557,77 @@ impl ProofOfLife {
             .instance()
             .set(&DataKey::SessionKeyScope(session_id, owner.clone(), Self::role_to_u32(role)), scope);
     }
+    /// Rejects masks with bits outside `VALID_METHOD_MASK`, which no method checks.
+    fn check_allow_masks(p: &SessionKeyParams) -> Result<(), Error> {
+        if (p.dispatcher_allow_mask | p.assassin_allow_mask) & !VALID_METHOD_MASK != 0 {
+            return Err(Error::InvalidMethodMask);
+        }
+        Ok(())
+    }
+    /// Stores the dispatcher and/or assassin scope `owner` is entitled to in this session.
+    fn store_owner_scopes(env: &Env, owner: &Address, c: &SessionCore, p: &SessionKeyParams) {
+        let session_id = c.session_id;
+        let expires_ledger = env.ledger().sequence().saturating_add(p.ttl_ledgers);
+
+        if *owner == c.dispatcher && p.dispatcher_allow_mask != 0 {
+            let scope = SessionKeyScope {
+                owner: owner.clone(),
+                delegate: p.delegate.clone(),
+                session_id,
+                role: Role::Dispatcher,
+                expires_ledger,
+                max_writes: p.max_writes,
+                writes_used: 0,
+                allow_mask: p.dispatcher_allow_mask,
+            };
+            Self::put_session_key_scope(env, session_id, owner, Role::Dispatcher, &scope);
+        }
+
+        if *owner == c.assassin && p.assassin_allow_mask != 0 {
+            let scope = SessionKeyScope {
+                owner: owner.clone(),
+                delegate: p.delegate.clone(),
+                session_id,
+                role: Role::Assassin,
+                expires_ledger,
+                max_writes: p.max_writes,
+                writes_used: 0,
+                allow_mask: p.assassin_allow_mask,
+            };
+            Self::put_session_key_scope(env, session_id, owner, Role::Assassin, &scope);
+        }
+    }
+    /// Stores a (new or replacing) scope and keeps the delegate reverse index in sync.
+    fn put_session_key_scope(env: &Env, session_id: u32, owner: &Address, role: Role, scope: &SessionKeyScope) {
+        let role_u32 = Self::role_to_u32(role);
+        if let Ok(old) = Self::load_session_key_scope(env, session_id, owner, role) {
+            if old.delegate != scope.delegate {
+                Self::unindex_delegate_scope(env, &old.delegate, session_id, role_u32);
+            }
+        }
+        Self::store_session_key_scope(env, session_id, owner, role, scope);
+
+        let key = DataKey::DelegateScopes(scope.delegate.clone());
+        let mut entries: Vec<(u32, u32)> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        if !entries.contains((session_id, role_u32)) {
+            entries.push_back((session_id, role_u32));
+            env.storage().instance().set(&key, &entries);
+        }
+    }
+    fn unindex_delegate_scope(env: &Env, delegate: &Address, session_id: u32, role_u32: u32) {
+        let key = DataKey::DelegateScopes(delegate.clone());
+        let Some(mut entries) = env.storage().instance().get::<_, Vec<(u32, u32)>>(&key) else {
+            return;
+        };
+        if let Some(idx) = entries.first_index_of((session_id, role_u32)) {
+            entries.remove(idx);
+        }
+        if entries.is_empty() {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &entries);
+        }
+    }
     fn require_owner_or_delegate(
         env: &Env,
         session_id: u32,
@@ -1143,7 +2651,7 @@ impl ProofOfLife {
         if scope.expires_ledger < env.ledger().sequence() {
             return Err(Error::SessionKeyExpired);
         }
-        if (scope.allow_mask & method_flag) == 0 {
+        if (scope.allow_mask & method_flag) != method_flag {
             return Err(Error::SessionKeyMethodNotAllowed);
         }
         if scope.max_writes != 0 && scope.writes_used >= scope.max_writes {
@@ -1155,14 +2663,330 @@ impl ProofOfLife {
         Self::store_session_key_scope(env, session_id, owner, role, &scope);
         Ok(())
     }
+    // Shared by `commit_location` and `do_turn`. The first commitment is free;
+    // later ones replace it only inside the grace window and until it locks.
+    fn apply_commit_location(c: &mut SessionCore, s: &SessionRuntime, commitment: BytesN<32>) -> Result<(), Error> {
+        Self::ensure_not_ended(s)?;
+        if c.commitment_locked {
+            return Err(Error::CommitmentMismatch);
+        }
+        if c.commitment.is_some() && s.turn >= c.commit_grace_turns {
+            return Err(Error::CommitmentAlreadySet);
+        }
+        c.commitment = Some(commitment);
+        Ok(())
+    }
+    fn apply_move_proof(
+        env: &Env,
+        c: &mut SessionCore,
+        s: &mut SessionRuntime,
+        session_id: u32,
+        new_commitment: BytesN<32>,
+        proof: &Bytes,
+        public_inputs: &Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        Self::ensure_not_ended(s)?;
+
+        if s.phase != TurnPhase::Assassin {
+            return Err(Error::NotAssassinTurn);
+        }
+        if s.pending_ping_tower.is_some() {
+            return Err(Error::PendingPingExists);
+        }
+        let max_moves = Self::max_moves_this_turn(s);
+        if s.assassin_moves_this_turn >= max_moves {
+            return Err(Error::AlreadyMovedThisTurn);
+        }
+        // A move that lands on the current commitment is not a move.
+        if c.commitment.as_ref() == Some(&new_commitment) {
+            return Err(Error::StaleMoveCommitment);
+        }
+
+        let distance = if Self::is_insecure(c) {
+            Self::check_move_step(c, s, public_inputs)?
+        } else {
+            let d = Self::verify_move_proof(env, c, s, session_id, &new_commitment, proof, public_inputs)?;
+            // A verified move builds on the current commitment; swapping it now would teleport.
+            c.commitment_locked = true;
+            d
+        };
+
+        c.commitment = Some(new_commitment);
+        s.moved_this_turn = true;
+        s.assassin_moves_this_turn = s.assassin_moves_this_turn.saturating_add(distance);
+        Ok(())
+    }
+    // Runs in insecure mode too: the claimed step count and distance are the only
+    // things tying move submissions to the budget. Returns the proven distance,
+    // which is what the step costs. Secure sessions always carry both fields
+    // (`verify_move_proof` checks the arity); an insecure submission without them
+    // costs 1.
+    fn check_move_step(c: &SessionCore, s: &SessionRuntime, public_inputs: &Vec<BytesN<32>>) -> Result<u32, Error> {
+        let distance = if public_inputs.len() > MOVE_STEP_DISTANCE_INDEX {
+            let d = Self::read_u32_field(public_inputs, MOVE_STEP_DISTANCE_INDEX, c.endianness)
+                .ok_or(Error::IllegalStep)?;
+            if d == 0 || d > Self::allowed_step_distance(s) {
+                return Err(Error::IllegalStep);
+            }
+            d
+        } else {
+            1
+        };
+        if public_inputs.len() > MOVE_STEPS_CLAIMED_INDEX {
+            let steps = s.assassin_moves_this_turn.saturating_add(distance);
+            Self::verify_u32_field(public_inputs, MOVE_STEPS_CLAIMED_INDEX, steps, c.endianness, Error::MoveCountMismatch)?;
+        }
+        Ok(distance)
+    }
+    fn apply_turn_status(
+        env: &Env,
+        c: &mut SessionCore,
+        s: &mut SessionRuntime,
+        session_id: u32,
+        d2_chad: u32,
+        proof: &Bytes,
+        public_inputs: &Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        Self::ensure_not_ended(s)?;
+
+        if s.phase != TurnPhase::Assassin {
+            return Err(Error::NotAssassinTurn);
+        }
+        // The dispatcher's ping must be answered before the turn can close.
+        if s.pending_ping_tower.is_some() {
+            return Err(Error::PendingPingExists);
+        }
+        // A hidden Chad lets the assassin pass (stay put) without revealing a move;
+        // otherwise a move is required so the commitment cannot go stale.
+        if s.assassin_moves_this_turn == 0 && !s.chad_hidden {
+            return Err(Error::AssassinMustMove);
+        }
+        // No two tiles are further apart than this, whatever the proof says.
+        if d2_chad > floorplan::max_dist_sq() {
+            return Err(Error::DistanceOutOfRange);
+        }
+        Self::check_distance_consistency(env, c, s, d2_chad)?;
+
+        if !Self::is_insecure(c) {
+            // Bind the statement to the on-chain Chad location so the assassin can't choose a fake (cx,cy).
+            Self::verify_turn_status_proof(
+                env,
+                c,
+                s,
+                session_id,
+                (s.chad_x, s.chad_y),
+                Some(d2_chad),
+                proof,
+                public_inputs,
+            )?;
+            c.commitment_locked = true;
+        }
+
+        s.last_d2_chad = Some(d2_chad);
+        if d2_chad == 0 {
+            Self::end_game_internal(env, session_id, s, false, EndReason::Kill)?;
+        } else {
+            if d2_chad <= c.strong_radius_sq {
+                s.alpha = s.alpha.saturating_sub(1);
+            } else {
+                s.alpha = s.alpha.saturating_add(c.alpha_regen).min(c.alpha_max);
+            }
+
+            if s.alpha == 0 {
+                Self::end_game_internal(env, session_id, s, false, EndReason::AlphaDepleted)?;
+            } else {
+                if s.battery == 0 {
+                    Self::end_game_internal(env, session_id, s, true, EndReason::BatteryDepleted)?;
+                } else {
+                    s.turn = s.turn.saturating_add(1);
+                    s.phase = TurnPhase::Dispatcher;
+                    s.moved_this_turn = false;
+                    s.assassin_moves_this_turn = 0;
+                    s.last_action_ledger = env.ledger().sequence();
+                }
+            }
+        }
+
+        Ok(())
+    }
+    // Hidden Chad gives the assassin up to 6 steps; otherwise exactly one.
+    fn max_moves_this_turn(s: &SessionRuntime) -> u32 {
+        let (base, bonus) = Self::move_budget_parts(s);
+        base + bonus
+    }
+    // One tile per proof, unless Chad is hidden: then a proof may cover the rest of the turn's budget.
+    fn allowed_step_distance(s: &SessionRuntime) -> u32 {
+        if s.chad_hidden {
+            Self::max_moves_this_turn(s).saturating_sub(s.assassin_moves_this_turn)
+        } else {
+            1
+        }
+    }
+    // (base cap, bonus while Chad is hidden)
+    fn move_budget_parts(s: &SessionRuntime) -> (u32, u32) {
+        (1, if s.chad_hidden { 5 } else { 0 })
+    }
+    // `strict` builds never skip verification, even for sessions stored with the flag set.
+    fn is_insecure(c: &SessionCore) -> bool {
+        !cfg!(feature = "strict") && c.insecure_mode
+    }
     fn ensure_not_ended(s: &SessionRuntime) -> Result<(), Error> {
         if s.ended { Err(Error::GameAlreadyEnded) } else { Ok(()) }
     }
+    fn session_towers(env: &Env, session_id: u32) -> Towers {
+        let storage = env.storage().instance();
+        match storage.get(&DataKey::SessionTowers(session_id)) {
+            Some(t) => t,
+            None => storage.get(&DataKey::Towers).unwrap(),
+        }
+    }
+    fn tower_coords(towers: &Towers, tower_id: u32) -> Option<(u32, u32)> {
+        match tower_id {
+            0 => Some((towers.n_x, towers.n_y)),
+            1 => Some((towers.e_x, towers.e_y)),
+            2 => Some((towers.s_x, towers.s_y)),
+            3 => Some((towers.w_x, towers.w_y)),
+            _ => None,
+        }
+    }
+    /// Triangle inequality between the pinged tower, Chad and the assassin, using
+    /// this turn's ping distance and the reported `d2_chad`. Skipped when disabled
+    /// or when no ping was answered this turn.
+    fn check_distance_consistency(env: &Env, c: &SessionCore, s: &SessionRuntime, d2_chad: u32) -> Result<(), Error> {
+        if c.distance_tolerance == 0 {
+            return Ok(());
+        }
+        let (Some(tower_id), Some(d2)) = (s.last_ping_tower, s.last_d2) else {
+            return Ok(());
+        };
+        if s.last_ping_turn != s.turn {
+            return Ok(());
+        }
+        let towers = Self::session_towers(env, c.session_id);
+        let Some((tx, ty)) = Self::tower_coords(&towers, tower_id) else {
+            return Ok(());
+        };
+        let tc2 = tx.abs_diff(s.chad_x).pow(2) + ty.abs_diff(s.chad_y).pow(2);
+
+        let (tc, ta, ca) = (utils::isqrt(tc2), utils::isqrt(d2), utils::isqrt(d2_chad));
+        let tol = c.distance_tolerance;
+        if tc > ta + ca + tol || ta.abs_diff(ca) > tc + tol {
+            return Err(Error::DistanceInconsistent);
+        }
+        Ok(())
+    }
+    fn ensure_ready(c: &SessionCore) -> Result<(), Error> {
+        if c.ready_check && !(c.dispatcher_ready && c.assassin_ready) {
+            return Err(Error::NotReady);
+        }
+        Ok(())
+    }
+
+    fn apply_dispatch(
+        c: &SessionCore,
+        s: &mut SessionRuntime,
+        closed_doors: u32,
+        tower_id: u32,
+        command: ChadCommand,
+        cost: u32,
+    ) -> Result<(), Error> {
+        Self::ensure_not_ended(s)?;
+        Self::ensure_ready(c)?;
+        if s.phase != TurnPhase::Dispatcher {
+            return Err(Error::NotDispatcherTurn);
+        }
 
-    fn apply_chad_command(s: &mut SessionRuntime, command: ChadCommand) -> Result<(), Error> {
+        // Free dispatches stay free; paid ones pay for the distance travelled.
+        let cost = if cost == 0 { 0 } else { cost + Self::command_surcharge(s, closed_doors, command)? };
+        if s.battery < cost {
+            return Err(Error::BatteryTooLow);
+        }
+        s.battery -= cost;
+        s.consecutive_recharges = 0;
+
+        Self::apply_chad_command(s, c.max_hide_streak, closed_doors, command)?;
+
+        s.pending_ping_tower = Some(tower_id);
+        s.phase = TurnPhase::Assassin;
+        s.moved_this_turn = false;
+        s.assassin_moves_this_turn = 0;
+        if s.battery == 0 {
+            s.ended = true;
+        }
+        Ok(())
+    }
+
+    // `ROOM_STEP_COST` for every room a `GoRoom` crosses after the first;
+    // `RUN_EXTRA_COST` for a `RunGarden`.
+    fn command_surcharge(s: &SessionRuntime, closed_doors: u32, command: ChadCommand) -> Result<u32, Error> {
+        match command {
+            ChadCommand::GoRoom(room_id) => {
+                let from_room = floorplan::get_room_code(s.chad_x, s.chad_y);
+                let rooms = floorplan::room_distance(from_room, Self::room_code_from_id(room_id), closed_doors)
+                    .ok_or(Error::InvalidRoomTransition)?;
+                Ok(ROOM_STEP_COST * rooms.saturating_sub(1))
+            }
+            ChadCommand::RunGarden(_) => Ok(RUN_EXTRA_COST),
+            _ => Ok(0),
+        }
+    }
+
+    // Diminishing returns for back-to-back recharges: 10, 5, 2, 1, 1, ...
+    fn recharge_gain(s: &SessionRuntime) -> u32 {
+        RECHARGE_AMOUNT >> s.consecutive_recharges.min(3)
+    }
+
+    fn apply_recharge(
+        c: &SessionCore,
+        s: &mut SessionRuntime,
+        closed_doors: u32,
+        command: ChadCommand,
+    ) -> Result<(), Error> {
+        Self::ensure_not_ended(s)?;
+        Self::ensure_ready(c)?;
+        if s.phase != TurnPhase::Dispatcher {
+            return Err(Error::NotDispatcherTurn);
+        }
+        s.battery = (s.battery + Self::recharge_gain(s)).min(BATTERY_MAX);
+        s.consecutive_recharges = s.consecutive_recharges.saturating_add(1);
+        Self::apply_chad_command(s, c.max_hide_streak, closed_doors, command)?;
+        s.pending_ping_tower = None;
+        s.phase = TurnPhase::Assassin;
+        s.moved_this_turn = false;
+        s.assassin_moves_this_turn = 0;
+        Ok(())
+    }
+
+    fn apply_assassin_tick(c: &SessionCore, s: &mut SessionRuntime) -> Result<(), Error> {
+        Self::ensure_not_ended(s)?;
+        if s.phase != TurnPhase::Assassin {
+            return Err(Error::NotAssassinTurn);
+        }
+
+        // In secure mode, the assassin must have submitted at least one move proof
+        // before the turn can advance. This prevents the on-chain commitment from
+        // going stale during recharge turns (which would cause CommitmentMismatch
+        // on the next ping).
+        if !Self::is_insecure(c) && s.assassin_moves_this_turn == 0 {
+            return Err(Error::AssassinMustMove);
+        }
+
+        s.turn = s.turn.saturating_add(1);
+        s.phase = TurnPhase::Dispatcher;
+        s.moved_this_turn = false;
+        s.assassin_moves_this_turn = 0;
+        Ok(())
+    }
+
+    fn apply_chad_command(
+        s: &mut SessionRuntime,
+        max_hide_streak: u32,
+        closed_doors: u32,
+        command: ChadCommand,
+    ) -> Result<(), Error> {
         match command {
             ChadCommand::Hide => {
-                if s.chad_hide_streak >= 2 {
+                if s.chad_hide_streak >= max_hide_streak {
                     return Err(Error::InvalidHide);
                 }
                 s.chad_hidden = true;
@@ -1175,72 +2999,228 @@ impl ProofOfLife {
             ChadCommand::WalkGarden(dir) => {
                 s.chad_hide_streak = 0;
                 s.chad_hidden = false;
-                match dir {
-                    0 => s.chad_y = s.chad_y.saturating_sub(1),
-                    1 => s.chad_x = s.chad_x.saturating_add(1),
-                    2 => s.chad_y = s.chad_y.saturating_add(1),
-                    3 => s.chad_x = s.chad_x.saturating_sub(1),
-                    _ => return Err(Error::InvalidMove),
+                let (nx, ny) = Self::garden_step(s.chad_x, s.chad_y, dir)?;
+                // Walking off the grid or into a blocked tile leaves Chad in place.
+                if floorplan::in_bounds(nx, ny) && !floorplan::is_blocked_tile(nx, ny) {
+                    s.chad_x = nx;
+                    s.chad_y = ny;
                 }
             }
+            ChadCommand::RunGarden(dir) => {
+                // Unlike a walk, a run that cannot complete is rejected outright.
+                let (mx, my) = Self::garden_step(s.chad_x, s.chad_y, dir)?;
+                let (nx, ny) = Self::garden_step(mx, my, dir)?;
+                if !floorplan::can_move4(s.chad_x, s.chad_y, mx, my, closed_doors)
+                    || !floorplan::can_move4(mx, my, nx, ny, closed_doors)
+                {
+                    return Err(Error::InvalidMove);
+                }
+                s.chad_hide_streak = 0;
+                s.chad_hidden = false;
+                s.chad_x = nx;
+                s.chad_y = ny;
+            }
             ChadCommand::GoRoom(room_id) => {
                 s.chad_hide_streak = 0;
                 s.chad_hidden = false;
 
                 let to_room = Self::room_code_from_id(room_id);
                 let from_room = floorplan::get_room_code(s.chad_x, s.chad_y);
+                if floorplan::room_distance(from_room, to_room, closed_doors).is_none() {
+                    return Err(Error::InvalidRoomTransition);
+                }
 
-                if let Some((nx, ny)) = Self::find_door(from_room, to_room) {
+                if let Some((nx, ny)) = Self::find_door(from_room, to_room, closed_doors) {
                     s.chad_x = nx;
                     s.chad_y = ny;
-                } else {
-                    // Fallback to a representative center tile if no direct door found (e.g. Garden spawns)
+                } else if let Some((sx, sy)) = floorplan::room_spawn(to_room) {
+                    // Fallback to the room's spawn tile if no direct door found (e.g. Garden spawns)
                     // This matches frontend's findAnyExitToRoom or default entry logic.
-                    match to_room {
-                        b'G' => { s.chad_x = 5; s.chad_y = 1; }
-                        b'L' => { s.chad_x = 1; s.chad_y = 4; }
-                        b'S' => { s.chad_x = 8; s.chad_y = 4; }
-                        b'B' => { s.chad_x = 1; s.chad_y = 7; }
-                        b'D' => { s.chad_x = 8; s.chad_y = 6; }
-                        b'K' => { s.chad_x = 8; s.chad_y = 8; }
-                        b'E' => { s.chad_x = 4; s.chad_y = 8; }
-                        _ => { s.chad_x = 4; s.chad_y = 5; } // Hallway/Winter
-                    }
+                    s.chad_x = sx;
+                    s.chad_y = sy;
+                } else {
+                    return Err(Error::InvalidRoomTransition);
                 }
             }
         }
         Ok(())
     }
 
-    fn verify_session_turn(pis: &Vec<BytesN<32>>, s_id: u32, turn: u32, s_idx: u32, t_idx: u32) -> Result<(), Error> {
+    // One tile in direction `dir` (0=N, 1=E, 2=S, 3=W). May leave the grid.
+    fn garden_step(x: u32, y: u32, dir: u32) -> Result<(u32, u32), Error> {
+        match dir {
+            0 => Ok((x, y.wrapping_sub(1))),
+            1 => Ok((x.wrapping_add(1), y)),
+            2 => Ok((x, y.wrapping_add(1))),
+            3 => Ok((x.wrapping_sub(1), y)),
+            _ => Err(Error::InvalidMove),
+        }
+    }
+
+    /// Rejects a wrong-length input vector before any parsing or verifier call.
+    fn check_arity(public_inputs: &Vec<BytesN<32>>, expected: u64) -> Result<(), Error> {
+        if public_inputs.len() as u64 != expected {
+            return Err(Error::PublicInputArityMismatch);
+        }
+        Ok(())
+    }
+
+    fn verify_session_turn(
+        pis: &Vec<BytesN<32>>,
+        endian: Endian,
+        s_id: u32,
+        turn: u32,
+        s_idx: u32,
+        t_idx: u32,
+    ) -> Result<(), Error> {
         let pi_sid = pis.get(s_idx).ok_or(Error::ProofSessionMismatch)?;
         let pi_turn = pis.get(t_idx).ok_or(Error::ProofTurnMismatch)?;
         
-        let sid_b32 = Self::bytes32_from_u32(pis.env(), s_id);
-        let turn_b32 = Self::bytes32_from_u32(pis.env(), turn);
+        let sid_b32 = Self::encode_u32(pis.env(), s_id, endian);
+        let turn_b32 = Self::encode_u32(pis.env(), turn, endian);
 
-        // Temporary on-chain diagnostics to debug field-order/encoding mismatches.
-        pis.env().events().publish(
-            (symbol_short!("dbg_sid"),),
-            (s_idx, pi_sid.clone(), sid_b32.clone()),
-        );
-        pis.env().events().publish(
-            (symbol_short!("dbg_turn"),),
-            (t_idx, pi_turn.clone(), turn_b32.clone()),
-        );
+        if !utils::ct_eq_bytes32(&pi_sid, &sid_b32) { return Err(Error::ProofSessionMismatch); }
+        if !utils::ct_eq_bytes32(&pi_turn, &turn_b32) { return Err(Error::ProofTurnMismatch); }
+        Ok(())
+    }
 
-        if pi_sid != sid_b32 { return Err(Error::ProofSessionMismatch); }
-        if pi_turn != turn_b32 { return Err(Error::ProofTurnMismatch); }
+    fn verify_move_proof(
+        env: &Env,
+        c: &SessionCore,
+        s: &SessionRuntime,
+        session_id: u32,
+        new_commitment: &BytesN<32>,
+        proof: &Bytes,
+        public_inputs: &Vec<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        let old_commitment = c.commitment.as_ref().ok_or(Error::CommitmentNotSet)?;
+        // UltraHonk (`bb --output_format bytes_and_fields`) public field ordering:
+        // [session_id, turn, commitment_old, commitment_new, floorplan_slot,
+        //  steps_claimed, step_distance]
+        Self::check_arity(public_inputs, MOVE_PUBLIC_INPUTS)?;
+        Self::verify_session_turn(public_inputs, c.endianness, session_id, s.turn, 0, 1)?;
+
+        let pi_old = public_inputs.get(2).ok_or(Error::CommitmentMismatch)?;
+        if !utils::ct_eq_bytes32(&pi_old, old_commitment) {
+            return Err(Error::CommitmentMismatch);
+        }
+        let pi_new = public_inputs.get(3).ok_or(Error::CommitmentMismatch)?;
+        if !utils::ct_eq_bytes32(&pi_new, new_commitment) {
+            return Err(Error::CommitmentMismatch);
+        }
+        let distance = Self::check_move_step(c, s, public_inputs)?;
+
+        // The circuit can't be trusted to know the walls unless it says which
+        // floorplan it checked; walled sessions bind it in the 5th field.
+        let verifier_addr = if c.walled_moves {
+            Self::verify_u32_field(public_inputs, 4, floorplan::FLOORPLAN_ID, c.endianness, Error::FloorplanMismatch)?;
+            env.storage()
+                .instance()
+                .get(&DataKey::WalledMoveVerifier)
+                .ok_or(Error::VerifierNotSet)?
+        } else {
+            // 0 from `move_proof`, or the id from a wall-aware circuit; nothing else,
+            // so one layout never reads as another.
+            let slot = Self::read_u32_field(public_inputs, 4, c.endianness);
+            if slot != Some(0) && slot != Some(floorplan::FLOORPLAN_ID) {
+                return Err(Error::FloorplanMismatch);
+            }
+            Self::load_verifier(env, session_id, VERIFIER_MOVE)?
+        };
+        if !Self::call_verifier(env, &verifier_addr, public_inputs, proof, VERIFIER_MOVE) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(distance)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_turn_status_proof(
+        env: &Env,
+        c: &SessionCore,
+        s: &SessionRuntime,
+        session_id: u32,
+        (cx, cy): (u32, u32),
+        d2_chad: Option<u32>,
+        proof: &Bytes,
+        public_inputs: &Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let commitment = c.commitment.as_ref().ok_or(Error::CommitmentNotSet)?;
+        // v3 layout: [cx, cy, session_id, turn, commitment, d2_chad]
+        Self::check_arity(public_inputs, TURN_STATUS_PUBLIC_INPUTS)?;
+        Self::verify_session_turn(public_inputs, c.endianness, session_id, s.turn, 2, 3)?;
+
+        Self::verify_u32_field(public_inputs, 0, cx, c.endianness, Error::ChadCoordMismatch)?;
+        Self::verify_u32_field(public_inputs, 1, cy, c.endianness, Error::ChadCoordMismatch)?;
+        let pi_cmt = public_inputs.get(4).ok_or(Error::CommitmentMismatch)?;
+        if !utils::ct_eq_bytes32(&pi_cmt, commitment) {
+            return Err(Error::CommitmentMismatch);
+        }
+        if let Some(d2) = d2_chad {
+            Self::verify_u32_field(public_inputs, 5, d2, c.endianness, Error::D2ChadMismatch)?;
+        }
+        let verifier_addr = Self::load_verifier(env, session_id, VERIFIER_TURN_STATUS)?;
+        if !Self::call_verifier(env, &verifier_addr, public_inputs, proof, VERIFIER_TURN_STATUS) {
+            return Err(Error::InvalidProof);
+        }
         Ok(())
     }
 
-    fn verify_u32_field(pis: &Vec<BytesN<32>>, idx: u32, expected: u32, err: Error) -> Result<(), Error> {
+    /// Runs `verifier_addr` on the proof. Domain-tagged verifiers check it in
+    /// the `kind` domain, every other verifier in domain 0 via `verify_proof`.
+    fn call_verifier(
+        env: &Env,
+        verifier_addr: &Address,
+        public_inputs: &Vec<BytesN<32>>,
+        proof: &Bytes,
+        kind: u32,
+    ) -> bool {
+        let verifier = UltraHonkClient::new(env, verifier_addr);
+        let mut pis = Bytes::new(env);
+        for pi in public_inputs.iter() {
+            pis.append(&pi.into());
+        }
+        let tagged = env
+            .storage()
+            .instance()
+            .has(&DataKey::DomainTaggedVerifier(verifier_addr.clone()));
+        if tagged {
+            matches!(verifier.try_verify_proof_in_domain(&pis, proof, &kind), Ok(Ok(())))
+        } else {
+            matches!(verifier.try_verify_proof(&pis, proof), Ok(Ok(())))
+        }
+    }
+
+    fn verify_u32_field(
+        pis: &Vec<BytesN<32>>,
+        idx: u32,
+        expected: u32,
+        endian: Endian,
+        err: Error,
+    ) -> Result<(), Error> {
         let got = pis.get(idx).ok_or(err)?;
-        let exp = Self::bytes32_from_u32(pis.env(), expected);
-        if got != exp { return Err(err); }
+        let exp = Self::encode_u32(pis.env(), expected, endian);
+        if !utils::ct_eq_bytes32(&got, &exp) { return Err(err); }
         Ok(())
     }
 
+    /// Decodes a field written by `encode_u32`; `None` if it does not fit a u32.
+    fn read_u32_field(pis: &Vec<BytesN<32>>, idx: u32, endian: Endian) -> Option<u32> {
+        let field = pis.get(idx)?.to_array();
+        let (word, rest) = match endian {
+            Endian::Big => (&field[28..], &field[..28]),
+            Endian::Little => (&field[..4], &field[4..]),
+        };
+        if rest.iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut w = [0u8; 4];
+        w.copy_from_slice(word);
+        Some(match endian {
+            Endian::Big => u32::from_be_bytes(w),
+            Endian::Little => u32::from_le_bytes(w),
+        })
+    }
+
     fn bytes32_from_u32(env: &Env, v: u32) -> BytesN<32> {
         let mut arr = [0u8; 32];
         // Big-endian (matches existing verify_session_turn behavior).
@@ -1251,6 +3231,19 @@ impl ProofOfLife {
         BytesN::from_array(env, &arr)
     }
 
+    fn bytes32_from_u32_le(env: &Env, v: u32) -> BytesN<32> {
+        let mut arr = [0u8; 32];
+        arr[..4].copy_from_slice(&v.to_le_bytes());
+        BytesN::from_array(env, &arr)
+    }
+
+    fn encode_u32(env: &Env, v: u32, endian: Endian) -> BytesN<32> {
+        match endian {
+            Endian::Big => Self::bytes32_from_u32(env, v),
+            Endian::Little => Self::bytes32_from_u32_le(env, v),
+        }
+    }
+
     fn room_code_from_id(id: u32) -> u8 {
         match id {
             0 => b'G', // Garden
@@ -1261,12 +3254,16 @@ impl ProofOfLife {
             5 => b'D', // Dining
             6 => b'K', // Kitchen
             7 => b'E', // Grand Hall
+            8 => b'W', // Winter garden (sealed)
             _ => b'H',
         }
     }
 
-    fn find_door(from_room: u8, to_room: u8) -> Option<(u32, u32)> {
-        for &(ax, ay, bx, by) in &floorplan::DOORS_OPEN {
+    fn find_door(from_room: u8, to_room: u8, closed_doors: u32) -> Option<(u32, u32)> {
+        for (i, &(ax, ay, bx, by)) in floorplan::DOORS_OPEN.iter().enumerate() {
+            if floorplan::is_door_closed(closed_doors, i) {
+                continue;
+            }
             if floorplan::get_room_code(ax, ay) == from_room && floorplan::get_room_code(bx, by) == to_room {
                 return Some((bx, by));
             }
@@ -1276,11 +3273,220 @@ impl ProofOfLife {
         }
         None
     }
-    fn end_game_internal(env: &Env, session_id: u32, s: &mut SessionRuntime, dispatcher_won: bool) -> Result<(), Error> {
+    fn closed_doors(env: &Env, session_id: u32) -> u32 {
+        env.storage().instance().get(&DataKey::ClosedDoors(session_id)).unwrap_or(0)
+    }
+    // Shared by the public `start_game*` entry points; the runtime starts with
+    // `initial_battery`.
+    #[allow(clippy::too_many_arguments)]
+    fn start_game_internal(
+        env: Env,
+        session_id: u32,
+        dispatcher: Address,
+        assassin: Address,
+        chad_x: u32,
+        chad_y: u32,
+        alpha_max: u32,
+        strong_radius_sq: u32,
+        initial_battery: u32,
+    ) -> Result<(), Error> {
+        if !floorplan::in_bounds(chad_x, chad_y) || floorplan::is_blocked_tile(chad_x, chad_y) {
+            return Err(Error::InvalidStartPosition);
+        }
+        // Hackathon requirement: register each session in the shared Game Hub.
+        let game_hub_addr: Address = env.storage().instance().get(&DataKey::GameHub).unwrap();
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &dispatcher,
+            &assassin,
+            &DEFAULT_HUB_POINTS_DISPATCHER,
+            &DEFAULT_HUB_POINTS_ASSASSIN,
+        );
+        HubStartGame {
+            session_id,
+            player1: dispatcher.clone(),
+            player2: assassin.clone(),
+            player1_points: DEFAULT_HUB_POINTS_DISPATCHER,
+            player2_points: DEFAULT_HUB_POINTS_ASSASSIN,
+        }
+        .publish(&env);
+
+        let core = SessionCore {
+            session_id,
+            dispatcher,
+            assassin,
+            commitment: None,
+            alpha_max,
+            strong_radius_sq,
+            insecure_mode: false, // 100% ZK verification enforced by default
+            init_chad_x: chad_x,
+            init_chad_y: chad_y,
+            runtime_initialized: true, // runtime initialized immediately
+            turn_timeout_ledgers: 0,
+            max_hide_streak: DEFAULT_MAX_HIDE_STREAK,
+            grant_alpha_cost: DEFAULT_GRANT_ALPHA_COST,
+            ready_check: false,
+            dispatcher_ready: false,
+            assassin_ready: false,
+            distance_tolerance: 0,
+            commit_grace_turns: 0,
+            coarse_ping: false,
+            walled_moves: false,
+            endianness: Endian::Big,
+            commitment_locked: false,
+            alpha_regen: DEFAULT_ALPHA_REGEN,
+        };
+        // Initialize runtime immediately so callers don't need a separate initialize_session_runtime call.
+        let runtime = SessionRuntime {
+            chad_x,
+            chad_y,
+            battery: initial_battery,
+            turn: 0,
+            phase: TurnPhase::Dispatcher,
+            ended: false,
+            alpha: alpha_max,
+            pending_ping_tower: None,
+            moved_this_turn: false,
+            assassin_moves_this_turn: 0,
+            chad_hidden: false,
+            chad_hide_streak: 0,
+            last_action_ledger: env.ledger().sequence(),
+            last_d2: None,
+            last_d2_chad: None,
+            last_ping_tower: None,
+            last_ping_turn: 0,
+            free_action_used: false,
+            consecutive_recharges: 0,
+            last_ping_in_radius: None,
+        };
+        Self::store_session_runtime(&env, session_id, &runtime);
+        Self::index_player_session(&env, &core.dispatcher, session_id);
+        if core.assassin != core.dispatcher {
+            Self::index_player_session(&env, &core.assassin, session_id);
+        }
+        Self::store_session_core(&env, session_id, &core);
+        Ok(())
+    }
+
+    fn end_game_internal(
+        env: &Env,
+        session_id: u32,
+        s: &mut SessionRuntime,
+        dispatcher_won: bool,
+        reason: EndReason,
+    ) -> Result<(), Error> {
         let game_hub_addr: Address = env.storage().instance().get(&DataKey::GameHub).unwrap();
         let game_hub = GameHubClient::new(env, &game_hub_addr);
         game_hub.end_game(&session_id, &dispatcher_won);
+        HubEndGame {
+            session_id,
+            player1_won: dispatcher_won,
+        }
+        .publish(env);
         s.ended = true;
+        env.storage().instance().set(&DataKey::DispatcherWon(session_id), &dispatcher_won);
+        Self::record_end_reason(env, session_id, reason);
         Ok(())
     }
+    fn record_end_reason(env: &Env, session_id: u32, reason: EndReason) {
+        env.storage().instance().set(&DataKey::EndReason(session_id), &reason);
+        // Every way a game ends goes through here.
+        if let Ok(c) = Self::load_session_core(env, session_id) {
+            Self::mark_player_session_ended(env, &c.dispatcher, session_id);
+            if c.assassin != c.dispatcher {
+                Self::mark_player_session_ended(env, &c.assassin, session_id);
+            }
+        }
+    }
+    // Adds `session_id` and drops ended entries past `PLAYER_SESSION_TTL_LEDGERS`.
+    fn index_player_session(env: &Env, player: &Address, session_id: u32) {
+        let key = DataKey::PlayerSessions(player.clone());
+        let now = env.ledger().sequence();
+        let entries: Vec<PlayerSession> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        let mut kept = Vec::new(env);
+        for e in entries.iter() {
+            let expired = e
+                .ended_ledger
+                .is_some_and(|at| now.saturating_sub(at) > PLAYER_SESSION_TTL_LEDGERS);
+            if e.session_id != session_id && !expired {
+                kept.push_back(e);
+            }
+        }
+        kept.push_back(PlayerSession { session_id, ended_ledger: None });
+        env.storage().instance().set(&key, &kept);
+    }
+    fn unindex_player_session(env: &Env, player: &Address, session_id: u32) {
+        let key = DataKey::PlayerSessions(player.clone());
+        let Some(entries) = env.storage().instance().get::<_, Vec<PlayerSession>>(&key) else {
+            return;
+        };
+        let mut kept = Vec::new(env);
+        for e in entries.iter() {
+            if e.session_id != session_id {
+                kept.push_back(e);
+            }
+        }
+        env.storage().instance().set(&key, &kept);
+    }
+    fn mark_player_session_ended(env: &Env, player: &Address, session_id: u32) {
+        let key = DataKey::PlayerSessions(player.clone());
+        let Some(mut entries) = env.storage().instance().get::<_, Vec<PlayerSession>>(&key) else {
+            return;
+        };
+        for i in 0..entries.len() {
+            let mut e = entries.get_unchecked(i);
+            if e.session_id == session_id && e.ended_ledger.is_none() {
+                e.ended_ledger = Some(env.ledger().sequence());
+                entries.set(i, e);
+                env.storage().instance().set(&key, &entries);
+                return;
+            }
+        }
+    }
+
+    fn emit_chad_moved(env: &Env, session_id: u32, s: &SessionRuntime, command: ChadCommand) {
+        let command_tag = match command {
+            ChadCommand::Stay => 0,
+            ChadCommand::Hide => 1,
+            ChadCommand::GoRoom(_) => 2,
+            ChadCommand::WalkGarden(_) => 3,
+            ChadCommand::RunGarden(_) => 4,
+        };
+        ChadMoved {
+            session_id,
+            turn: s.turn,
+            chad_x: s.chad_x,
+            chad_y: s.chad_y,
+            command_tag,
+        }
+        .publish(env);
+    }
+}
+
+// Support/debug reads, kept out of production builds. Enable with the
+// `debug-dump` feature; tests always get them.
+#[cfg(any(test, feature = "debug-dump"))]
+#[contractimpl]
+impl ProofOfLife {
+    /// Support/debug dump: `(core, runtime, towers, (ping, turn_status, move) verifiers)`.
+    /// Verifiers are the ones this session resolves to (per-session override first).
+    #[allow(clippy::type_complexity)]
+    pub fn dump_state(
+        env: Env,
+        session_id: u32,
+    ) -> Result<(SessionCore, SessionRuntime, Towers, (Address, Address, Address)), Error> {
+        let (c, s) = Self::load_session_pair(&env, session_id)?;
+        let towers = Self::session_towers(&env, session_id);
+        let verifiers = match env
+            .storage()
+            .instance()
+            .get::<_, SessionVerifiers>(&DataKey::SessionVerifiers(session_id))
+        {
+            Some(v) => (v.ping_v, v.turn_v, v.move_v),
+            None => Self::get_verifiers(env.clone()),
+        };
+        Ok((c, s, towers, verifiers))
+    }
 }
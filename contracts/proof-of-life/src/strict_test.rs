@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+//! Tests for the `strict` feature. Run with `cargo test --features strict`.
+
+use crate::{Error, ProofOfLife, ProofOfLifeClient, Session};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    }
+
+    pub fn add_game(_env: Env, _game_address: Address) {
+    }
+}
+
+fn setup() -> (Env, ProofOfLifeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ProofOfLife, (&admin, &hub_addr));
+    let client = ProofOfLifeClient::new(&env, &contract_id);
+    let dispatcher = Address::generate(&env);
+    let assassin = Address::generate(&env);
+    (env, client, dispatcher, assassin)
+}
+
+#[test]
+fn strict_rejects_enabling_insecure_mode() {
+    let (_env, client, dispatcher, assassin) = setup();
+    client.start_game(&1u32, &dispatcher, &assassin, &5i128, &4i128);
+
+    let res = client.try_set_insecure_mode(&1u32, &true);
+    assert_eq!(res, Err(Ok(Error::InsecureModeDisabled)));
+    // Disabling is still a harmless no-op.
+    client.set_insecure_mode(&1u32, &false);
+    let s: Session = client.get_session(&1u32);
+    assert!(!s.insecure_mode);
+}
+
+#[test]
+fn strict_always_verifies_proofs() {
+    let (env, client, dispatcher, assassin) = setup();
+    client.start_game(&2u32, &dispatcher, &assassin, &5i128, &4i128);
+    client.commit_location(&2u32, &assassin, &BytesN::from_array(&env, &[7u8; 32]));
+    client.request_ping(&2u32, &dispatcher, &0u32);
+
+    // Public inputs are checked (and then the verifier called) even though the
+    // stored flag is never consulted; empty inputs fail the arity pre-flight.
+    let res = client.try_submit_ping_proof(
+        &2u32, &assassin, &0u32, &25u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_eq!(res, Err(Ok(Error::PublicInputArityMismatch)));
+}
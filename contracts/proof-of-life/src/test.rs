@@ -1,8 +1,8 @@
 #![cfg(test)]
 
-use crate::{Error, MoveProofEntry, ProofOfLife, ProofOfLifeClient, Role, Session, SessionKeyParams, TurnPhase, Towers};
+use crate::{Action, DataKey, EndReason, Error, GameConfig, MoveProofEntry, ProofOfLife, ProofOfLifeClient, ProofSubmission, Role, Session, SessionKeyParams, SessionVerifiers, TurnPhase, Towers};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -31,6 +31,92 @@ impl MockGameHub {
     }
 }
 
+// ============================================================================
+// Mock Verifier (accepts every proof) for secure-mode unit tests
+// ============================================================================
+
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn verify_proof(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes) {
+    }
+
+    pub fn verify_proof_in_domain(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes, _circuit_domain: u32) {
+    }
+}
+
+// Accepts every proof and reports the VK metadata it was built with.
+#[contract]
+pub struct MetaVerifier;
+
+#[contractimpl]
+impl MetaVerifier {
+    pub fn __constructor(env: Env, log_n: u64, public_inputs: u64) {
+        env.storage().instance().set(&symbol_short!("meta"), &(log_n, public_inputs));
+    }
+
+    pub fn verify_proof(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes) {
+    }
+
+    pub fn verify_proof_in_domain(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes, _circuit_domain: u32) {
+    }
+
+    pub fn vk_log_n(env: Env) -> u64 {
+        Self::meta(&env).0
+    }
+
+    pub fn vk_public_inputs(env: Env) -> u64 {
+        Self::meta(&env).1
+    }
+
+    pub fn expected_proof_len(env: Env) -> u64 {
+        (75 + 11 * Self::meta(&env).0) * 32
+    }
+
+    fn meta(env: &Env) -> (u64, u64) {
+        env.storage().instance().get(&symbol_short!("meta")).unwrap()
+    }
+}
+
+// Rejects every proof.
+#[contract]
+pub struct RejectingVerifier;
+
+#[contractimpl]
+impl RejectingVerifier {
+    pub fn verify_proof(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes) {
+        panic!("rejected");
+    }
+
+    pub fn verify_proof_in_domain(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes, _circuit_domain: u32) {
+        panic!("rejected");
+    }
+}
+
+// Only accepts proofs checked in the circuit domain it was built for.
+#[contract]
+pub struct DomainVerifier;
+
+#[contractimpl]
+impl DomainVerifier {
+    pub fn __constructor(env: Env, domain: u32) {
+        env.storage().instance().set(&symbol_short!("domain"), &domain);
+    }
+
+    pub fn verify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes) {
+        Self::verify_proof_in_domain(env, public_inputs, proof_bytes, 0);
+    }
+
+    pub fn verify_proof_in_domain(env: Env, _public_inputs: Bytes, _proof_bytes: Bytes, circuit_domain: u32) {
+        let domain: u32 = env.storage().instance().get(&symbol_short!("domain")).unwrap();
+        if circuit_domain != domain {
+            panic!("wrong circuit domain");
+        }
+    }
+}
+
 // ============================================================================
 // Test Helpers
 // ============================================================================
@@ -114,6 +200,13 @@ fn dummy_commitment(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[7u8; 32])
 }
 
+// A commitment other than the session's current one: a move has to land somewhere new.
+fn next_commitment(env: &Env, client: &ProofOfLifeClient, session_id: u32) -> BytesN<32> {
+    let mut bytes = client.get_session(&session_id).commitment.map_or([7u8; 32], |c| c.to_array());
+    bytes[0] = bytes[0].wrapping_add(1);
+    BytesN::from_array(env, &bytes)
+}
+
 fn b32_u32(env: &Env, v: u32) -> BytesN<32> {
     // Big-endian, matching the contract's `bytes32_from_u32`.
     let mut arr = [0u8; 32];
@@ -135,6 +228,44 @@ fn split_public_inputs(env: &Env, blob: &[u8]) -> soroban_sdk::Vec<BytesN<32>> {
     out
 }
 
+fn deploy_mock_verifiers(env: &Env, client: &ProofOfLifeClient) -> Address {
+    let verifier = env.register(MockVerifier, ());
+    client.set_verifiers(&verifier, &verifier, &verifier);
+    verifier
+}
+
+fn move_public_inputs(
+    env: &Env,
+    session_id: u32,
+    turn: u32,
+    old: &BytesN<32>,
+    new: &BytesN<32>,
+) -> soroban_sdk::Vec<BytesN<32>> {
+    move_step_public_inputs(env, session_id, turn, old, new, 1, 1)
+}
+
+// [session_id, turn, old, new, floorplan slot, steps_claimed, step_distance]
+fn move_step_public_inputs(
+    env: &Env,
+    session_id: u32,
+    turn: u32,
+    old: &BytesN<32>,
+    new: &BytesN<32>,
+    steps_claimed: u32,
+    distance: u32,
+) -> soroban_sdk::Vec<BytesN<32>> {
+    soroban_sdk::vec![
+        env,
+        b32_u32(env, session_id),
+        b32_u32(env, turn),
+        old.clone(),
+        new.clone(),
+        b32_u32(env, 0),
+        b32_u32(env, steps_claimed),
+        b32_u32(env, distance),
+    ]
+}
+
 fn deploy_ultrahonk_verifier(env: &Env, vk_bytes: &[u8]) -> Address {
     // We use the same verifier wasm embedded for contractimport in lib.rs.
     let wasm: &[u8] = include_bytes!("../bin/verifier.wasm");
@@ -217,11 +348,18 @@ fn recharge_caps_at_100_and_enforces_turn_order() {
     assert_eq!(s.battery, 90);
     assert_eq!(s.phase, TurnPhase::Assassin);
 
-    // Advance and recharge again (90 -> 100 cap).
+    // Advance and recharge again: back-to-back recharges are halved (90 -> 95).
     client.assassin_tick(&session_id, &assassin, &25u32);
     client.recharge(&session_id, &dispatcher);
     let s2: Session = client.get_session(&session_id);
-    assert_eq!(s2.battery, 100);
+    assert_eq!(s2.battery, 95);
+
+    // 95 -> 97 -> 98 -> 99 -> 100, then capped.
+    for _ in 0..5 {
+        client.assassin_tick(&session_id, &assassin, &25u32);
+        client.recharge(&session_id, &dispatcher);
+    }
+    assert_eq!(client.get_session(&session_id).battery, 100);
 }
 
 #[test]
@@ -319,7 +457,7 @@ fn submit_ping_proof_rejects_without_insecure_mode() {
         &soroban_sdk::vec![&env],
     );
     // Empty public_inputs fails immediately (before any verifier call).
-    assert_pol_error(&res, Error::ProofSessionMismatch);
+    assert_pol_error(&res, Error::PublicInputArityMismatch);
 }
 
 // ============================================================================
@@ -522,7 +660,7 @@ fn submit_turn_status_updates_alpha_and_can_end_game() {
         client.submit_move_proof(
             &session_id,
             &assassin,
-            &BytesN::from_array(&env, &[3u8; 32]),
+            &next_commitment(&env, &client, session_id),
             &Bytes::from_slice(&env, &[3u8]),
             &soroban_sdk::vec![&env],
         );
@@ -766,8 +904,7 @@ fn submit_turn_status_proof_rejects_when_d2_chad_does_not_match_public_output_fi
     let session_id = 201u32;
     client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
     client.set_insecure_mode(&session_id, &true);
-    let cmt = dummy_commitment(&env);
-    client.commit_location(&session_id, &assassin, &cmt);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
 
     // Move to assassin phase via ping (clears pending ping by submitting ping proof).
     client.request_ping(&session_id, &dispatcher, &0u32);
@@ -780,7 +917,8 @@ fn submit_turn_status_proof_rejects_when_d2_chad_does_not_match_public_output_fi
         &soroban_sdk::vec![&env],
     );
 
-    // Assassin must move before submitting status; keep commitment stable for this test.
+    // Assassin must move before submitting status.
+    let cmt = BytesN::from_array(&env, &[8u8; 32]);
     client.submit_move_proof(
         &session_id,
         &assassin,
@@ -893,14 +1031,7 @@ fn submit_move_proof_rejects_when_commitment_old_does_not_match_session_commitme
     let wrong_old = BytesN::from_array(&env, &[8u8; 32]);
     let new_cmt = BytesN::from_array(&env, &[9u8; 32]);
 
-    // UltraHonk public fields (v3): [session_id, turn, commitment_old, commitment_new]
-    let pis = soroban_sdk::vec![
-        &env,
-        b32_u32(&env, session_id),
-        b32_u32(&env, 0),
-        wrong_old,
-        new_cmt.clone(),
-    ];
+    let pis = move_public_inputs(&env, session_id, 0, &wrong_old, &new_cmt);
 
     let res = client.try_submit_move_proof(
         &session_id,
@@ -1517,7 +1648,7 @@ fn submit_multi_move_proof_chains_commitments_in_insecure_mode() {
 }
 
 #[test]
-fn submit_multi_move_proof_rejects_excess_moves() {
+fn submit_multi_move_proof_stops_at_move_budget() {
     let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
 
     let session_id = 301u32;
@@ -1549,6 +1680,14 @@ fn submit_multi_move_proof_rejects_excess_moves() {
         },
     ];
 
+    // Budget is 1: the first entry applies and the batch stops there.
+    let applied = client.submit_multi_move_proof(&session_id, &assassin, &entries);
+    assert_eq!(applied, 1);
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(BytesN::from_array(&env, &[11u8; 32])));
+    assert_eq!(s.assassin_moves_this_turn, 1);
+
+    // Nothing left to spend: the call is rejected outright.
     let res = client.try_submit_multi_move_proof(&session_id, &assassin, &entries);
     assert_pol_error(&res, Error::AlreadyMovedThisTurn);
 }
@@ -1589,3 +1728,3360 @@ fn submit_multi_move_proof_single_entry_works() {
     assert!(s.moved_this_turn);
     assert_eq!(s.assassin_moves_this_turn, 1);
 }
+
+// ============================================================================
+// do_turn: batched owner actions
+// ============================================================================
+
+#[test]
+fn do_turn_applies_actions_in_order_with_single_authorization() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, _assassin) = setup_test();
+
+    // Solo play so one owner can drive both phases in a single batch.
+    let session_id = 400u32;
+    client.start_game(&session_id, &dispatcher, &dispatcher, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+
+    let actions = soroban_sdk::vec![
+        &env,
+        Action::CommitLocation(dummy_commitment(&env)),
+        Action::Dispatch(0u32, crate::ChadCommand::Stay),
+        Action::AssassinTick,
+        Action::Recharge(crate::ChadCommand::Stay),
+    ];
+    client.do_turn(&session_id, &dispatcher, &actions);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, dispatcher);
+
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(dummy_commitment(&env)));
+    assert_eq!(s.battery, 90);
+    assert_eq!(s.turn, 1);
+    assert_eq!(s.phase, TurnPhase::Assassin);
+    assert_eq!(s.pending_ping_tower, None);
+}
+
+#[test]
+fn do_turn_rolls_back_on_first_failure() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 401u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+
+    // Second dispatch violates turn order.
+    let actions = soroban_sdk::vec![
+        &env,
+        Action::Dispatch(0u32, crate::ChadCommand::Stay),
+        Action::Dispatch(1u32, crate::ChadCommand::Stay),
+    ];
+    let res = client.try_do_turn(&session_id, &dispatcher, &actions);
+    assert_pol_error(&res, Error::NotDispatcherTurn);
+
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.battery, 100);
+    assert_eq!(s.phase, TurnPhase::Dispatcher);
+    assert_eq!(s.pending_ping_tower, None);
+}
+
+#[test]
+fn do_turn_enforces_role_per_action() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 402u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    let actions = soroban_sdk::vec![&env, Action::AssassinTick];
+    let res = client.try_do_turn(&session_id, &dispatcher, &actions);
+    assert_pol_error(&res, Error::NotAssassin);
+
+    let outsider = Address::generate(&env);
+    let res = client.try_do_turn(&session_id, &outsider, &actions);
+    assert_pol_error(&res, Error::NotSessionPlayer);
+}
+
+// ============================================================================
+// moves_remaining_this_turn
+// ============================================================================
+
+#[test]
+fn moves_remaining_is_zero_outside_assassin_phase() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 410u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 0);
+}
+
+#[test]
+fn moves_remaining_when_hidden_after_one_move() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 411u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Hide);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &250u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 6);
+
+    client.submit_move_proof(
+        &session_id, &assassin,
+        &BytesN::from_array(&env, &[11u8; 32]),
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 5);
+}
+
+#[test]
+fn moves_remaining_when_not_hidden_after_one_move() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 412u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &250u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 1);
+
+    client.submit_move_proof(
+        &session_id, &assassin,
+        &BytesN::from_array(&env, &[11u8; 32]),
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 0);
+}
+
+// ============================================================================
+// Floorplan: room spawn points
+// ============================================================================
+
+#[test]
+fn room_spawn_points_are_walkable_and_inside_their_room() {
+    for &(room, x, y) in &crate::floorplan::ROOM_SPAWNS {
+        assert!(crate::floorplan::in_bounds(x, y));
+        assert!(!crate::floorplan::is_blocked_tile(x, y), "spawn for {} is blocked", room as char);
+        assert_eq!(crate::floorplan::get_room_code(x, y), room);
+    }
+    // The sealed Winter garden has no spawn.
+    assert_eq!(crate::floorplan::room_spawn(b'W'), None);
+}
+
+#[test]
+fn room_spawn_points_match_previous_go_room_fallback() {
+    let expected: [(u8, (u32, u32)); 7] = [
+        (b'G', (5, 1)),
+        (b'L', (1, 4)),
+        (b'S', (8, 4)),
+        (b'B', (1, 7)),
+        (b'D', (8, 6)),
+        (b'K', (8, 8)),
+        (b'E', (4, 8)),
+    ];
+    for (room, pos) in expected {
+        assert_eq!(crate::floorplan::room_spawn(room), Some(pos));
+    }
+    // The old hallway fallback (4,5) was a blocked Winter tile; it now uses the adjacent hallway tile.
+    assert_eq!(crate::floorplan::room_spawn(b'H'), Some((4, 6)));
+}
+
+#[test]
+fn go_room_without_door_uses_floorplan_spawn() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // Chad starts in the Grand Hall (4,7); there is no direct door to the Garden.
+    let session_id = 420u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(0));
+
+    let s: Session = client.get_session(&session_id);
+    assert_eq!((s.chad_x, s.chad_y), crate::floorplan::room_spawn(b'G').unwrap());
+}
+
+#[test]
+fn submit_multi_move_proof_reports_partial_success_on_bad_commitment() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 303u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    let c0 = dummy_commitment(&env);
+    client.commit_location(&session_id, &assassin, &c0);
+
+    // Hidden Chad allows up to 6 moves; insecure mode only for the ping step.
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Hide);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &250u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    client.set_insecure_mode(&session_id, &false);
+
+    let c1 = BytesN::from_array(&env, &[31u8; 32]);
+    let c2 = BytesN::from_array(&env, &[32u8; 32]);
+    let c3 = BytesN::from_array(&env, &[33u8; 32]);
+    let wrong = BytesN::from_array(&env, &[99u8; 32]);
+
+    let entries = soroban_sdk::vec![
+        &env,
+        MoveProofEntry {
+            new_commitment: c1.clone(),
+            proof: Bytes::from_slice(&env, &[1u8]),
+            public_inputs: move_public_inputs(&env, session_id, 0, &c0, &c1),
+        },
+        // Chain is off by one: old commitment should be c1.
+        MoveProofEntry {
+            new_commitment: c2.clone(),
+            proof: Bytes::from_slice(&env, &[2u8]),
+            public_inputs: move_public_inputs(&env, session_id, 0, &wrong, &c2),
+        },
+        MoveProofEntry {
+            new_commitment: c3.clone(),
+            proof: Bytes::from_slice(&env, &[3u8]),
+            public_inputs: move_public_inputs(&env, session_id, 0, &c2, &c3),
+        },
+    ];
+
+    let applied = client.submit_multi_move_proof(&session_id, &assassin, &entries);
+    assert_eq!(applied, 1);
+
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(c1));
+    assert_eq!(s.assassin_moves_this_turn, 1);
+}
+
+// ============================================================================
+// get_session_summary
+// ============================================================================
+
+#[test]
+fn session_summary_matches_full_session_for_fresh_game() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 430u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    let s: Session = client.get_session(&session_id);
+    let (d, a, ended, turn) = client.get_session_summary(&session_id);
+    assert_eq!(d, s.dispatcher);
+    assert_eq!(a, s.assassin);
+    assert_eq!(ended, s.ended);
+    assert_eq!(turn, s.turn);
+}
+
+#[test]
+fn session_summary_rejects_unknown_session() {
+    let (_env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+    let res = client.try_get_session_summary(&999u32);
+    assert_pol_error(&res, Error::SessionNotFound);
+}
+
+// ============================================================================
+// commit_and_prepare
+// ============================================================================
+
+#[test]
+fn commit_and_prepare_commits_and_reports_ready() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 440u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    let ready = client.commit_and_prepare(&session_id, &assassin, &dummy_commitment(&env));
+    assert!(ready);
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(dummy_commitment(&env)));
+
+    let res = client.try_commit_and_prepare(&session_id, &assassin, &dummy_commitment(&env));
+    assert_pol_error(&res, Error::CommitmentAlreadySet);
+}
+
+#[test]
+fn commit_and_prepare_reports_not_ready_without_verifier_in_secure_mode() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 441u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    let ready = client.commit_and_prepare(&session_id, &assassin, &dummy_commitment(&env));
+    assert!(!ready);
+    // The commitment is still recorded.
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(dummy_commitment(&env)));
+}
+
+#[test]
+fn commit_and_prepare_is_ready_in_insecure_mode_without_verifier() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 442u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+
+    assert!(client.commit_and_prepare(&session_id, &assassin, &dummy_commitment(&env)));
+}
+
+// ============================================================================
+// Assassin pass while Chad is hidden
+// ============================================================================
+
+#[test]
+fn turn_status_allows_zero_moves_when_chad_hidden() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 450u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Hide);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &250u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    client.submit_turn_status_proof(
+        &session_id, &assassin, &50u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.turn, 1);
+    assert_eq!(s.phase, TurnPhase::Dispatcher);
+    assert_eq!(s.commitment, Some(dummy_commitment(&env)));
+}
+
+#[test]
+fn turn_status_still_requires_move_when_chad_visible() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 451u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &250u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    let res = client.try_submit_turn_status_proof(
+        &session_id, &assassin, &50u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::AssassinMustMove);
+}
+
+// ============================================================================
+// Turn timeout
+// ============================================================================
+
+#[test]
+fn claim_timeout_rejected_before_deadline() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 460u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.set_turn_timeout(&session_id, &10u32);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.phase, TurnPhase::Assassin);
+    assert_eq!(s.last_action_ledger, 100);
+
+    env.ledger().set_sequence_number(110);
+    let res = client.try_claim_timeout(&session_id, &dispatcher);
+    assert_pol_error(&res, Error::TimeoutNotReached);
+
+    // The player whose phase it is cannot claim against their opponent.
+    env.ledger().set_sequence_number(200);
+    let res = client.try_claim_timeout(&session_id, &assassin);
+    assert_pol_error(&res, Error::TimeoutNotReached);
+
+    let s: Session = client.get_session(&session_id);
+    assert!(!s.ended);
+}
+
+#[test]
+fn claim_timeout_ends_game_past_deadline() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 461u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.set_turn_timeout(&session_id, &10u32);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+
+    env.ledger().set_sequence_number(111);
+    client.claim_timeout(&session_id, &dispatcher);
+
+    let s: Session = client.get_session(&session_id);
+    assert!(s.ended);
+}
+
+#[test]
+fn claim_timeout_disabled_by_default() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 462u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    env.ledger().set_sequence_number(10_000);
+    let res = client.try_claim_timeout(&session_id, &assassin);
+    assert_pol_error(&res, Error::TimeoutNotReached);
+
+    let outsider = Address::generate(&env);
+    let res = client.try_claim_timeout(&session_id, &outsider);
+    assert_pol_error(&res, Error::NotSessionPlayer);
+}
+
+// ============================================================================
+// Delegate reverse lookup
+// ============================================================================
+
+#[test]
+fn delegate_scopes_lists_every_authorized_session() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let delegate = Address::generate(&env);
+    client.start_game(&470u32, &dispatcher, &assassin, &5i128, &4i128);
+    client.start_game(&471u32, &dispatcher, &assassin, &5i128, &4i128);
+    client.authorize_session_key(&dispatcher, &470u32, &delegate, &100u32, &10u32, &1u32, &0u32);
+    client.authorize_session_key(&assassin, &471u32, &delegate, &100u32, &10u32, &0u32, &4u32);
+
+    let scopes = client.get_delegate_scopes(&delegate);
+    assert_eq!(scopes.len(), 2);
+    assert!(scopes.contains((470u32, 0u32)));
+    assert!(scopes.contains((471u32, 1u32)));
+
+    // Re-authorizing the same scope does not duplicate it.
+    client.authorize_session_key(&dispatcher, &470u32, &delegate, &200u32, &10u32, &1u32, &0u32);
+    assert_eq!(client.get_delegate_scopes(&delegate).len(), 2);
+
+    client.revoke_session_key(&dispatcher, &470u32, &Role::Dispatcher);
+    assert_eq!(client.get_delegate_scopes(&delegate), soroban_sdk::vec![&env, (471u32, 1u32)]);
+}
+
+#[test]
+fn delegate_scopes_moves_to_replacement_delegate() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let old_delegate = Address::generate(&env);
+    let new_delegate = Address::generate(&env);
+    let sk_params = SessionKeyParams {
+        delegate: old_delegate.clone(),
+        ttl_ledgers: 100,
+        max_writes: 50,
+        dispatcher_allow_mask: 1,
+        assassin_allow_mask: 0,
+    };
+    client.start_game_with_session_key(&472u32, &dispatcher, &assassin, &sk_params);
+    assert_eq!(client.get_delegate_scopes(&old_delegate), soroban_sdk::vec![&env, (472u32, 0u32)]);
+
+    client.authorize_session_key(&dispatcher, &472u32, &new_delegate, &100u32, &10u32, &1u32, &0u32);
+    assert!(client.get_delegate_scopes(&old_delegate).is_empty());
+    assert_eq!(client.get_delegate_scopes(&new_delegate), soroban_sdk::vec![&env, (472u32, 0u32)]);
+}
+
+// ============================================================================
+// Active session key scope
+// ============================================================================
+
+#[test]
+fn active_session_key_scope_is_none_after_expiry() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 490u32;
+    let delegate = Address::generate(&env);
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.authorize_session_key(&dispatcher, &session_id, &delegate, &10u32, &10u32, &1u32, &0u32);
+
+    env.ledger().set_sequence_number(110);
+    assert!(client.get_active_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).is_some());
+
+    env.ledger().set_sequence_number(111);
+    assert!(client.get_active_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).is_none());
+    // The raw read is unchanged and nothing was removed.
+    assert!(client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).is_some());
+}
+
+#[test]
+fn sweep_expired_session_key_removes_only_expired_scopes() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 491u32;
+    let delegate = Address::generate(&env);
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.authorize_session_key(&dispatcher, &session_id, &delegate, &10u32, &10u32, &1u32, &0u32);
+
+    assert!(!client.sweep_expired_session_key(&dispatcher, &session_id, &Role::Dispatcher));
+    assert!(client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).is_some());
+
+    env.ledger().set_sequence_number(111);
+    assert!(client.sweep_expired_session_key(&dispatcher, &session_id, &Role::Dispatcher));
+    assert!(client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).is_none());
+    assert!(client.get_delegate_scopes(&delegate).is_empty());
+}
+
+// ============================================================================
+// Configurable hide streak
+// ============================================================================
+
+#[test]
+fn max_hide_streak_three_allows_third_consecutive_hide() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 500u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.set_max_hide_streak(&session_id, &dispatcher, &3u32);
+    assert_eq!(client.get_session(&session_id).max_hide_streak, 3);
+
+    for _ in 0..3 {
+        client.dispatcher_command(&session_id, &dispatcher, &crate::ChadCommand::Hide);
+        client.assassin_tick(&session_id, &assassin, &25u32);
+    }
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.chad_hide_streak, 3);
+
+    let res = client.try_dispatcher_command(&session_id, &dispatcher, &crate::ChadCommand::Hide);
+    assert_pol_error(&res, Error::InvalidHide);
+}
+
+#[test]
+fn max_hide_streak_zero_disables_hiding() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 501u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.set_max_hide_streak(&session_id, &dispatcher, &0u32);
+
+    let res = client.try_dispatcher_command(&session_id, &dispatcher, &crate::ChadCommand::Hide);
+    assert_pol_error(&res, Error::InvalidHide);
+}
+
+#[test]
+fn set_max_hide_streak_requires_dispatcher() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 502u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    assert_eq!(client.get_session(&session_id).max_hide_streak, 2);
+
+    let res = client.try_set_max_hide_streak(&session_id, &assassin, &5u32);
+    assert_pol_error(&res, Error::NotDispatcher);
+}
+
+#[test]
+fn set_max_hide_streak_rejected_after_first_dispatch() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 664u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.dispatcher_command(&session_id, &dispatcher, &crate::ChadCommand::Hide);
+
+    let res = client.try_set_max_hide_streak(&session_id, &dispatcher, &0u32);
+    assert_pol_error(&res, Error::GameAlreadyStarted);
+    client.assassin_tick(&session_id, &assassin, &25u32);
+    let res = client.try_set_max_hide_streak(&session_id, &dispatcher, &9u32);
+    assert_pol_error(&res, Error::GameAlreadyStarted);
+    assert_eq!(client.get_session(&session_id).max_hide_streak, 2);
+}
+
+// ============================================================================
+// Constant-time comparison
+// ============================================================================
+
+#[test]
+fn ct_eq_bytes32_agrees_with_eq() {
+    let env = Env::default();
+    let a = BytesN::from_array(&env, &[7u8; 32]);
+    assert!(crate::utils::ct_eq_bytes32(&a, &BytesN::from_array(&env, &[7u8; 32])));
+
+    for idx in [0usize, 15, 31] {
+        let mut arr = [7u8; 32];
+        arr[idx] ^= 0x80;
+        let b = BytesN::from_array(&env, &arr);
+        assert_eq!(crate::utils::ct_eq_bytes32(&a, &b), a == b);
+        assert!(!crate::utils::ct_eq_bytes32(&a, &b));
+    }
+}
+
+// ============================================================================
+// start_game_secure (per-session verifiers)
+// ============================================================================
+
+fn secure_config(verifier: &Address) -> GameConfig {
+    GameConfig {
+        chad_x: 4,
+        chad_y: 7,
+        alpha_max: 5,
+        strong_radius_sq: 4,
+        verifiers: SessionVerifiers {
+            ping_v: verifier.clone(),
+            turn_v: verifier.clone(),
+            move_v: verifier.clone(),
+            coarse_ping_v: None,
+        },
+    }
+}
+
+#[test]
+fn start_game_secure_verifies_ping_without_global_verifiers() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 510u32;
+    let verifier = env.register(MockVerifier, ());
+    client.set_verifier_approved(&verifier, &true);
+    client.start_game_secure(&session_id, &dispatcher, &assassin, &secure_config(&verifier));
+
+    let s: Session = client.get_session(&session_id);
+    assert!(!s.insecure_mode);
+    assert_eq!((s.chad_x, s.chad_y), (4, 7));
+
+    client.request_ping(&session_id, &dispatcher, &1u32); // East tower (9,5)
+    let commitment = dummy_commitment(&env);
+    let pis = soroban_sdk::vec![
+        &env,
+        b32_u32(&env, 9),
+        b32_u32(&env, 5),
+        b32_u32(&env, session_id),
+        b32_u32(&env, 0),
+        commitment.clone(),
+        b32_u32(&env, 13),
+    ];
+    let d2 = client.submit_ping_proof(
+        &session_id, &assassin, &1u32, &13u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &pis,
+    );
+    assert_eq!(d2, 13);
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(commitment));
+}
+
+#[test]
+fn plain_session_still_needs_global_verifiers() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // A per-session override on one game does not leak into another.
+    let verifier = env.register(MockVerifier, ());
+    client.set_verifier_approved(&verifier, &true);
+    client.start_game_secure(&511u32, &dispatcher, &assassin, &secure_config(&verifier));
+
+    let session_id = 512u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.request_ping(&session_id, &dispatcher, &1u32);
+    let pis = soroban_sdk::vec![
+        &env,
+        b32_u32(&env, 9),
+        b32_u32(&env, 5),
+        b32_u32(&env, session_id),
+        b32_u32(&env, 0),
+        dummy_commitment(&env),
+        b32_u32(&env, 13),
+    ];
+    let res = client.try_submit_ping_proof(
+        &session_id, &assassin, &1u32, &13u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &pis,
+    );
+    assert_pol_error(&res, Error::VerifierNotSet);
+}
+
+#[test]
+fn coarse_ping_uses_session_verifier_override() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // The global coarse verifier would reject; the session's own one decides.
+    let rejecting = env.register(RejectingVerifier, ());
+    client.set_coarse_ping_verifier(&rejecting);
+    let verifier = env.register(MockVerifier, ());
+    client.set_verifier_approved(&verifier, &true);
+    let mut config = secure_config(&verifier);
+    config.verifiers.coarse_ping_v = Some(verifier.clone());
+
+    let session_id = 662u32;
+    client.start_game_secure(&session_id, &dispatcher, &assassin, &config);
+    client.set_coarse_ping(&session_id, &true);
+    assert!(client.session_ready_for_secure_play(&session_id));
+
+    client.request_ping(&session_id, &dispatcher, &1u32); // East tower (9,5)
+    let pis = soroban_sdk::vec![
+        &env,
+        b32_u32(&env, 9),
+        b32_u32(&env, 5),
+        b32_u32(&env, session_id),
+        b32_u32(&env, 0),
+        dummy_commitment(&env),
+        b32_u32(&env, 4),
+        b32_u32(&env, 0),
+    ];
+    let in_radius = client.submit_ping_proof_coarse(
+        &session_id, &assassin, &1u32, &false, &Bytes::from_slice(&env, &[1u8]), &pis,
+    );
+    assert!(!in_radius);
+}
+
+#[test]
+fn start_game_secure_rejects_unapproved_verifiers() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // A player-deployed verifier could accept anything.
+    let verifier = env.register(MockVerifier, ());
+    let res = client.try_start_game_secure(&656u32, &dispatcher, &assassin, &secure_config(&verifier));
+    assert_pol_error(&res, Error::VerifierNotApproved);
+    assert_pol_error(&client.try_get_session(&656u32), Error::SessionNotFound);
+
+    // Approval is per verifier: one unapproved slot is enough to refuse.
+    let approved = env.register(MockVerifier, ());
+    client.set_verifier_approved(&approved, &true);
+    let mut config = secure_config(&approved);
+    config.verifiers.move_v = verifier.clone();
+    let res = client.try_start_game_secure(&656u32, &dispatcher, &assassin, &config);
+    assert_pol_error(&res, Error::VerifierNotApproved);
+
+    client.set_verifier_approved(&verifier, &true);
+    client.start_game_secure(&656u32, &dispatcher, &assassin, &config);
+    assert!(client.is_verifier_approved(&verifier));
+
+    client.set_verifier_approved(&verifier, &false);
+    assert!(!client.is_verifier_approved(&verifier));
+    let res = client.try_start_game_secure(&657u32, &dispatcher, &assassin, &config);
+    assert_pol_error(&res, Error::VerifierNotApproved);
+}
+
+// ============================================================================
+// Proof audit log
+// ============================================================================
+
+#[test]
+fn verify_and_record_logs_ping_result() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 520u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    assert_eq!(client.get_proof_log(&session_id, &0u32, &0u32), None);
+
+    let ok = client.verify_and_record(
+        &session_id, &assassin, &0u32, &0u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env, dummy_commitment(&env)],
+    );
+    assert!(ok);
+    assert_eq!(client.get_proof_log(&session_id, &0u32, &0u32), Some(true));
+    assert_eq!(client.get_proof_log(&session_id, &0u32, &1u32), None);
+
+    let res = client.try_verify_and_record(
+        &session_id, &assassin, &0u32, &3u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::InvalidProofKind);
+}
+
+#[test]
+fn verify_and_record_logs_failure() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let verifier = env.register(RejectingVerifier, ());
+    client.set_verifiers(&verifier, &verifier, &verifier);
+
+    let session_id = 521u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let ok = client.verify_and_record(
+        &session_id, &assassin, &0u32, &2u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert!(!ok);
+    assert_eq!(client.get_proof_log(&session_id, &0u32, &2u32), Some(false));
+}
+
+#[test]
+fn verify_and_record_requires_player_and_current_turn() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 658u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    let pis = soroban_sdk::vec![&env, dummy_commitment(&env)];
+
+    let outsider = Address::generate(&env);
+    let res = client.try_verify_and_record(&session_id, &outsider, &0u32, &0u32, &proof, &pis);
+    assert_pol_error(&res, Error::NotSessionPlayer);
+    // Entries for other turns can't be written ahead of time or back-filled.
+    let res = client.try_verify_and_record(&session_id, &assassin, &1u32, &0u32, &proof, &pis);
+    assert_pol_error(&res, Error::ProofTurnMismatch);
+    assert_eq!(client.get_proof_log(&session_id, &1u32, &0u32), None);
+
+    assert!(client.verify_and_record(&session_id, &dispatcher, &0u32, &0u32, &proof, &pis));
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, dispatcher);
+
+    client.admin_force_end(&session_id, &false);
+    let res = client.try_verify_and_record(&session_id, &assassin, &0u32, &1u32, &proof, &pis);
+    assert_pol_error(&res, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Flat session view
+// ============================================================================
+
+#[test]
+fn session_flat_matches_full_session() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 530u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::WalkGarden(1));
+
+    let s: Session = client.get_session(&session_id);
+    let flat = client.get_session_flat(&session_id);
+    assert_eq!(
+        flat,
+        (
+            s.turn,
+            1u32,
+            s.battery,
+            s.alpha,
+            s.alpha_max,
+            s.chad_x,
+            s.ended,
+            s.chad_y,
+            s.assassin_moves_this_turn,
+        )
+    );
+    assert_eq!(s.phase, TurnPhase::Assassin);
+}
+
+// ============================================================================
+// Spectator view
+// ============================================================================
+
+#[test]
+fn spectator_view_surfaces_last_distances() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 540u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let v: Session = client.spectator_view(&session_id);
+    assert_eq!((v.d2, v.d2_chad), (None, None));
+
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &17u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    client.submit_move_proof(
+        &session_id, &assassin, &next_commitment(&env, &client, session_id),
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    client.submit_turn_status_proof(
+        &session_id, &assassin, &8u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+
+    let v: Session = client.spectator_view(&session_id);
+    assert_eq!(v.d2, Some(17));
+    assert_eq!(v.d2_chad, Some(8));
+    assert_eq!(v, client.get_session(&session_id));
+}
+
+// ============================================================================
+// Live strong_radius_sq adjustment
+// ============================================================================
+
+/// Plays one insecure-mode turn ending with the given `d2_chad`.
+fn play_turn_with_d2_chad(
+    env: &Env,
+    client: &ProofOfLifeClient,
+    session_id: u32,
+    dispatcher: &Address,
+    assassin: &Address,
+    d2_chad: u32,
+) {
+    client.request_ping(&session_id, dispatcher, &0u32);
+    client.submit_ping_proof(
+        &session_id, assassin, &0u32, &25u32,
+        &Bytes::from_slice(env, &[1u8]),
+        &soroban_sdk::vec![env],
+    );
+    client.submit_move_proof(
+        &session_id, assassin, &next_commitment(env, client, session_id),
+        &Bytes::from_slice(env, &[1u8]),
+        &soroban_sdk::vec![env],
+    );
+    client.submit_turn_status_proof(
+        &session_id, assassin, &d2_chad,
+        &Bytes::from_slice(env, &[1u8]),
+        &soroban_sdk::vec![env],
+    );
+}
+
+#[test]
+fn set_strong_radius_sq_flips_alpha_direction() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 550u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 2);
+    assert_eq!(client.get_session(&session_id).alpha, 4);
+
+    // radius 4: d2_chad = 8 is weak, alpha goes up.
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 8);
+    assert_eq!(client.get_session(&session_id).alpha, 5);
+
+    // radius 9: the same d2_chad is now strong, alpha goes down.
+    client.set_strong_radius_sq(&session_id, &9u32);
+    assert_eq!(client.get_session(&session_id).strong_radius_sq, 9);
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 8);
+    assert_eq!(client.get_session(&session_id).alpha, 4);
+}
+
+#[test]
+fn set_strong_radius_sq_rejected_after_game_end() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 551u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 0);
+    assert!(client.get_session(&session_id).ended);
+
+    let res = client.try_set_strong_radius_sq(&session_id, &9u32);
+    assert_pol_error(&res, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// grant_alpha power-up
+// ============================================================================
+
+#[test]
+fn grant_alpha_restores_alpha_up_to_max() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 560u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    assert_eq!(client.get_alpha_max(&session_id), 5);
+    client.set_grant_alpha_cost(&session_id, &5u32);
+
+    for _ in 0..4 {
+        play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 1);
+    }
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.alpha, 1);
+    let battery = s.battery;
+
+    client.grant_alpha(&session_id, &dispatcher, &2u32);
+    let s: Session = client.get_session(&session_id);
+    assert_eq!(s.alpha, 3);
+    assert_eq!(s.battery, battery - 5);
+
+    client.grant_alpha(&session_id, &dispatcher, &10u32);
+    assert_eq!(client.get_session(&session_id).alpha, 5);
+}
+
+#[test]
+fn grant_alpha_rejects_low_battery_and_delegate_without_flag() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 561u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_grant_alpha_cost(&session_id, &101u32);
+    let res = client.try_grant_alpha(&session_id, &dispatcher, &1u32);
+    assert_pol_error(&res, Error::BatteryTooLow);
+
+    let delegate = Address::generate(&env);
+    client.authorize_session_key(&dispatcher, &session_id, &delegate, &100u32, &10u32, &1u32, &0u32);
+    let res = client.try_grant_alpha(&session_id, &delegate, &1u32);
+    assert_pol_error(&res, Error::SessionKeyMethodNotAllowed);
+}
+
+// ============================================================================
+// batch_authorize_session_keys
+// ============================================================================
+
+#[test]
+fn batch_authorize_session_keys_covers_every_session() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let delegate = Address::generate(&env);
+    let other = Address::generate(&env);
+    for sid in [570u32, 571, 572] {
+        client.start_game(&sid, &dispatcher, &assassin, &5i128, &4i128);
+    }
+    // The owner plays no role here; it must be skipped.
+    client.start_game(&573u32, &other, &assassin, &5i128, &4i128);
+
+    let p = SessionKeyParams {
+        delegate: delegate.clone(),
+        ttl_ledgers: 100,
+        max_writes: 10,
+        dispatcher_allow_mask: 1,
+        assassin_allow_mask: 0,
+    };
+    let params = soroban_sdk::vec![
+        &env,
+        (570u32, p.clone()),
+        (571u32, p.clone()),
+        (572u32, p.clone()),
+        (573u32, p.clone()),
+    ];
+    client.batch_authorize_session_keys(&dispatcher, &params);
+
+    for sid in [570u32, 571, 572] {
+        let scope = client.get_session_key_scope(&dispatcher, &sid, &Role::Dispatcher).unwrap();
+        assert_eq!(scope.delegate, delegate);
+        assert_eq!(scope.allow_mask, 1);
+    }
+    assert!(client.get_session_key_scope(&dispatcher, &573u32, &Role::Dispatcher).is_none());
+    assert_eq!(client.get_delegate_scopes(&delegate).len(), 3);
+}
+
+// ============================================================================
+// free_dispatch
+// ============================================================================
+
+#[test]
+fn free_dispatch_is_free_once_per_game() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 574u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.free_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    let s = client.get_session(&session_id);
+    assert_eq!(s.battery, 100);
+    assert_eq!(s.phase, TurnPhase::Assassin);
+    assert!(s.free_action_used);
+
+    client.assassin_tick(&session_id, &assassin, &25u32);
+    let res = client.try_free_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_pol_error(&res, Error::FreeActionUsed);
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_eq!(client.get_session(&session_id).battery, 80);
+}
+
+// ============================================================================
+// whose_turn / get_turn_phase
+// ============================================================================
+
+#[test]
+fn whose_turn_follows_the_phase() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 575u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    assert_eq!(client.whose_turn(&session_id), dispatcher);
+    assert_eq!(client.get_turn_phase(&session_id), TurnPhase::Dispatcher);
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_eq!(client.whose_turn(&session_id), assassin);
+    assert_eq!(client.get_turn_phase(&session_id), TurnPhase::Assassin);
+
+    let res = client.try_whose_turn(&999u32);
+    assert_pol_error(&res, Error::SessionNotFound);
+}
+
+// ============================================================================
+// commitment_matches
+// ============================================================================
+
+#[test]
+fn commitment_matches_compares_against_stored_commitment() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 576u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let res = client.try_commitment_matches(&session_id, &dummy_commitment(&env));
+    assert_pol_error(&res, Error::CommitmentNotSet);
+
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    assert!(client.commitment_matches(&session_id, &dummy_commitment(&env)));
+    assert!(!client.commitment_matches(&session_id, &BytesN::from_array(&env, &[9u8; 32])));
+}
+
+// ============================================================================
+// reset_session_key_writes
+// ============================================================================
+
+#[test]
+fn reset_session_key_writes_restores_budget() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 577u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let delegate = Address::generate(&env);
+    client.authorize_session_key(&dispatcher, &session_id, &delegate, &100u32, &2u32, &1u32, &0u32);
+    for _ in 0..2 {
+        client.dispatch(&session_id, &delegate, &0u32, &crate::ChadCommand::Stay);
+        client.assassin_tick(&session_id, &assassin, &25u32);
+    }
+    let res = client.try_dispatch(&session_id, &delegate, &0u32, &crate::ChadCommand::Stay);
+    assert_pol_error(&res, Error::SessionKeyWriteLimitExceeded);
+
+    let before = client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).unwrap();
+    client.reset_session_key_writes(&dispatcher, &session_id, &Role::Dispatcher);
+    let after = client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).unwrap();
+    assert_eq!(after.writes_used, 0);
+    assert_eq!(after.expires_ledger, before.expires_ledger);
+    assert_eq!(after.allow_mask, before.allow_mask);
+
+    client.dispatch(&session_id, &delegate, &0u32, &crate::ChadCommand::Stay);
+
+    let res = client.try_reset_session_key_writes(&dispatcher, &session_id, &Role::Assassin);
+    assert_pol_error(&res, Error::SessionKeyNotAuthorized);
+}
+
+// ============================================================================
+// submit_proof_once
+// ============================================================================
+
+#[test]
+fn submit_proof_once_skips_duplicate_nonce() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 578u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.request_ping(&session_id, &dispatcher, &0u32);
+
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    let ping = ProofSubmission::Ping(assassin.clone(), 0, 25, proof.clone(), soroban_sdk::vec![&env]);
+    assert!(client.submit_proof_once(&session_id, &1u64, &ping));
+    assert_eq!(client.get_session(&session_id).pending_ping_tower, None);
+
+    // Same nonce: no-op success instead of a "no ping pending" rejection.
+    assert!(!client.submit_proof_once(&session_id, &1u64, &ping));
+
+    // A fresh nonce is applied normally.
+    let res = client.try_submit_proof_once(&session_id, &2u64, &ping);
+    assert_pol_error(&res, Error::UnexpectedTower);
+    let mv = ProofSubmission::Move(assassin.clone(), next_commitment(&env, &client, session_id), proof.clone(), soroban_sdk::vec![&env]);
+    assert!(client.submit_proof_once(&session_id, &3u64, &mv));
+    assert_eq!(client.get_session(&session_id).assassin_moves_this_turn, 1);
+
+    // A turn-status retry is recognised after the turn has advanced.
+    let status = ProofSubmission::TurnStatus(assassin.clone(), 25, proof, soroban_sdk::vec![&env]);
+    assert!(client.submit_proof_once(&session_id, &4u64, &status));
+    let turn = client.get_session(&session_id).turn;
+    assert!(!client.submit_proof_once(&session_id, &4u64, &status));
+    assert_eq!(client.get_session(&session_id).turn, turn);
+}
+
+// ============================================================================
+// admin_repair_runtime
+// ============================================================================
+
+/// Simulates a lost runtime entry.
+fn delete_runtime(env: &Env, client: &ProofOfLifeClient, session_id: u32) {
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::SessionRuntime(session_id));
+    });
+}
+
+#[test]
+fn admin_repair_runtime_rebuilds_missing_runtime() {
+    let (env, client, _hub, _hub_addr, admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 579u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+
+    delete_runtime(&env, &client, session_id);
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_pol_error(&res, Error::RuntimeNotInitialized);
+
+    client.admin_repair_runtime(&session_id);
+    let s = client.get_session(&session_id);
+    assert_eq!((s.chad_x, s.chad_y), (4, 7));
+    assert_eq!(s.battery, 100);
+    assert_eq!(s.phase, TurnPhase::Dispatcher);
+
+    // Repairing an intact runtime changes nothing.
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    client.admin_repair_runtime(&session_id);
+    assert_eq!(client.get_session(&session_id).battery, 80);
+
+    // The admin may also go through initialize_session_runtime.
+    client.initialize_session_runtime(&session_id, &admin);
+    let stranger = Address::generate(&env);
+    let res = client.try_initialize_session_runtime(&session_id, &stranger);
+    assert!(res.is_err());
+}
+
+// ============================================================================
+// dump_state
+// ============================================================================
+
+#[test]
+fn dump_state_reflects_mid_game() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let verifier = deploy_mock_verifiers(&env, &client);
+
+    let session_id = 580u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 1);
+    client.request_ping(&session_id, &dispatcher, &1u32);
+
+    let (core, runtime, towers, verifiers) = client.dump_state(&session_id);
+    assert_eq!(core.dispatcher, dispatcher);
+    assert_eq!(core.commitment, client.get_session(&session_id).commitment);
+    assert_eq!(runtime.turn, 1);
+    assert_eq!(runtime.phase, TurnPhase::Assassin);
+    assert_eq!(runtime.pending_ping_tower, Some(1));
+    assert_eq!(runtime.battery, 60);
+    assert_eq!(runtime.last_d2_chad, Some(1));
+    assert_eq!(towers, client.get_towers());
+    assert_eq!(verifiers, (verifier.clone(), verifier.clone(), verifier));
+}
+
+// ============================================================================
+// d2_chad range
+// ============================================================================
+
+#[test]
+fn impossible_d2_chad_is_rejected() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 581u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &Bytes::from_slice(&env, &[1u8]), &soroban_sdk::vec![&env]);
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &Bytes::from_slice(&env, &[1u8]), &soroban_sdk::vec![&env]);
+
+    let max = crate::floorplan::max_dist_sq();
+    for d2_chad in [max + 1, u32::MAX] {
+        let res = client.try_submit_turn_status_proof(
+            &session_id, &assassin, &d2_chad,
+            &Bytes::from_slice(&env, &[1u8]),
+            &soroban_sdk::vec![&env],
+        );
+        assert_pol_error(&res, Error::DistanceOutOfRange);
+    }
+
+    client.submit_turn_status_proof(&session_id, &assassin, &max, &Bytes::from_slice(&env, &[1u8]), &soroban_sdk::vec![&env]);
+    assert_eq!(client.get_session(&session_id).d2_chad, Some(max));
+}
+
+// ============================================================================
+// Ready check
+// ============================================================================
+
+#[test]
+fn ready_check_blocks_dispatch_until_both_ready() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 582u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.set_ready_check(&session_id, &true);
+
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_pol_error(&res, Error::NotReady);
+
+    // The assassin cannot be ready without a committed location.
+    let res = client.try_mark_ready(&session_id, &assassin);
+    assert_pol_error(&res, Error::CommitmentNotSet);
+    let res = client.try_mark_ready(&session_id, &Address::generate(&env));
+    assert_pol_error(&res, Error::NotSessionPlayer);
+
+    client.mark_ready(&session_id, &dispatcher);
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_pol_error(&res, Error::NotReady);
+
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.mark_ready(&session_id, &assassin);
+    let s = client.get_session(&session_id);
+    assert!(s.dispatcher_ready && s.assassin_ready);
+
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_eq!(client.get_session(&session_id).phase, TurnPhase::Assassin);
+}
+
+// ============================================================================
+// Configured vs. defaulted verifiers
+// ============================================================================
+
+#[test]
+fn verifiers_configured_flips_after_set_verifiers() {
+    let (env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+
+    assert_eq!(client.verifiers_configured(), (false, false, false));
+    // The defaulted addresses alone can't tell the difference.
+    let (p, _, _) = client.get_verifiers();
+    assert_eq!(p, client.address);
+
+    deploy_mock_verifiers(&env, &client);
+    assert_eq!(client.verifiers_configured(), (true, true, true));
+}
+
+// ============================================================================
+// Distance consistency
+// ============================================================================
+
+#[test]
+fn inconsistent_ping_and_turn_status_distances_are_rejected() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 583u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.set_distance_tolerance(&session_id, &1u32);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Tower N (5,0) is ~7 tiles from Chad (4,7): an assassin cannot be 1 tile
+    // from both.
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &1u32, &proof, &soroban_sdk::vec![&env]);
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+    let res = client.try_submit_turn_status_proof(&session_id, &assassin, &1u32, &proof, &soroban_sdk::vec![&env]);
+    assert_pol_error(&res, Error::DistanceInconsistent);
+
+    // 1 tile from the tower and 6 from Chad fits within the tolerance.
+    client.submit_turn_status_proof(&session_id, &assassin, &36u32, &proof, &soroban_sdk::vec![&env]);
+    assert_eq!(client.get_session(&session_id).turn, 1);
+
+    // Disabled by default.
+    let other = 584u32;
+    client.start_game(&other, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&other, &true);
+    client.commit_location(&other, &assassin, &dummy_commitment(&env));
+    play_turn_with_d2_chad(&env, &client, other, &dispatcher, &assassin, 1);
+    assert_eq!(client.get_session(&other).turn, 1);
+}
+
+// ============================================================================
+// Per-session tower snapshots
+// ============================================================================
+
+#[test]
+fn backfill_session_towers_snapshots_global_towers() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 585u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let global = client.get_towers();
+    assert_eq!(client.get_session_towers(&session_id), global);
+    let has_snapshot = |env: &Env| {
+        env.as_contract(&client.address, || env.storage().instance().has(&DataKey::SessionTowers(session_id)))
+    };
+    assert!(!has_snapshot(&env));
+
+    client.backfill_session_towers(&session_id);
+    assert!(has_snapshot(&env));
+
+    // The snapshot no longer follows global changes.
+    let moved = Towers { n_x: 1, n_y: 2, ..global };
+    client.set_towers(&moved);
+    assert_eq!(client.get_session_towers(&session_id), global);
+
+    // Backfilling again keeps the existing snapshot.
+    client.backfill_session_towers(&session_id);
+    assert_eq!(client.get_session_towers(&session_id), global);
+}
+
+// ============================================================================
+// End reasons
+// ============================================================================
+
+#[test]
+fn end_reason_records_kill() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 586u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    assert_eq!(client.get_end_reason(&session_id), None);
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 0);
+    assert!(client.get_session(&session_id).ended);
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::Kill));
+}
+
+#[test]
+fn end_reason_records_alpha_depleted() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 587u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let alpha = client.get_session(&session_id).alpha;
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env]);
+    for _ in 0..alpha {
+        client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+        // Strong signal (<= strong_radius_sq) costs one alpha.
+        client.submit_turn_status_proof(&session_id, &assassin, &4u32, &proof, &soroban_sdk::vec![&env]);
+        if !client.get_session(&session_id).ended {
+            client.recharge(&session_id, &dispatcher);
+        }
+    }
+    assert_eq!(client.get_session(&session_id).alpha, 0);
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::AlphaDepleted));
+}
+
+#[test]
+fn end_reason_records_battery_depleted() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 588u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Spend the battery down to exactly one ping, then dispatch it away.
+    client.set_grant_alpha_cost(&session_id, &80u32);
+    client.grant_alpha(&session_id, &dispatcher, &0u32);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+
+    let s = client.get_session(&session_id);
+    assert_eq!(s.battery, 0);
+    assert!(s.ended);
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::BatteryDepleted));
+}
+
+// ============================================================================
+// Turn status with a pending ping
+// ============================================================================
+
+#[test]
+fn turn_status_rejected_while_ping_pending() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 589u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    let res = client.try_submit_turn_status_proof(
+        &session_id, &assassin, &25u32, &proof, &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::PendingPingExists);
+
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env]);
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+    client.submit_turn_status_proof(&session_id, &assassin, &25u32, &proof, &soroban_sdk::vec![&env]);
+    assert_eq!(client.get_session(&session_id).pending_ping_tower, None);
+    assert_eq!(client.get_session(&session_id).turn, 1);
+}
+
+// ============================================================================
+// Dispatch preview
+// ============================================================================
+
+#[test]
+fn preview_dispatch_matches_real_dispatch() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 590u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Door from the Grand Hall into the Hallway, then the Garden spawn fallback.
+    for cmd in [crate::ChadCommand::GoRoom(1), crate::ChadCommand::GoRoom(0)] {
+        let before = client.get_session(&session_id);
+        let preview = client.preview_dispatch(&session_id, &cmd);
+        let after_preview = client.get_session(&session_id);
+        assert_eq!(after_preview, before);
+
+        client.dispatch(&session_id, &dispatcher, &0u32, &cmd);
+        let s = client.get_session(&session_id);
+        assert_eq!((s.chad_x, s.chad_y), preview);
+
+        let tower = s.pending_ping_tower.unwrap();
+        client.submit_ping_proof(&session_id, &assassin, &tower, &25u32, &proof, &soroban_sdk::vec![&env]);
+        client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+        client.submit_turn_status_proof(&session_id, &assassin, &25u32, &proof, &soroban_sdk::vec![&env]);
+    }
+
+    let preview = client.preview_dispatch(&session_id, &crate::ChadCommand::WalkGarden(1));
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::WalkGarden(1));
+    let s = client.get_session(&session_id);
+    assert_eq!((s.chad_x, s.chad_y), preview);
+}
+
+#[test]
+fn preview_dispatch_rejects_bad_garden_direction() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 591u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let res = client.try_preview_dispatch(&session_id, &crate::ChadCommand::WalkGarden(4));
+    assert_pol_error(&res, Error::InvalidMove);
+}
+
+// ============================================================================
+// Field encoding probe
+// ============================================================================
+
+#[test]
+fn probe_field_encoding_returns_both_byte_orders() {
+    let (env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+
+    let (be, le) = client.probe_field_encoding(&0x0102_0304u32);
+    let mut expected_be = [0u8; 32];
+    expected_be[28..].copy_from_slice(&[1, 2, 3, 4]);
+    let mut expected_le = [0u8; 32];
+    expected_le[..4].copy_from_slice(&[4, 3, 2, 1]);
+    assert_eq!(be, BytesN::from_array(&env, &expected_be));
+    assert_eq!(le, BytesN::from_array(&env, &expected_le));
+}
+
+// ============================================================================
+// Chad path events
+// ============================================================================
+
+/// Data of the last event with topics `(topic0, topic1)` from the most recent invocation.
+fn last_event_data(env: &Env, topic0: &str, topic1: &str) -> Option<soroban_sdk::Val> {
+    use soroban_sdk::{testutils::Events as _, xdr, Symbol, TryFromVal, Val};
+
+    let all = env.events().all();
+    all.events().iter().rev().find_map(|e| {
+        let xdr::ContractEventBody::V0(body) = &e.body;
+        let topic = |i: usize| {
+            let v = Val::try_from_val(env, body.topics.get(i)?).ok()?;
+            Symbol::try_from_val(env, &v).ok()
+        };
+        if topic(0)? != Symbol::new(env, topic0) || topic(1)? != Symbol::new(env, topic1) {
+            return None;
+        }
+        Val::try_from_val(env, &body.data).ok()
+    })
+}
+
+/// `(session_id, turn, chad_x, chad_y, command_tag)` of the last `("chad", "moved")` event.
+fn last_chad_moved(env: &Env) -> Option<(u32, u32, u32, u32, u32)> {
+    use soroban_sdk::{TryFromVal, Vec};
+
+    let data = last_event_data(env, "chad", "moved")?;
+    let v = Vec::<u32>::try_from_val(env, &data).ok()?;
+    Some((v.get(0)?, v.get(1)?, v.get(2)?, v.get(3)?, v.get(4)?))
+}
+
+#[test]
+fn chad_moved_events_reconstruct_path() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 592u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let commands = [
+        (crate::ChadCommand::GoRoom(1), 2u32),
+        (crate::ChadCommand::GoRoom(0), 2),
+        (crate::ChadCommand::WalkGarden(1), 3),
+        (crate::ChadCommand::Stay, 0),
+    ];
+    let mut replay: soroban_sdk::Vec<(u32, u32)> = soroban_sdk::vec![&env];
+    let mut actual: soroban_sdk::Vec<(u32, u32)> = soroban_sdk::vec![&env];
+    for (turn, (cmd, tag)) in commands.iter().enumerate() {
+        client.dispatch(&session_id, &dispatcher, &0u32, cmd);
+        let (sid, ev_turn, x, y, ev_tag) = last_chad_moved(&env).expect("chad moved event");
+        assert_eq!((sid, ev_turn, ev_tag), (session_id, turn as u32, *tag));
+        replay.push_back((x, y));
+
+        let s = client.get_session(&session_id);
+        actual.push_back((s.chad_x, s.chad_y));
+
+        let tower = s.pending_ping_tower.unwrap();
+        client.submit_ping_proof(&session_id, &assassin, &tower, &25u32, &proof, &soroban_sdk::vec![&env]);
+        client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+        client.submit_turn_status_proof(&session_id, &assassin, &25u32, &proof, &soroban_sdk::vec![&env]);
+    }
+    assert_eq!(replay, actual);
+
+    // Recharge also moves Chad and is part of the trail.
+    client.recharge_with_command(&session_id, &dispatcher, &crate::ChadCommand::WalkGarden(3));
+    let event = last_chad_moved(&env);
+    let s = client.get_session(&session_id);
+    assert_eq!(event, Some((session_id, s.turn, s.chad_x, s.chad_y, 3)));
+}
+
+// ============================================================================
+// move_budget
+// ============================================================================
+
+#[test]
+fn move_budget_not_hidden() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 593u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_eq!(client.move_budget(&session_id), (1, 0, 1));
+}
+
+#[test]
+fn move_budget_hidden() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 594u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Hide);
+    let (base, bonus, total) = client.move_budget(&session_id);
+    assert_eq!((base, bonus, total), (1, 5, 6));
+    assert_eq!(client.moves_remaining_this_turn(&session_id), total);
+}
+
+// ============================================================================
+// Recharge diminishing returns
+// ============================================================================
+
+#[test]
+fn consecutive_recharges_diminish_and_dispatch_resets() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 595u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Drain some battery first so the gains are not clipped by the cap.
+    for _ in 0..2 {
+        play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 25);
+    }
+    assert_eq!(client.get_session(&session_id).battery, 60);
+
+    let mut gains = [0u32; 4];
+    for gain in gains.iter_mut() {
+        let before = client.get_session(&session_id).battery;
+        client.recharge(&session_id, &dispatcher);
+        *gain = client.get_session(&session_id).battery - before;
+        client.assassin_tick(&session_id, &assassin, &25u32);
+    }
+    assert_eq!(gains, [10, 5, 2, 1]);
+    assert_eq!(client.get_session(&session_id).consecutive_recharges, 4);
+
+    // A dispatch resets the streak: the next recharge is full again.
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_eq!(client.get_session(&session_id).consecutive_recharges, 0);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env]);
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+    client.submit_turn_status_proof(&session_id, &assassin, &25u32, &proof, &soroban_sdk::vec![&env]);
+
+    let before = client.get_session(&session_id).battery;
+    client.recharge(&session_id, &dispatcher);
+    assert_eq!(client.get_session(&session_id).battery - before, 10);
+}
+
+// ============================================================================
+// Hub call events
+// ============================================================================
+
+#[test]
+fn hub_call_events_record_start_and_winner() {
+    use soroban_sdk::TryFromVal;
+
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 596u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let data = last_event_data(&env, "hub_call", "start_game").expect("start_game hub call");
+    let (sid, p1, p2, _p1_points, _p2_points) =
+        <(u32, Address, Address, i128, i128)>::try_from_val(&env, &data).unwrap();
+    assert_eq!((sid, p1, p2), (session_id, dispatcher.clone(), assassin.clone()));
+
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // d2_chad = 0 is a kill: the assassin (player2) wins.
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env]);
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+    client.submit_turn_status_proof(&session_id, &assassin, &0u32, &proof, &soroban_sdk::vec![&env]);
+    let data = last_event_data(&env, "hub_call", "end_game").expect("end_game hub call");
+    let (sid, player1_won) = <(u32, bool)>::try_from_val(&env, &data).unwrap();
+    assert_eq!((sid, player1_won), (session_id, false));
+}
+
+// ============================================================================
+// Verifier metadata passthrough
+// ============================================================================
+
+#[test]
+fn verifier_metadata_passthrough() {
+    let (env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+
+    assert_pol_error(&client.try_get_move_verifier_metadata(), Error::VerifierNotSet);
+
+    let ping_v = env.register(MetaVerifier, (12u64, 22u64));
+    let turn_v = env.register(MetaVerifier, (13u64, 6u64));
+    let move_v = env.register(MetaVerifier, (14u64, 7u64));
+    client.set_verifiers(&ping_v, &turn_v, &move_v);
+    assert_eq!(client.get_ping_verifier_metadata(), (12, 22, (75 + 11 * 12) * 32));
+    assert_eq!(client.get_turn_verifier_metadata(), (13, 6, (75 + 11 * 13) * 32));
+    assert_eq!(client.get_move_verifier_metadata(), (14, 7, (75 + 11 * 14) * 32));
+}
+
+// ============================================================================
+// Commitment grace period
+// ============================================================================
+
+#[test]
+fn recommit_allowed_within_grace_then_locked() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 597u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.set_commit_grace_turns(&session_id, &1u32);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let fixed = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &fixed);
+    assert_eq!(client.get_session(&session_id).commitment, Some(fixed.clone()));
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 25);
+    let res = client.try_commit_location(&session_id, &assassin, &fixed);
+    assert_pol_error(&res, Error::CommitmentAlreadySet);
+}
+
+#[test]
+fn recommit_rejected_without_grace() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 598u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    let res = client.try_commit_location(&session_id, &assassin, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_pol_error(&res, Error::CommitmentAlreadySet);
+}
+
+#[test]
+fn do_turn_commit_location_follows_commit_location_rules() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 660u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_commit_grace_turns(&session_id, &1u32);
+    let commit = |c: &BytesN<32>| soroban_sdk::vec![&env, Action::CommitLocation(c.clone())];
+
+    client.do_turn(&session_id, &assassin, &commit(&dummy_commitment(&env)));
+    // Inside the grace window a batched re-commit replaces the first one.
+    let fixed = BytesN::from_array(&env, &[9u8; 32]);
+    client.do_turn(&session_id, &assassin, &commit(&fixed));
+    assert_eq!(client.get_session(&session_id).commitment, Some(fixed.clone()));
+
+    client.finalize_commitment(&session_id, &assassin);
+    let res = client.try_do_turn(&session_id, &assassin, &commit(&fixed));
+    assert_pol_error(&res, Error::CommitmentMismatch);
+
+    let session_id = 661u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.do_turn(&session_id, &assassin, &commit(&dummy_commitment(&env)));
+    let res = client.try_do_turn(&session_id, &assassin, &commit(&fixed));
+    assert_pol_error(&res, Error::CommitmentAlreadySet);
+}
+
+// ============================================================================
+// Coarse ping
+// ============================================================================
+
+#[test]
+fn coarse_ping_reveals_only_radius_bit() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 599u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Exact sessions reject the coarse entrypoint.
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    let res = client.try_submit_ping_proof_coarse(
+        &session_id, &assassin, &0u32, &true, &proof, &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::PingModeMismatch);
+
+    // ...and coarse sessions reject exact pings.
+    client.set_coarse_ping(&session_id, &true);
+    let res = client.try_submit_ping_proof(
+        &session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::PingModeMismatch);
+
+    let in_radius = client.submit_ping_proof_coarse(
+        &session_id, &assassin, &0u32, &true, &proof, &soroban_sdk::vec![&env],
+    );
+    assert!(in_radius);
+    let s = client.get_session(&session_id);
+    assert!(s.coarse_ping);
+    assert_eq!(s.ping_in_radius, Some(true));
+    assert_eq!(s.d2, None);
+    assert_eq!(s.pending_ping_tower, None);
+
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+    client.submit_turn_status_proof(&session_id, &assassin, &25u32, &proof, &soroban_sdk::vec![&env]);
+    assert_eq!(client.get_session(&session_id).turn, 1);
+}
+
+// ============================================================================
+// Verifier circuit layout check
+// ============================================================================
+
+#[test]
+fn set_verifiers_rejects_wrong_public_input_count() {
+    let (env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+
+    let ping_v = env.register(MetaVerifier, (12u64, 6u64));
+    let turn_v = env.register(MetaVerifier, (12u64, 6u64));
+    let move_v = env.register(MetaVerifier, (12u64, 7u64));
+
+    // A move circuit wired as the ping verifier.
+    let res = client.try_set_verifiers(&move_v, &turn_v, &move_v);
+    assert_pol_error(&res, Error::VerifierCircuitMismatch);
+    // A ping circuit wired as the move verifier.
+    let res = client.try_set_verifiers(&ping_v, &turn_v, &ping_v);
+    assert_pol_error(&res, Error::VerifierCircuitMismatch);
+    // A move circuit without the step fields could prove any distance.
+    let legacy_move_v = env.register(MetaVerifier, (12u64, 4u64));
+    let res = client.try_set_verifiers(&ping_v, &turn_v, &legacy_move_v);
+    assert_pol_error(&res, Error::VerifierCircuitMismatch);
+    assert_pol_error(&client.try_set_walled_move_verifier(&legacy_move_v), Error::VerifierCircuitMismatch);
+    assert_pol_error(&client.try_get_ping_verifier_metadata(), Error::VerifierNotSet);
+
+    client.set_verifiers(&ping_v, &turn_v, &move_v);
+    assert_eq!(client.get_verifiers(), (ping_v, turn_v, move_v));
+}
+
+// ============================================================================
+// Default-mask session key authorizers
+// ============================================================================
+
+#[test]
+fn authorize_dispatcher_key_grants_dispatcher_gameplay_methods() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 600u32;
+    let delegate = Address::generate(&env);
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.authorize_dispatcher_key(&dispatcher, &session_id, &delegate, &100u32, &10u32);
+
+    let scope = client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).unwrap();
+    // dispatch | recharge | grant_alpha
+    assert_eq!(scope.allow_mask, (1 << 0) | (1 << 1) | (1 << 9));
+    assert_eq!(client.get_session_key_scope(&dispatcher, &session_id, &Role::Assassin), None);
+
+    client.dispatch(&session_id, &delegate, &0u32, &crate::ChadCommand::Stay);
+    assert_eq!(client.get_session(&session_id).phase, TurnPhase::Assassin);
+
+    let res = client.try_authorize_dispatcher_key(&assassin, &session_id, &delegate, &100u32, &10u32);
+    assert_pol_error(&res, Error::NotDispatcher);
+}
+
+#[test]
+fn authorize_assassin_key_grants_assassin_gameplay_methods() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 601u32;
+    let delegate = Address::generate(&env);
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.authorize_assassin_key(&assassin, &session_id, &delegate, &100u32, &10u32);
+
+    let scope = client.get_session_key_scope(&assassin, &session_id, &Role::Assassin).unwrap();
+    // commit_location | ping | move | turn_status | assassin_tick
+    assert_eq!(scope.allow_mask, (1 << 2) | (1 << 3) | (1 << 4) | (1 << 5) | (1 << 6));
+    assert_eq!(client.get_session_key_scope(&assassin, &session_id, &Role::Dispatcher), None);
+
+    client.commit_location(&session_id, &delegate, &dummy_commitment(&env));
+    assert_eq!(client.get_session(&session_id).commitment, Some(dummy_commitment(&env)));
+
+    let res = client.try_authorize_assassin_key(&dispatcher, &session_id, &delegate, &100u32, &10u32);
+    assert_pol_error(&res, Error::NotAssassin);
+}
+
+// ============================================================================
+// Start position / garden walk bounds
+// ============================================================================
+
+#[test]
+fn start_game_ext_rejects_invalid_start_tile() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // Off the grid.
+    let res = client.try_start_game_ext(&602u32, &dispatcher, &assassin, &10u32, &0u32, &5u32, &4u32);
+    assert_pol_error(&res, Error::InvalidStartPosition);
+    // Blocked tile (0, 9).
+    let res = client.try_start_game_ext(&603u32, &dispatcher, &assassin, &0u32, &9u32, &5u32, &4u32);
+    assert_pol_error(&res, Error::InvalidStartPosition);
+    assert!(client.try_get_session(&602u32).is_err());
+}
+
+#[test]
+fn garden_walk_off_the_grid_leaves_chad_in_place() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 604u32;
+    client.start_game_ext(&session_id, &dispatcher, &assassin, &9u32, &0u32, &5u32, &4u32);
+
+    // East from the right edge would have been (10, 0).
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::WalkGarden(1));
+    let s = client.get_session(&session_id);
+    assert_eq!((s.chad_x, s.chad_y), (9, 0));
+}
+
+// ============================================================================
+// Secure-play readiness
+// ============================================================================
+
+#[test]
+fn secure_session_without_verifiers_is_not_ready() {
+    use soroban_sdk::{Symbol, TryFromVal, Val, Vec};
+
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 605u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    assert!(!client.session_ready_for_secure_play(&session_id));
+    let data = last_event_data(&env, "secure", "not_ready").expect("not_ready event");
+    let v = Vec::<Val>::try_from_val(&env, &data).unwrap();
+    assert_eq!(u32::try_from_val(&env, &v.get(0).unwrap()).unwrap(), session_id);
+    let missing = Vec::<Symbol>::try_from_val(&env, &v.get(1).unwrap()).unwrap();
+    assert_eq!(
+        missing,
+        soroban_sdk::vec![&env, symbol_short!("ping"), symbol_short!("turn"), symbol_short!("move")]
+    );
+
+    // Insecure sessions never wait on verifiers.
+    client.set_insecure_mode(&session_id, &true);
+    assert!(client.session_ready_for_secure_play(&session_id));
+}
+
+#[test]
+fn secure_session_with_verifiers_is_ready() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 606u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    assert!(client.session_ready_for_secure_play(&session_id));
+
+    // Coarse mode needs its own ping verifier.
+    client.set_coarse_ping(&session_id, &true);
+    assert!(!client.session_ready_for_secure_play(&session_id));
+    let coarse_v = env.register(MockVerifier, ());
+    client.set_coarse_ping_verifier(&coarse_v);
+    assert!(client.session_ready_for_secure_play(&session_id));
+
+    let res = client.try_session_ready_for_secure_play(&999_999u32);
+    assert_pol_error(&res, Error::SessionNotFound);
+}
+
+// ============================================================================
+// Bundled assassin end of turn
+// ============================================================================
+
+#[test]
+fn assassin_end_turn_applies_move_and_advances_turn() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 607u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.recharge(&session_id, &dispatcher);
+    let new_cmt = BytesN::from_array(&env, &[9u8; 32]);
+    let entry = MoveProofEntry {
+        new_commitment: new_cmt.clone(),
+        proof: Bytes::from_slice(&env, &[1u8]),
+        public_inputs: soroban_sdk::vec![&env],
+    };
+    client.assassin_end_turn(
+        &session_id,
+        &assassin,
+        &Some(entry),
+        &25u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+
+    let s = client.get_session(&session_id);
+    assert_eq!(s.turn, 1);
+    assert_eq!(s.phase, TurnPhase::Dispatcher);
+    assert_eq!(s.commitment, Some(new_cmt));
+}
+
+#[test]
+fn assassin_end_turn_rejects_pending_ping() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 608u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.request_ping(&session_id, &dispatcher, &0u32);
+
+    let res = client.try_assassin_end_turn(
+        &session_id,
+        &assassin,
+        &None,
+        &25u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::PendingPingExists);
+    assert_eq!(client.get_session(&session_id).turn, 0);
+}
+
+// ============================================================================
+// Wall-aware move proofs
+// ============================================================================
+
+#[test]
+fn walled_moves_require_floorplan_binding() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 609u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    client.set_walled_moves(&session_id, &true);
+    assert!(client.get_session(&session_id).walled_moves);
+
+    let old = dummy_commitment(&env);
+    let new = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    // A plain move proof says nothing about walls: it could step through one.
+    let plain = move_public_inputs(&env, session_id, 0, &old, &new);
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &plain);
+    assert_pol_error(&res, Error::FloorplanMismatch);
+
+    // Bound to some other floorplan.
+    let mut other = plain.clone();
+    other.set(4, b32_u32(&env, crate::floorplan::FLOORPLAN_ID ^ 1));
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &other);
+    assert_pol_error(&res, Error::FloorplanMismatch);
+
+    let mut walled = plain.clone();
+    walled.set(4, b32_u32(&env, crate::floorplan::FLOORPLAN_ID));
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &walled);
+    assert_pol_error(&res, Error::VerifierNotSet);
+    assert!(!client.session_ready_for_secure_play(&session_id));
+
+    let walled_v = env.register(MockVerifier, ());
+    client.set_walled_move_verifier(&walled_v);
+    client.submit_move_proof(&session_id, &assassin, &new, &proof, &walled);
+    assert_eq!(client.get_session(&session_id).commitment, Some(new));
+}
+
+#[test]
+fn walled_move_rejected_when_verifier_rejects_transition() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 610u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    client.set_walled_moves(&session_id, &true);
+    // The wall-aware circuit has no valid proof for an illegal step.
+    let rejecting = env.register(RejectingVerifier, ());
+    client.set_walled_move_verifier(&rejecting);
+
+    let old = dummy_commitment(&env);
+    let new = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+
+    let mut pis = move_public_inputs(&env, session_id, 0, &old, &new);
+    pis.set(4, b32_u32(&env, crate::floorplan::FLOORPLAN_ID));
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &Bytes::from_slice(&env, &[1u8]), &pis);
+    assert_pol_error(&res, Error::InvalidProof);
+    assert_eq!(client.get_session(&session_id).commitment, Some(old));
+}
+
+// ============================================================================
+// Player session index
+// ============================================================================
+
+#[test]
+fn player_sessions_cover_both_roles() {
+    let (env, client, _hub, _hub_addr, _admin, player, other) = setup_test();
+    let third = Address::generate(&env);
+
+    client.start_game(&611u32, &player, &other, &5i128, &4i128);
+    client.start_game(&612u32, &player, &third, &5i128, &4i128);
+    client.start_game(&613u32, &other, &player, &5i128, &4i128);
+
+    assert_eq!(
+        client.get_player_sessions(&player, &false),
+        soroban_sdk::vec![&env, 611u32, 612u32, 613u32]
+    );
+    assert_eq!(client.get_player_sessions(&third, &false), soroban_sdk::vec![&env, 612u32]);
+}
+
+#[test]
+fn ended_player_sessions_are_hidden_then_pruned() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    client.start_game(&614u32, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&614u32, &true);
+    client.commit_location(&614u32, &assassin, &dummy_commitment(&env));
+    // d2_chad = 0: the assassin reaches Chad and the game ends.
+    play_turn_with_d2_chad(&env, &client, 614, &dispatcher, &assassin, 0);
+    assert!(client.get_session(&614u32).ended);
+
+    client.start_game(&615u32, &dispatcher, &assassin, &5i128, &4i128);
+    assert_eq!(client.get_player_sessions(&assassin, &false), soroban_sdk::vec![&env, 615u32]);
+    assert_eq!(client.get_player_sessions(&assassin, &true), soroban_sdk::vec![&env, 614u32, 615u32]);
+
+    // Long after the end, the next start prunes the finished game.
+    env.ledger().with_mut(|l| l.sequence_number += 17_281);
+    client.start_game(&616u32, &dispatcher, &assassin, &5i128, &4i128);
+    assert_eq!(
+        client.get_player_sessions(&assassin, &true),
+        soroban_sdk::vec![&env, 615u32, 616u32]
+    );
+}
+
+// ============================================================================
+// Session archival
+// ============================================================================
+
+#[test]
+fn archived_session_keeps_only_its_digest() {
+    use soroban_sdk::xdr::ToXdr;
+
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 617u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let res = client.try_archive_session(&session_id, &dispatcher);
+    assert_pol_error(&res, Error::GameNotEnded);
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 0);
+    let s = client.get_session(&session_id);
+    let expected: BytesN<32> = env
+        .crypto()
+        .sha256(
+            &(
+                session_id,
+                dispatcher.clone(),
+                assassin.clone(),
+                Some(EndReason::Kill),
+                crate::Outcome::AssassinWon,
+                s.turn,
+                s.battery,
+                s.alpha,
+                s.chad_x,
+                s.chad_y,
+                Some(0u32),
+            )
+                .to_xdr(&env),
+        )
+        .into();
+
+    let digest = client.archive_session(&session_id, &dispatcher);
+    assert_eq!(digest, expected);
+    assert_eq!(client.get_session_archive(&session_id), digest);
+
+    assert_pol_error(&client.try_get_session(&session_id), Error::SessionNotFound);
+    let gone = env.as_contract(&client.address, || {
+        let storage = env.storage().instance();
+        !storage.has(&DataKey::SessionCore(session_id)) && !storage.has(&DataKey::SessionRuntime(session_id))
+    });
+    assert!(gone);
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::Kill));
+}
+
+#[test]
+fn archive_session_drops_proof_logs_scopes_and_winner() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 663u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    let delegate = Address::generate(&env);
+    client.authorize_session_key(&assassin, &session_id, &delegate, &100u32, &0u32, &0u32, &1u32);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    client.verify_and_record(&session_id, &dispatcher, &0u32, &0u32, &proof, &soroban_sdk::vec![&env]);
+    assert_eq!(client.get_proof_log(&session_id, &0u32, &0u32), Some(true));
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 0);
+    client.archive_session(&session_id, &assassin);
+
+    assert_eq!(client.get_proof_log(&session_id, &0u32, &0u32), None);
+    assert_eq!(client.get_session_key_scope(&assassin, &session_id, &Role::Assassin), None);
+    assert_eq!(client.get_delegate_scopes(&delegate).len(), 0);
+    let won_kept = env.as_contract(&client.address, || {
+        env.storage().instance().has(&DataKey::DispatcherWon(session_id))
+    });
+    assert!(!won_kept);
+    assert!(client.get_player_sessions(&dispatcher, &true).is_empty());
+    assert!(client.get_player_sessions(&assassin, &true).is_empty());
+}
+
+#[test]
+fn archive_session_requires_a_player_or_the_admin() {
+    let (env, client, _hub, _hub_addr, admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 668u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.admin_force_end(&session_id, &true);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_archive_session(&session_id, &stranger);
+    assert_pol_error(&res, Error::NotSessionPlayer);
+
+    client.archive_session(&session_id, &admin);
+    assert!(client.try_get_session_archive(&session_id).is_ok());
+}
+
+#[test]
+fn archive_of_unknown_session_is_not_found() {
+    let (_env, client, _hub, _hub_addr, admin, _dispatcher, _assassin) = setup_test();
+
+    assert_pol_error(&client.try_get_session_archive(&618u32), Error::SessionNotFound);
+    assert_pol_error(&client.try_archive_session(&618u32, &admin), Error::SessionNotFound);
+}
+
+// ============================================================================
+// Single-verifier rotation
+// ============================================================================
+
+#[test]
+fn set_move_verifier_leaves_ping_and_turn_untouched() {
+    let (env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+
+    let ping_v = env.register(MetaVerifier, (12u64, 6u64));
+    let turn_v = env.register(MetaVerifier, (12u64, 6u64));
+    let move_v = env.register(MetaVerifier, (12u64, 7u64));
+    client.set_verifiers(&ping_v, &turn_v, &move_v);
+
+    let rotated = env.register(MetaVerifier, (13u64, 7u64));
+    client.set_move_verifier(&rotated);
+    assert_eq!(client.get_verifiers(), (ping_v.clone(), turn_v.clone(), rotated.clone()));
+
+    // The single setters keep the circuit layout check.
+    assert_pol_error(&client.try_set_ping_verifier(&rotated), Error::VerifierCircuitMismatch);
+    assert_eq!(client.get_verifiers(), (ping_v, turn_v, rotated));
+}
+
+// ============================================================================
+// GoRoom path-length cost
+// ============================================================================
+
+#[test]
+fn room_distance_counts_doors_between_rooms() {
+    assert_eq!(crate::floorplan::room_distance(b'E', b'E', 0), Some(0));
+    assert_eq!(crate::floorplan::room_distance(b'E', b'H', 0), Some(1));
+    assert_eq!(crate::floorplan::room_distance(b'E', b'G', 0), Some(2));
+    assert_eq!(crate::floorplan::room_distance(b'G', b'W', 0), None);
+}
+
+#[test]
+fn go_room_to_adjacent_room_costs_base_ping() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // Chad starts in the Grand Hall; the Hallway is one door away.
+    let session_id = 619u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(1));
+
+    assert_eq!(client.get_session(&session_id).battery, 80);
+}
+
+#[test]
+fn go_room_to_far_room_costs_per_room_crossed() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // Grand Hall -> Hallway -> Garden crosses two rooms.
+    let session_id = 620u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(0));
+
+    assert_eq!(client.get_session(&session_id).battery, 75);
+}
+
+#[test]
+fn go_room_to_unreachable_room_is_rejected() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 621u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+
+    // Room 8 is the sealed Winter garden: no door leads in.
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(8));
+    assert_pol_error(&res, Error::InvalidRoomTransition);
+    let res = client.try_preview_dispatch(&session_id, &crate::ChadCommand::GoRoom(8));
+    assert_pol_error(&res, Error::InvalidRoomTransition);
+    assert_eq!(client.get_session(&session_id).battery, 100);
+}
+
+// ============================================================================
+// Little-endian public inputs
+// ============================================================================
+
+fn b32_u32_le(env: &Env, v: u32) -> BytesN<32> {
+    let mut arr = [0u8; 32];
+    arr[..4].copy_from_slice(&v.to_le_bytes());
+    BytesN::from_array(env, &arr)
+}
+
+#[test]
+fn little_endian_session_accepts_le_encoded_session_id() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 622u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    assert_eq!(client.get_session(&session_id).endianness, crate::Endian::Big);
+    client.set_endianness(&session_id, &crate::Endian::Little);
+    assert_eq!(client.get_session(&session_id).endianness, crate::Endian::Little);
+
+    let old = dummy_commitment(&env);
+    let new = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    // bb's big-endian encoding no longer matches.
+    let be = move_public_inputs(&env, session_id, 0, &old, &new);
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &be);
+    assert_pol_error(&res, Error::ProofSessionMismatch);
+
+    let sid_le = b32_u32_le(&env, session_id);
+    assert_eq!(client.probe_field_encoding(&session_id).1, sid_le);
+    let le = soroban_sdk::vec![
+        &env,
+        sid_le,
+        b32_u32_le(&env, 0),
+        old.clone(),
+        new.clone(),
+        b32_u32_le(&env, 0),
+        b32_u32_le(&env, 1),
+        b32_u32_le(&env, 1),
+    ];
+    client.submit_move_proof(&session_id, &assassin, &new, &proof, &le);
+    assert_eq!(client.get_session(&session_id).commitment, Some(new));
+}
+
+// ============================================================================
+// Commitment lock
+// ============================================================================
+
+#[test]
+fn first_verified_ping_locks_commitment() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 623u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_commit_grace_turns(&session_id, &5u32);
+    let cmt = dummy_commitment(&env);
+    client.commit_location(&session_id, &assassin, &cmt);
+    assert!(!client.get_session(&session_id).commitment_locked);
+
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    let pis = soroban_sdk::vec![
+        &env,
+        b32_u32(&env, 5),
+        b32_u32(&env, 0),
+        b32_u32(&env, session_id),
+        b32_u32(&env, 0),
+        cmt,
+        b32_u32(&env, 25),
+    ];
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &Bytes::from_slice(&env, &[1u8]), &pis);
+    assert!(client.get_session(&session_id).commitment_locked);
+
+    // Still inside the grace window, but the ping already bound the commitment.
+    let res = client.try_commit_location(&session_id, &assassin, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_pol_error(&res, Error::CommitmentMismatch);
+    let res = client.try_hide(&session_id, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_pol_error(&res, Error::CommitmentMismatch);
+}
+
+#[test]
+fn finalize_commitment_locks_insecure_session() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 624u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.set_commit_grace_turns(&session_id, &5u32);
+    assert_pol_error(&client.try_finalize_commitment(&session_id, &assassin), Error::CommitmentNotSet);
+
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 25);
+    // Insecure pings verify nothing, so they never lock.
+    assert!(!client.get_session(&session_id).commitment_locked);
+
+    let res = client.try_finalize_commitment(&session_id, &dispatcher);
+    assert_pol_error(&res, Error::SessionKeyNotAuthorized);
+    client.finalize_commitment(&session_id, &assassin);
+    assert!(client.get_session(&session_id).commitment_locked);
+    let res = client.try_commit_location(&session_id, &assassin, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_pol_error(&res, Error::CommitmentMismatch);
+}
+
+#[test]
+fn verified_move_locks_commitment_inside_grace_window() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    deploy_mock_verifiers(&env, &client);
+
+    let session_id = 659u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_commit_grace_turns(&session_id, &5u32);
+    let old = dummy_commitment(&env);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+
+    let new = BytesN::from_array(&env, &[8u8; 32]);
+    let pis = move_public_inputs(&env, session_id, 0, &old, &new);
+    client.submit_move_proof(&session_id, &assassin, &new, &Bytes::from_slice(&env, &[1u8]), &pis);
+    assert!(client.get_session(&session_id).commitment_locked);
+
+    // The move was proven from `old`; re-committing now would be a free teleport.
+    let res = client.try_commit_location(&session_id, &assassin, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_pol_error(&res, Error::CommitmentMismatch);
+    assert_eq!(client.get_session(&session_id).commitment, Some(new));
+}
+
+// ============================================================================
+// Battery projection
+// ============================================================================
+
+#[test]
+fn battery_turns_remaining_counts_affordable_pings() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 625u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let s = client.get_session(&session_id);
+    assert_eq!((s.battery, s.ping_cost), (100, 20));
+    assert_eq!(client.battery_turns_remaining(&session_id), 5);
+
+    env.as_contract(&client.address, || {
+        let key = DataKey::SessionRuntime(session_id);
+        let mut r: crate::SessionRuntime = env.storage().instance().get(&key).unwrap();
+        r.battery = 10;
+        env.storage().instance().set(&key, &r);
+    });
+    assert_eq!(client.battery_turns_remaining(&session_id), 0);
+    assert_pol_error(&client.try_battery_turns_remaining(&626u32), Error::SessionNotFound);
+}
+
+// ============================================================================
+// RunGarden
+// ============================================================================
+
+#[test]
+fn run_garden_moves_two_tiles_for_extra_battery() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // Chad starts at (4,7); (4,8) and (4,9) are open Grand Hall tiles.
+    let session_id = 626u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    assert_eq!(client.preview_dispatch(&session_id, &crate::ChadCommand::RunGarden(2)), (4, 9));
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::RunGarden(2));
+
+    let s = client.get_session(&session_id);
+    assert_eq!((s.chad_x, s.chad_y), (4, 9));
+    assert_eq!(s.battery, 70);
+}
+
+#[test]
+fn run_garden_blocked_at_second_tile_is_rejected() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // North of (4,7): the door to (4,6) is open, but (4,5) is a blocked Winter tile.
+    let session_id = 627u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    let before = client.get_session(&session_id);
+
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::RunGarden(0));
+    assert_pol_error(&res, Error::InvalidMove);
+    assert_eq!(client.get_session(&session_id), before);
+}
+
+// ============================================================================
+// Admin force end
+// ============================================================================
+
+#[test]
+fn admin_force_end_closes_stuck_session() {
+    use soroban_sdk::TryFromVal;
+
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 628u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.admin_force_end(&session_id, &true);
+    let data = last_event_data(&env, "hub_call", "end_game").expect("end_game hub call");
+    let (sid, player1_won) = <(u32, bool)>::try_from_val(&env, &data).unwrap();
+    assert_eq!((sid, player1_won), (session_id, true));
+    assert_eq!(client.get_end_reason(&session_id), Some(crate::EndReason::AdminForced));
+
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+    assert_pol_error(&res, Error::GameAlreadyEnded);
+    let res = client.try_admin_force_end(&session_id, &false);
+    assert_pol_error(&res, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Circuit domain separation
+// ============================================================================
+
+#[test]
+fn shared_verifier_only_accepts_matching_circuit_domain() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    // One verifier behind all three slots, accepting turn-status proofs only.
+    let v = env.register(DomainVerifier, (1u32,));
+    client.set_verifiers(&v, &v, &v);
+    client.set_verifier_domain_tagged(&v, &true);
+
+    let session_id = 629u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    let pis = soroban_sdk::vec![&env, dummy_commitment(&env)];
+
+    assert!(!client.verify_and_record(&session_id, &assassin, &0u32, &0u32, &proof, &pis));
+    assert!(client.verify_and_record(&session_id, &assassin, &0u32, &1u32, &proof, &pis));
+    assert!(!client.verify_and_record(&session_id, &assassin, &0u32, &2u32, &proof, &pis));
+}
+
+#[test]
+fn untagged_verifier_checks_every_kind_in_domain_zero() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    // Stock bb proofs carry no domain tag, so every slot is verified in domain 0.
+    let v = env.register(DomainVerifier, (0u32,));
+    client.set_verifiers(&v, &v, &v);
+
+    let session_id = 653u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    let pis = soroban_sdk::vec![&env, dummy_commitment(&env)];
+
+    assert!(client.verify_and_record(&session_id, &assassin, &0u32, &0u32, &proof, &pis));
+    assert!(client.verify_and_record(&session_id, &assassin, &0u32, &1u32, &proof, &pis));
+    assert!(client.verify_and_record(&session_id, &assassin, &0u32, &2u32, &proof, &pis));
+
+    client.set_verifier_domain_tagged(&v, &true);
+    assert!(!client.verify_and_record(&session_id, &assassin, &0u32, &1u32, &proof, &pis));
+}
+
+// ============================================================================
+// Raw session storage readers
+// ============================================================================
+
+#[test]
+fn raw_session_readers_return_stored_halves() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 630u32;
+    assert_pol_error(&client.try_get_session_core(&session_id), Error::SessionNotFound);
+    assert_pol_error(&client.try_get_session_runtime(&session_id), Error::RuntimeNotInitialized);
+
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    let core = client.get_session_core(&session_id);
+    assert!(core.runtime_initialized);
+    assert_eq!(core.dispatcher, dispatcher);
+
+    let runtime = client.get_session_runtime(&session_id);
+    let view = client.get_session(&session_id);
+    assert_eq!((runtime.chad_x, runtime.chad_y, runtime.turn), (view.chad_x, view.chad_y, view.turn));
+}
+
+// ============================================================================
+// Alpha regeneration rate
+// ============================================================================
+
+#[test]
+fn alpha_regen_two_raises_alpha_by_two_clamped() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 631u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    assert_eq!(client.get_session(&session_id).alpha_regen, 1);
+    client.set_alpha_regen(&session_id, &2u32);
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 2);
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 2);
+    assert_eq!(client.get_session(&session_id).alpha, 3);
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 8);
+    assert_eq!(client.get_session(&session_id).alpha, 5);
+
+    // Already at alpha_max: the clamp holds.
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 8);
+    assert_eq!(client.get_session(&session_id).alpha, 5);
+}
+
+#[test]
+fn alpha_regen_zero_disables_regeneration() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 632u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.set_alpha_regen(&session_id, &0u32);
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 2);
+    assert_eq!(client.get_session(&session_id).alpha, 4);
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 8);
+    assert_eq!(client.get_session(&session_id).alpha, 4);
+}
+
+// ============================================================================
+// Game outcome
+// ============================================================================
+
+#[test]
+fn game_outcome_kill_is_assassin_win() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 633u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    assert_eq!(client.game_outcome(&session_id), crate::Outcome::Ongoing);
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 0);
+    assert_eq!(client.game_outcome(&session_id), crate::Outcome::AssassinWon);
+}
+
+#[test]
+fn game_outcome_alpha_depletion_is_assassin_win() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 634u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    let alpha = client.get_session(&session_id).alpha;
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env]);
+    for _ in 0..alpha {
+        client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &soroban_sdk::vec![&env]);
+        client.submit_turn_status_proof(&session_id, &assassin, &4u32, &proof, &soroban_sdk::vec![&env]);
+        if !client.get_session(&session_id).ended {
+            client.recharge(&session_id, &dispatcher);
+        }
+    }
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::AlphaDepleted));
+    assert_eq!(client.game_outcome(&session_id), crate::Outcome::AssassinWon);
+}
+
+#[test]
+fn game_outcome_battery_depletion_is_dispatcher_win() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 635u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.set_grant_alpha_cost(&session_id, &80u32);
+    client.grant_alpha(&session_id, &dispatcher, &0u32);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Stay);
+
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::BatteryDepleted));
+    assert_eq!(client.game_outcome(&session_id), crate::Outcome::DispatcherWon);
+}
+
+#[test]
+fn game_outcome_forced_end_uses_reported_winner() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 636u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    client.admin_force_end(&session_id, &true);
+    assert_eq!(client.game_outcome(&session_id), crate::Outcome::DispatcherWon);
+}
+
+#[test]
+fn game_outcome_without_recorded_winner_is_unknown() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 667u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.admin_force_end(&session_id, &false);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::DispatcherWon(session_id));
+    });
+
+    let res = client.try_game_outcome(&session_id);
+    assert_pol_error(&res, Error::OutcomeUnknown);
+}
+
+// ============================================================================
+// Claimed move count
+// ============================================================================
+
+#[test]
+fn move_proof_rejects_mismatched_steps_claimed() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let session_id = 637u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &25u32, &proof, &soroban_sdk::vec![&env]);
+
+    let with_claim = |steps: u32| {
+        move_step_public_inputs(&env, session_id, 0, &dummy_commitment(&env), &dummy_commitment(&env), steps, 1)
+    };
+
+    // First move of the turn claiming two steps.
+    let res = client.try_submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &with_claim(2));
+    assert_pol_error(&res, Error::MoveCountMismatch);
+    assert_eq!(client.get_session(&session_id).assassin_moves_this_turn, 0);
+
+    client.submit_move_proof(&session_id, &assassin, &next_commitment(&env, &client, session_id), &proof, &with_claim(1));
+    assert_eq!(client.get_session(&session_id).assassin_moves_this_turn, 1);
+}
+
+// ============================================================================
+// Insecure mode consent
+// ============================================================================
+
+#[test]
+fn enabling_insecure_mode_requires_both_players() {
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+    use soroban_sdk::IntoVal;
+
+    let (env, client, _hub, _hub_addr, admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 638u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    // Admin alone cannot switch verification off.
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "set_insecure_mode",
+            args: (session_id, true).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_set_insecure_mode(&session_id, &true).is_err());
+    assert!(!client.get_session(&session_id).insecure_mode);
+
+    env.mock_all_auths();
+    client.set_insecure_mode(&session_id, &true);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 3);
+    assert_eq!((&auths[0].0, &auths[1].0, &auths[2].0), (&admin, &dispatcher, &assassin));
+    assert!(client.get_session(&session_id).insecure_mode);
+}
+
+#[test]
+fn disabling_insecure_mode_stays_admin_only() {
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+    use soroban_sdk::IntoVal;
+
+    let (env, client, _hub, _hub_addr, admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 639u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "set_insecure_mode",
+            args: (session_id, false).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.set_insecure_mode(&session_id, &false);
+    assert!(!client.get_session(&session_id).insecure_mode);
+
+    // Without the admin, the players cannot re-secure (or do anything) alone.
+    env.mock_auths(&[MockAuth {
+        address: &dispatcher,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "set_insecure_mode",
+            args: (session_id, false).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_set_insecure_mode(&session_id, &false).is_err());
+}
+
+// ============================================================================
+// Recharge phase guard
+// ============================================================================
+
+#[test]
+fn recharge_only_in_dispatcher_phase() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 640u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Dispatcher phase: recharge hands the turn to the assassin.
+    assert_eq!(client.get_session(&session_id).phase, TurnPhase::Dispatcher);
+    client.recharge(&session_id, &dispatcher);
+    let s = client.get_session(&session_id);
+    assert_eq!(s.phase, TurnPhase::Assassin);
+
+    // Assassin phase: a second top-up is out of turn.
+    let res = client.try_recharge(&session_id, &dispatcher);
+    assert_pol_error(&res, Error::NotDispatcherTurn);
+    let after = client.get_session(&session_id);
+    assert_eq!((after.battery, after.phase, after.turn), (s.battery, s.phase, s.turn));
+}
+
+// ============================================================================
+// Closed doors
+// ============================================================================
+
+// DOORS_OPEN[11] joins the Hallway and the Grand Hall; DOORS_OPEN[5] joins the
+// Library and the Grand Hall.
+const DOOR_HALLWAY_GRAND_HALL: u32 = 11;
+const DOOR_LIBRARY_GRAND_HALL: u32 = 5;
+
+#[test]
+fn closed_door_forces_go_room_onto_alternate_route() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    // Chad starts in the Grand Hall.
+    let session_id = 641u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.toggle_door(&session_id, &dispatcher, &DOOR_HALLWAY_GRAND_HALL);
+    assert_eq!(client.get_closed_doors(&session_id), 1 << DOOR_HALLWAY_GRAND_HALL);
+
+    // Grand Hall -> Library -> Hallway: one room further, so 5 more battery.
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(1));
+    let s = client.get_session(&session_id);
+    assert_eq!(s.battery, 75);
+    assert_eq!(crate::floorplan::get_room_code(s.chad_x, s.chad_y), b'H');
+}
+
+#[test]
+fn go_room_through_only_closed_doors_is_rejected() {
+    let (_env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 642u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.toggle_door(&session_id, &dispatcher, &DOOR_HALLWAY_GRAND_HALL);
+    client.toggle_door(&session_id, &dispatcher, &DOOR_LIBRARY_GRAND_HALL);
+
+    let res = client.try_dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(1));
+    assert_pol_error(&res, Error::InvalidRoomTransition);
+
+    // Reopening the direct door restores the one-room move.
+    client.toggle_door(&session_id, &dispatcher, &DOOR_HALLWAY_GRAND_HALL);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::GoRoom(1));
+    assert_eq!(client.get_session(&session_id).battery, 80);
+
+    let res = client.try_toggle_door(&session_id, &assassin, &0u32);
+    assert_pol_error(&res, Error::NotDispatcher);
+}
+
+// ============================================================================
+// Public-input arity pre-flight
+// ============================================================================
+
+fn dummy_public_inputs(env: &Env, n: u32) -> soroban_sdk::Vec<BytesN<32>> {
+    let mut out = soroban_sdk::Vec::new(env);
+    for i in 0..n {
+        out.push_back(b32_u32(env, i));
+    }
+    out
+}
+
+#[test]
+fn ping_proof_with_wrong_arity_is_rejected_before_verifier() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 643u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    client.request_ping(&session_id, &dispatcher, &0u32);
+
+    for n in [5u32, 7u32] {
+        let res = client.try_submit_ping_proof(
+            &session_id,
+            &assassin,
+            &0u32,
+            &25u32,
+            &Bytes::from_slice(&env, &[1u8]),
+            &dummy_public_inputs(&env, n),
+        );
+        assert_pol_error(&res, Error::PublicInputArityMismatch);
+    }
+}
+
+#[test]
+fn turn_status_proof_with_wrong_arity_is_rejected_before_verifier() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 644u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &25u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    let cmt = BytesN::from_array(&env, &[8u8; 32]);
+    client.submit_move_proof(
+        &session_id, &assassin, &cmt,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    client.set_insecure_mode(&session_id, &false);
+
+    // Right fields, but d2_chad left off.
+    let pis = soroban_sdk::vec![
+        &env,
+        b32_u32(&env, 4),
+        b32_u32(&env, 7),
+        b32_u32(&env, session_id),
+        b32_u32(&env, 0),
+        cmt,
+    ];
+    let res = client.try_submit_turn_status_proof(
+        &session_id,
+        &assassin,
+        &25u32,
+        &Bytes::from_slice(&env, &[2u8]),
+        &pis,
+    );
+    assert_pol_error(&res, Error::PublicInputArityMismatch);
+}
+
+#[test]
+fn move_proof_with_wrong_arity_is_rejected_before_verifier() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 645u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    let old = dummy_commitment(&env);
+    let new = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let mut pis = move_public_inputs(&env, session_id, 0, &old, &new);
+    pis.pop_back();
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &pis);
+    assert_pol_error(&res, Error::PublicInputArityMismatch);
+
+    // The step fields are not optional: a 4-field layout proves no distance.
+    let mut pis = move_public_inputs(&env, session_id, 0, &old, &new);
+    for _ in 0..3 {
+        pis.pop_back();
+    }
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &pis);
+    assert_pol_error(&res, Error::PublicInputArityMismatch);
+
+    let pis = move_public_inputs(&env, session_id, 0, &old, &new);
+    client.submit_move_proof(&session_id, &assassin, &new, &proof, &pis);
+    assert_eq!(client.get_session(&session_id).commitment, Some(new));
+}
+
+#[test]
+fn insecure_ping_without_commitment_is_rejected() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 646u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.request_ping(&session_id, &dispatcher, &0u32);
+
+    let res = client.try_submit_ping_proof(
+        &session_id,
+        &assassin,
+        &0u32,
+        &25u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::CommitmentNotSet);
+
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+    client.submit_ping_proof(
+        &session_id,
+        &assassin,
+        &0u32,
+        &25u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+}
+
+// ============================================================================
+// Move step distance
+// ============================================================================
+
+#[test]
+fn move_proof_with_multi_tile_step_is_rejected() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 647u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    let old = dummy_commitment(&env);
+    let new = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let with_step = |d: u32| move_step_public_inputs(&env, session_id, 0, &old, &new, d, d);
+
+    for d in [3u32, 0u32] {
+        let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &with_step(d));
+        assert_pol_error(&res, Error::IllegalStep);
+    }
+    assert_eq!(client.get_session(&session_id).commitment, Some(old.clone()));
+
+    client.submit_move_proof(&session_id, &assassin, &new, &proof, &with_step(1));
+    assert_eq!(client.get_session(&session_id).commitment, Some(new));
+}
+
+#[test]
+fn hidden_multi_tile_step_is_charged_by_distance() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 654u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    let c0 = dummy_commitment(&env);
+    client.commit_location(&session_id, &assassin, &c0);
+
+    client.set_insecure_mode(&session_id, &true);
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Hide);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    client.submit_ping_proof(&session_id, &assassin, &0u32, &250u32, &proof, &soroban_sdk::vec![&env]);
+    client.set_insecure_mode(&session_id, &false);
+
+    let c1 = BytesN::from_array(&env, &[31u8; 32]);
+    let c2 = BytesN::from_array(&env, &[32u8; 32]);
+    let pis = move_step_public_inputs(&env, session_id, 0, &c0, &c1, 3, 3);
+    client.submit_move_proof(&session_id, &assassin, &c1, &proof, &pis);
+    assert_eq!(client.get_session(&session_id).assassin_moves_this_turn, 3);
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 3);
+
+    // Four tiles would overrun the six-step budget.
+    let pis = move_step_public_inputs(&env, session_id, 0, &c1, &c2, 7, 4);
+    let res = client.try_submit_move_proof(&session_id, &assassin, &c2, &proof, &pis);
+    assert_pol_error(&res, Error::IllegalStep);
+
+    let pis = move_step_public_inputs(&env, session_id, 0, &c1, &c2, 6, 3);
+    client.submit_move_proof(&session_id, &assassin, &c2, &proof, &pis);
+    assert_eq!(client.moves_remaining_this_turn(&session_id), 0);
+}
+
+#[test]
+fn unwalled_move_floorplan_slot_must_be_zero_or_floorplan_id() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 655u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    deploy_mock_verifiers(&env, &client);
+    let old = dummy_commitment(&env);
+    let new = BytesN::from_array(&env, &[9u8; 32]);
+    client.commit_location(&session_id, &assassin, &old);
+    client.recharge(&session_id, &dispatcher);
+    let proof = Bytes::from_slice(&env, &[1u8]);
+
+    let mut pis = move_public_inputs(&env, session_id, 0, &old, &new);
+    pis.set(4, b32_u32(&env, 5));
+    let res = client.try_submit_move_proof(&session_id, &assassin, &new, &proof, &pis);
+    assert_pol_error(&res, Error::FloorplanMismatch);
+
+    // A wall-aware proof is also a valid plain move.
+    pis.set(4, b32_u32(&env, crate::floorplan::FLOORPLAN_ID));
+    client.submit_move_proof(&session_id, &assassin, &new, &proof, &pis);
+    assert_eq!(client.get_session(&session_id).commitment, Some(new));
+}
+
+// ============================================================================
+// Per-tower accessor
+// ============================================================================
+
+#[test]
+fn get_tower_coords_matches_ping_mapping() {
+    let (_env, client, _hub, _hub_addr, _admin, _dispatcher, _assassin) = setup_test();
+
+    assert_eq!(client.get_tower_coords(&1u32), (9, 5));
+    assert_eq!(client.get_tower_coords(&0u32), (5, 0));
+    let res = client.try_get_tower_coords(&4u32);
+    assert_pol_error(&res, Error::UnexpectedTower);
+}
+
+#[test]
+fn submit_multi_move_proof_rejects_repeated_commitment() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 648u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    let c0 = dummy_commitment(&env);
+    client.commit_location(&session_id, &assassin, &c0);
+    client.recharge(&session_id, &dispatcher);
+
+    let stale = MoveProofEntry {
+        new_commitment: c0.clone(),
+        proof: Bytes::from_slice(&env, &[1u8]),
+        public_inputs: soroban_sdk::vec![&env],
+    };
+    let fresh = MoveProofEntry {
+        new_commitment: BytesN::from_array(&env, &[11u8; 32]),
+        ..stale.clone()
+    };
+    let entries = soroban_sdk::vec![&env, stale, fresh];
+
+    let res = client.try_submit_multi_move_proof(&session_id, &assassin, &entries);
+    assert_pol_error(&res, Error::StaleMoveCommitment);
+    let s = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(c0));
+    assert_eq!(s.assassin_moves_this_turn, 0);
+}
+
+#[test]
+fn submit_multi_move_proof_stops_at_later_repeated_commitment() {
+    use soroban_sdk::{TryFromVal, Vec};
+
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 665u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    // Hidden Chad: the budget is not what stops the second entry.
+    client.dispatch(&session_id, &dispatcher, &0u32, &crate::ChadCommand::Hide);
+    client.submit_ping_proof(
+        &session_id, &assassin, &0u32, &250u32,
+        &Bytes::from_slice(&env, &[1u8]),
+        &soroban_sdk::vec![&env],
+    );
+
+    let c1 = BytesN::from_array(&env, &[11u8; 32]);
+    let entry = MoveProofEntry {
+        new_commitment: c1.clone(),
+        proof: Bytes::from_slice(&env, &[1u8]),
+        public_inputs: soroban_sdk::vec![&env],
+    };
+    let entries = soroban_sdk::vec![&env, entry.clone(), entry];
+
+    // The first entry is kept; the repeat ends the batch and is named in an event.
+    assert_eq!(client.submit_multi_move_proof(&session_id, &assassin, &entries), 1);
+    let data = last_event_data(&env, "multi_move", "stale").expect("stale entry event");
+    let v = Vec::<u32>::try_from_val(&env, &data).unwrap();
+    assert_eq!(v, soroban_sdk::vec![&env, session_id, 1u32]);
+    let s = client.get_session(&session_id);
+    assert_eq!(s.commitment, Some(c1));
+    assert_eq!(s.assassin_moves_this_turn, 1);
+}
+
+#[test]
+fn single_moves_reject_repeated_commitment() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 666u32;
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+    client.set_insecure_mode(&session_id, &true);
+    let c0 = dummy_commitment(&env);
+    client.commit_location(&session_id, &assassin, &c0);
+    client.recharge(&session_id, &dispatcher);
+
+    let proof = Bytes::from_slice(&env, &[1u8]);
+    let res = client.try_submit_move_proof(&session_id, &assassin, &c0, &proof, &soroban_sdk::vec![&env]);
+    assert_pol_error(&res, Error::StaleMoveCommitment);
+
+    let entry = MoveProofEntry {
+        new_commitment: c0.clone(),
+        proof: proof.clone(),
+        public_inputs: soroban_sdk::vec![&env],
+    };
+    let res = client.try_assassin_end_turn(
+        &session_id,
+        &assassin,
+        &Some(entry),
+        &25u32,
+        &proof,
+        &soroban_sdk::vec![&env],
+    );
+    assert_pol_error(&res, Error::StaleMoveCommitment);
+    let s = client.get_session(&session_id);
+    assert_eq!((s.turn, s.assassin_moves_this_turn), (0, 0));
+}
+
+// ============================================================================
+// Handicap start
+// ============================================================================
+
+#[test]
+fn handicap_battery_runs_out_after_two_pings() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 649u32;
+    client.start_game_handicap(&session_id, &dispatcher, &assassin, &5i128, &4i128, &40u32);
+    assert_eq!(client.get_session(&session_id).battery, 40);
+    client.set_insecure_mode(&session_id, &true);
+    client.commit_location(&session_id, &assassin, &dummy_commitment(&env));
+
+    play_turn_with_d2_chad(&env, &client, session_id, &dispatcher, &assassin, 8);
+    assert_eq!(client.get_session(&session_id).battery, 20);
+
+    client.request_ping(&session_id, &dispatcher, &0u32);
+    let s = client.get_session(&session_id);
+    assert_eq!(s.battery, 0);
+    assert!(s.ended);
+    assert_eq!(client.get_end_reason(&session_id), Some(EndReason::BatteryDepleted));
+
+    // Above the cap is clamped.
+    client.start_game_handicap(&650u32, &dispatcher, &assassin, &5i128, &4i128, &500u32);
+    assert_eq!(client.get_session(&650u32).battery, 100);
+}
+
+// ============================================================================
+// Session key mask validation
+// ============================================================================
+
+#[test]
+fn authorize_session_key_rejects_unknown_method_bits() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 651u32;
+    let delegate = Address::generate(&env);
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    let res = client.try_authorize_session_key(
+        &dispatcher, &session_id, &delegate, &100u32, &10u32, &(1u32 << 20), &0u32,
+    );
+    assert_pol_error(&res, Error::InvalidMethodMask);
+    let res = client.try_authorize_session_key(
+        &assassin, &session_id, &delegate, &100u32, &10u32, &0u32, &(4u32 | 1 << 20),
+    );
+    assert_pol_error(&res, Error::InvalidMethodMask);
+    assert!(client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).is_none());
+}
+
+#[test]
+fn authorize_session_key_accepts_every_defined_method_bit() {
+    let (env, client, _hub, _hub_addr, _admin, dispatcher, assassin) = setup_test();
+
+    let session_id = 652u32;
+    let delegate = Address::generate(&env);
+    client.start_game(&session_id, &dispatcher, &assassin, &5i128, &4i128);
+
+    // Bits 0..=7 and 9: dispatch through grant_alpha. Bit 8 is unassigned.
+    let all = ((1u32 << 10) - 1) & !(1 << 8);
+    client.authorize_session_key(&dispatcher, &session_id, &delegate, &100u32, &10u32, &all, &0u32);
+    let scope = client.get_session_key_scope(&dispatcher, &session_id, &Role::Dispatcher).unwrap();
+    assert_eq!(scope.allow_mask, all);
+}
@@ -0,0 +1,36 @@
+//! Small helpers shared by the proof-checking paths.
+
+use soroban_sdk::BytesN;
+
+/// Constant-time equality for 32-byte field elements / commitments.
+/// Always touches every byte so the comparison time does not depend on
+/// where the inputs first differ.
+pub fn ct_eq_bytes32(a: &BytesN<32>, b: &BytesN<32>) -> bool {
+    let a = a.to_array();
+    let b = b.to_array();
+    let mut diff = 0u8;
+    for i in 0..32 {
+        diff |= a[i] ^ b[i];
+    }
+    diff == 0
+}
+
+/// Integer square root (floor).
+pub fn isqrt(n: u32) -> u32 {
+    let mut rem = n;
+    let mut root = 0u32;
+    let mut bit = 1u32 << 30;
+    while bit > rem {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
@@ -1,6 +1,11 @@
 #![no_std]
-use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Bytes, Env, Symbol};
-use ultrahonk_soroban_verifier::UltraHonkVerifier;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, symbol_short, Bytes, Env, Symbol};
+use ultrahonk_soroban_verifier::{
+    proof_bytes_for_log_n, public_inputs_match_vk, verifier::VerifyError, UltraHonkVerifier,
+};
+
+#[cfg(test)]
+mod test;
 
 /// Verifier Contract
 #[contract]
@@ -16,31 +21,201 @@ pub enum Error {
     VkNotSet = 4,
 }
 
+/// VK header cached at construction. Its presence also marks the stored VK
+/// bytes as validated, so `verify_proof` can skip the full checks, and it is
+/// enough to reject mis-sized proofs and public inputs without loading the VK.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VkMeta {
+    pub log_n: u32,
+    pub public_inputs_size: u32,
+    pub pub_inputs_offset: u32,
+}
+
 #[contractimpl]
 impl Verifier {
     fn key_vk() -> Symbol {
         symbol_short!("vk")
     }
 
+    fn key_vk_meta() -> Symbol {
+        symbol_short!("vk_meta")
+    }
+
     /// Initialize the on-chain VK once at deploy time.
+    /// The VK is fully validated here and its header cached.
     pub fn __constructor(env: Env, vk_bytes: Bytes) -> Result<(), Error> {
+        let verifier = Self::parse_vk(&env, &vk_bytes)?;
+        let vk = verifier.get_vk();
+        let meta = VkMeta {
+            log_n: vk.log_circuit_size as u32,
+            public_inputs_size: vk.public_inputs_size as u32,
+            pub_inputs_offset: vk.pub_inputs_offset as u32,
+        };
         env.storage().instance().set(&Self::key_vk(), &vk_bytes);
+        env.storage().instance().set(&Self::key_vk_meta(), &meta);
         Ok(())
     }
 
     /// Verify an UltraHonk proof using the stored VK.
     pub fn verify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes) -> Result<(), Error> {
+        Self::verify_proof_in_domain(env, public_inputs, proof_bytes, 0)
+    }
+
+    /// `verify_proof` with a circuit domain tag in the Fiat–Shamir transcript.
+    /// Callers that reuse one verifier for several proof kinds pass a distinct
+    /// domain per kind so a proof of one kind can't be replayed as another.
+    pub fn verify_proof_in_domain(
+        env: Env,
+        public_inputs: Bytes,
+        proof_bytes: Bytes,
+        circuit_domain: u32,
+    ) -> Result<(), Error> {
+        // Cached header => VK was validated at construction. Its sizes settle
+        // the proof and public-input lengths, so only a well-shaped call pays
+        // for loading the VK points (without re-validating them).
+        let meta: Option<VkMeta> = env.storage().instance().get(&Self::key_vk_meta());
+        if let Some(meta) = &meta {
+            Self::check_shape(&env, meta, &public_inputs, &proof_bytes)?;
+        }
         let vk_bytes: Bytes = env
             .storage()
             .instance()
             .get(&Self::key_vk())
             .ok_or(Error::VkNotSet)?;
-
-        let verifier = UltraHonkVerifier::new(&env, &vk_bytes).map_err(|_| Error::VkParseError)?;
+        let verifier = if meta.is_some() {
+            UltraHonkVerifier::new_trusted(&env, &vk_bytes).map_err(|_| Error::VkParseError)?
+        } else {
+            Self::parse_vk(&env, &vk_bytes)?
+        };
 
         verifier
-            .verify(&proof_bytes, &public_inputs)
+            .verify_in_domain(&proof_bytes, &public_inputs, circuit_domain)
             .map_err(|_| Error::VerificationFailed)?;
         Ok(())
     }
+
+    /// Diagnostics: transcript, public-input delta and sum-check only, without
+    /// Shplemini. The error code names the failing stage: `VkNotSet`,
+    /// `VkParseError`, `ProofParseError` (malformed proof or public inputs) or
+    /// `VerificationFailed` (sum-check).
+    pub fn verify_sumcheck_only(env: Env, proof: Bytes, public_inputs: Bytes) -> Result<(), Error> {
+        let vk_bytes: Bytes = env
+            .storage()
+            .instance()
+            .get(&Self::key_vk())
+            .ok_or(Error::VkNotSet)?;
+        let verifier =
+            UltraHonkVerifier::new_trusted(&env, &vk_bytes).map_err(|_| Error::VkParseError)?;
+        verifier
+            .verify_sumcheck_only(&proof, &public_inputs)
+            .map_err(|e| match e {
+                VerifyError::InvalidInput(_) => Error::ProofParseError,
+                _ => Error::VerificationFailed,
+            })
+    }
+
+    pub fn get_vk_meta(env: Env) -> Option<VkMeta> {
+        env.storage().instance().get(&Self::key_vk_meta())
+    }
+
+    /// log₂ of the circuit size of the stored VK.
+    pub fn vk_log_n(env: Env) -> Result<u64, Error> {
+        Ok(Self::vk_meta(&env)?.log_n as u64)
+    }
+
+    /// Public input count from the VK header (includes the pairing point object).
+    pub fn vk_public_inputs(env: Env) -> Result<u64, Error> {
+        Ok(Self::vk_meta(&env)?.public_inputs_size as u64)
+    }
+
+    /// Proof size in bytes that `verify_proof` expects for the stored VK.
+    pub fn expected_proof_len(env: Env) -> Result<u64, Error> {
+        Ok(proof_bytes_for_log_n(Self::vk_meta(&env)?.log_n as u64) as u64)
+    }
+
+    /// `(log_circuit_size, public_inputs_size, pub_inputs_offset)` of the stored
+    /// VK: everything a prover needs to size the proof and public inputs.
+    pub fn get_circuit_params(env: Env) -> Result<(u64, u64, u64), Error> {
+        let meta = Self::vk_meta(&env)?;
+        Ok((
+            meta.log_n as u64,
+            meta.public_inputs_size as u64,
+            meta.pub_inputs_offset as u64,
+        ))
+    }
+
+    fn vk_meta(env: &Env) -> Result<VkMeta, Error> {
+        env.storage()
+            .instance()
+            .get(&Self::key_vk_meta())
+            .ok_or(Error::VkNotSet)
+    }
+
+    /// The verifier's length checks, answered from the cached header. Failures
+    /// are `VerificationFailed` as they would be from the verifier, and a
+    /// public-input count mismatch publishes the same `("err", "pi_len")`
+    /// event with `(provided, expected)`.
+    fn check_shape(
+        env: &Env,
+        meta: &VkMeta,
+        public_inputs: &Bytes,
+        proof_bytes: &Bytes,
+    ) -> Result<(), Error> {
+        if public_inputs.len() % 32 != 0 {
+            return Err(Error::VerificationFailed);
+        }
+        let provided = (public_inputs.len() / 32) as u64;
+        let vk_inputs = meta.public_inputs_size as u64;
+        if !public_inputs_match_vk(vk_inputs, provided) {
+            env.events().publish(
+                (symbol_short!("err"), symbol_short!("pi_len")),
+                (provided, vk_inputs),
+            );
+            return Err(Error::VerificationFailed);
+        }
+        if proof_bytes.len() as usize != proof_bytes_for_log_n(meta.log_n as u64) {
+            return Err(Error::VerificationFailed);
+        }
+        Ok(())
+    }
+
+    /// Full (validating) VK parse. Emits `("vk", "parsed")` so callers can
+    /// tell when the cache was bypassed.
+    fn parse_vk(env: &Env, vk_bytes: &Bytes) -> Result<UltraHonkVerifier, Error> {
+        let verifier = UltraHonkVerifier::new(env, vk_bytes).map_err(|_| Error::VkParseError)?;
+        env.events().publish(
+            (symbol_short!("vk"), symbol_short!("parsed")),
+            verifier.get_vk().log_circuit_size as u32,
+        );
+        Ok(verifier)
+    }
+}
+
+/// Budget reporting reads the host's cost model, which a deployed contract
+/// cannot see; these entry points exist only in native (testutils) builds.
+#[cfg(any(test, feature = "testutils"))]
+#[contractimpl]
+impl Verifier {
+    /// `verify_proof`, returning the CPU instructions the verification
+    /// consumed so callers can size future transactions.
+    ///
+    /// The count is an estimate from `env.cost_estimate()`: native execution
+    /// skips VM instantiation and Wasm costs, so simulate via RPC for exact
+    /// fees. Errors are the same as `verify_proof` (`VerificationFailed` is a
+    /// cryptographic rejection); running out of budget aborts the invocation
+    /// rather than returning an error.
+    pub fn verify_with_budget_report(
+        env: Env,
+        proof: Bytes,
+        public_inputs: Bytes,
+    ) -> Result<u64, Error> {
+        let before = env.cost_estimate().budget().cpu_instruction_cost();
+        Self::verify_proof(env.clone(), public_inputs, proof)?;
+        Ok(env
+            .cost_estimate()
+            .budget()
+            .cpu_instruction_cost()
+            .saturating_sub(before))
+    }
 }
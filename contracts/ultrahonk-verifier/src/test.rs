@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+use crate::{Verifier, VerifierClient, VkMeta};
+use ultrahonk_soroban_verifier::{verifier::VerifyError, UltraHonkVerifier};
+use soroban_sdk::{
+    symbol_short, testutils::Events, xdr, Bytes, Env, Symbol, TryFromVal, Val,
+};
+
+/// 32-byte hash prefix + 3 header fields + 28 points at infinity.
+fn synthetic_vk(env: &Env, log_n: u8, num_pis: u8, offset: u8) -> Bytes {
+    let mut raw = [0u8; 32 + 96 + 28 * 64];
+    raw[32 + 31] = log_n;
+    raw[64 + 31] = num_pis;
+    raw[96 + 31] = offset;
+    Bytes::from_slice(env, &raw)
+}
+
+fn count_events(env: &Env, topic0: Symbol, topic1: Symbol) -> usize {
+    let all = env.events().all();
+    all.events()
+        .iter()
+        .filter(|e| {
+            let xdr::ContractEventBody::V0(body) = &e.body;
+            let topic = |i: usize| -> Option<Symbol> {
+                let v = Val::try_from_val(env, body.topics.get(i)?).ok()?;
+                Symbol::try_from_val(env, &v).ok()
+            };
+            topic(0) == Some(topic0.clone()) && topic(1) == Some(topic1.clone())
+        })
+        .count()
+}
+
+fn parse_events(env: &Env) -> usize {
+    count_events(env, symbol_short!("vk"), symbol_short!("parsed"))
+}
+
+#[test]
+fn constructor_caches_vk_meta() {
+    let env = Env::default();
+    let id = env.register(Verifier, (synthetic_vk(&env, 3, 2, 1),));
+    let client = VerifierClient::new(&env, &id);
+
+    assert_eq!(
+        client.get_vk_meta(),
+        Some(VkMeta { log_n: 3, public_inputs_size: 2, pub_inputs_offset: 1 })
+    );
+}
+
+#[test]
+fn vk_metadata_getters_match_header() {
+    let env = Env::default();
+    let id = env.register(Verifier, (synthetic_vk(&env, 3, 2, 1),));
+    let client = VerifierClient::new(&env, &id);
+
+    assert_eq!(client.vk_log_n(), 3);
+    assert_eq!(client.vk_public_inputs(), 2);
+    assert_eq!(
+        client.expected_proof_len(),
+        ultrahonk_soroban_verifier::proof_bytes_for_log_n(3) as u64
+    );
+}
+
+#[test]
+fn circuit_params_match_header() {
+    let env = Env::default();
+    let id = env.register(Verifier, (synthetic_vk(&env, 3, 2, 1),));
+    let client = VerifierClient::new(&env, &id);
+
+    assert_eq!(client.get_circuit_params(), (3, 2, 1));
+}
+
+#[test]
+#[should_panic]
+fn constructor_rejects_invalid_vk() {
+    let env = Env::default();
+    let mut raw = [0u8; 32 + 96 + 28 * 64];
+    raw[32 + 31] = 3;
+    // First point = (1, 3), not on the curve.
+    raw[128 + 31] = 1;
+    raw[128 + 63] = 3;
+    env.register(Verifier, (Bytes::from_slice(&env, &raw),));
+}
+
+#[test]
+fn verify_uses_cached_vk_instead_of_full_parse() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let vk = synthetic_vk(&env, 3, 2, 1);
+    let id = env.register(Verifier, (vk.clone(),));
+    let pis = Bytes::from_slice(&env, &[0u8; 64]);
+    let proof = Bytes::new(&env);
+    let cpu = || env.cost_estimate().budget().cpu_instruction_cost();
+    // Measured inside the contract frame: each invocation resets the budget.
+    let verify = || {
+        env.as_contract(&id, || {
+            let before = cpu();
+            let res = Verifier::verify_proof(env.clone(), pis.clone(), proof.clone());
+            (res, cpu() - before)
+        })
+    };
+
+    // The proof has the wrong size for log_n = 3, so the cached header rejects
+    // it before the VK points are loaded: cheaper than loading them at all.
+    let (res, cached_cpu) = verify();
+    assert_eq!(res, Err(crate::Error::VerificationFailed));
+    assert_eq!(parse_events(&env), 0);
+
+    let before = cpu();
+    UltraHonkVerifier::new_trusted(&env, &vk).unwrap();
+    let load_cpu = cpu() - before;
+    assert!(cached_cpu < load_cpu, "cached {} vs load {}", cached_cpu, load_cpu);
+
+    // Without the cached header (pre-cache deployments), every call re-parses.
+    env.as_contract(&id, || env.storage().instance().remove(&symbol_short!("vk_meta")));
+    let (res, uncached_cpu) = verify();
+    assert_eq!(res, Err(crate::Error::VerificationFailed));
+    assert_eq!(parse_events(&env), 1);
+    assert!(cached_cpu < uncached_cpu);
+}
+
+#[test]
+fn cached_header_reports_public_input_count_mismatch() {
+    let env = Env::default();
+    let id = env.register(Verifier, (synthetic_vk(&env, 3, 2, 1),));
+    let len = ultrahonk_soroban_verifier::proof_bytes_for_log_n(3);
+    let proof = Bytes::from_slice(&env, &[0u8; 108 * 32][..len]);
+
+    // Three public inputs against a header declaring two.
+    let pis = Bytes::from_slice(&env, &[0u8; 96]);
+    let res = env.as_contract(&id, || Verifier::verify_proof(env.clone(), pis, proof));
+    assert_eq!(res, Err(crate::Error::VerificationFailed));
+    assert_eq!(count_events(&env, symbol_short!("err"), symbol_short!("pi_len")), 1);
+    assert_eq!(parse_events(&env), 0);
+}
+
+#[test]
+fn verify_sumcheck_only_reports_failing_stage() {
+    let env = Env::default();
+    let id = env.register(Verifier, (synthetic_vk(&env, 3, 2, 1),));
+    let client = VerifierClient::new(&env, &id);
+    let pis = Bytes::from_slice(&env, &[0u8; 64]);
+
+    // Wrong proof length is caught before the transcript.
+    let res = client.try_verify_sumcheck_only(&Bytes::from_slice(&env, &[1u8]), &pis);
+    assert_eq!(res, Err(Ok(crate::Error::ProofParseError)));
+
+    // Well-formed proof whose first round univariate does not sum to zero.
+    // It starts after 16 pairing fields and 8 G1 commitments (32 fields).
+    let len = ultrahonk_soroban_verifier::proof_bytes_for_log_n(3);
+    let mut raw = [0u8; 108 * 32];
+    raw[32 * 32 + 31] = 1;
+    let proof = Bytes::from_slice(&env, &raw[..len]);
+    let res = client.try_verify_sumcheck_only(&proof, &pis);
+    assert_eq!(res, Err(Ok(crate::Error::VerificationFailed)));
+}
+
+#[test]
+fn sumcheck_failure_reports_round_index() {
+    let env = Env::default();
+    let vk = synthetic_vk(&env, 3, 2, 1);
+    let id = env.register(Verifier, (vk.clone(),));
+    let pis = Bytes::from_slice(&env, &[0u8; 64]);
+
+    // All-zero univariates keep every round target at zero, so a nonzero
+    // u[0] in round 2 (8 fields per round after the 32 leading fields) is the
+    // first failing round.
+    let len = ultrahonk_soroban_verifier::proof_bytes_for_log_n(3);
+    let mut raw = [0u8; 108 * 32];
+    raw[(32 + 2 * 8) * 32 + 31] = 1;
+    let proof = Bytes::from_slice(&env, &raw[..len]);
+
+    let res = env.as_contract(&id, || {
+        UltraHonkVerifier::new_trusted(&env, &vk).unwrap().verify_sumcheck_only(&proof, &pis)
+    });
+    assert!(matches!(res, Err(VerifyError::SumcheckRoundFailed(2))), "{res:?}");
+
+    let all = env.events().all();
+    let reported = all.events().iter().find_map(|e| {
+        let xdr::ContractEventBody::V0(body) = &e.body;
+        let topic = Val::try_from_val(&env, body.topics.get(1)?).ok()?;
+        if Symbol::try_from_val(&env, &topic).ok()? != Symbol::new(&env, "sumcheck_round") {
+            return None;
+        }
+        u32::try_from_val(&env, &Val::try_from_val(&env, &body.data).ok()?).ok()
+    });
+    assert_eq!(reported, Some(2));
+}
+
+#[test]
+fn budget_report_returns_cost_or_verification_error() {
+    let env = Env::default();
+    let id = env.register(Verifier, (synthetic_vk(&env, 3, 2, 1),));
+    let client = VerifierClient::new(&env, &id);
+    let pis = Bytes::from_slice(&env, &[0u8; 64]);
+    let len = ultrahonk_soroban_verifier::proof_bytes_for_log_n(3);
+
+    // The all-zero proof is trivially valid against the all-infinity VK.
+    let mut raw = [0u8; 108 * 32];
+    let cpu = client.verify_with_budget_report(&Bytes::from_slice(&env, &raw[..len]), &pis);
+    assert!(cpu > 0);
+
+    raw[32 * 32 + 31] = 1;
+    let res = client.try_verify_with_budget_report(&Bytes::from_slice(&env, &raw[..len]), &pis);
+    assert_eq!(res, Err(Ok(crate::Error::VerificationFailed)));
+}
@@ -30,6 +30,43 @@ fn verify_simple_circuit_proof_succeeds() {
     client.verify_proof(&public_inputs, &proof_bytes);
 }
 
+#[test]
+fn simple_circuit_passes_sumcheck_only() {
+    let vk_bytes_raw: &[u8] = include_bytes!("simple_circuit/target/vk_with_hash");
+    let proof_bin: &[u8] = include_bytes!("simple_circuit/target/proof");
+    let pub_inputs_bin: &[u8] = include_bytes!("simple_circuit/target/public_inputs");
+
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let vk_bytes = Bytes::from_slice(&env, vk_bytes_raw);
+    let proof_bytes: Bytes = Bytes::from_slice(&env, proof_bin);
+    let public_inputs: Bytes = Bytes::from_slice(&env, pub_inputs_bin);
+
+    let client = register_client(&env, &vk_bytes);
+    assert_eq!(client.try_verify_sumcheck_only(&proof_bytes, &public_inputs), Ok(Ok(())));
+}
+
+#[test]
+fn simple_circuit_params_size_the_fixture_inputs() {
+    let vk_bytes_raw: &[u8] = include_bytes!("simple_circuit/target/vk_with_hash");
+    let proof_bin: &[u8] = include_bytes!("simple_circuit/target/proof");
+    let pub_inputs_bin: &[u8] = include_bytes!("simple_circuit/target/public_inputs");
+
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let vk_bytes = Bytes::from_slice(&env, vk_bytes_raw);
+    let client = register_client(&env, &vk_bytes);
+    let (log_n, public_inputs_size, _offset) = client.get_circuit_params();
+
+    assert_eq!(log_n, client.vk_log_n());
+    assert_eq!(proof_bin.len() as u64, client.expected_proof_len());
+    // The VK count may or may not include the 16 pairing point fields.
+    let provided = (pub_inputs_bin.len() / 32) as u64;
+    assert!(provided == public_inputs_size || provided + 16 == public_inputs_size);
+}
+
 #[test]
 fn verify_fib_chain_proof_succeeds() {
     let vk_bytes_raw: &[u8] = include_bytes!("fib_chain/target/vk_with_hash");
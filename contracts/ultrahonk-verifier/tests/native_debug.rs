@@ -6,6 +6,8 @@ use ultrahonk_soroban_verifier::types::PAIRING_POINTS_SIZE;
 use ultrahonk_soroban_verifier::field::Fr;
 use ultrahonk_soroban_verifier::sumcheck::verify_sumcheck;
 use ultrahonk_soroban_verifier::relations::accumulate_relation_evaluations;
+use ultrahonk_soroban_verifier::ec::{g1_msm, g1_msm_batched};
+use ultrahonk_soroban_verifier::shplemini::{fold_shifted_scalars, shplemini_msm_terms};
 
 #[test]
 fn native_debug_simple_circuit() {
@@ -30,7 +32,7 @@ fn native_debug_simple_circuit() {
     let vk_data = Bytes::from_slice(&env, &vk_bytes_raw[32..]);
     let vk = load_vk_from_bytes(&vk_data).expect("VK parse failed");
     let log_n = vk.log_circuit_size as usize;
-    let proof = load_proof(&proof_bytes, log_n);
+    let proof = load_proof(&proof_bytes, log_n).expect("proof parse");
 
     println!("circuit_size={}, log_n={}, public_inputs_size={}, pub_inputs_offset={}",
         vk.circuit_size, log_n, vk.public_inputs_size, vk.pub_inputs_offset);
@@ -41,7 +43,7 @@ fn native_debug_simple_circuit() {
     println!("provided={}, pis_total={}", provided, pis_total);
 
     let mut t = generate_transcript(
-        &env, &proof, &public_inputs, &vk_hash,
+        &env, &proof, &public_inputs, &vk_hash, 0,
         vk.circuit_size, pis_total, vk.pub_inputs_offset,
     );
 
@@ -94,6 +96,7 @@ fn native_debug_simple_circuit() {
         &t.rel_params,
         &t.alphas,
         pow_partial,
+        vk.active_relations,
     );
 
     // We need to compute the last round_target properly
@@ -110,6 +113,121 @@ fn native_debug_simple_circuit() {
     }
 }
 
+#[test]
+fn native_batched_shplemini_msm_matches_naive() {
+    let vk_bytes_raw: &[u8] = include_bytes!("simple_circuit/target/vk_with_hash");
+    let proof_bin: &[u8] = include_bytes!("simple_circuit/target/proof");
+    let pub_inputs_bin: &[u8] = include_bytes!("simple_circuit/target/public_inputs");
+
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let proof_bytes = Bytes::from_slice(&env, proof_bin);
+    let public_inputs = Bytes::from_slice(&env, pub_inputs_bin);
+    let mut vk_hash = [0u8; 32];
+    vk_hash.copy_from_slice(&vk_bytes_raw[..32]);
+    let vk = load_vk_from_bytes(&Bytes::from_slice(&env, &vk_bytes_raw[32..])).expect("VK parse failed");
+    let proof = load_proof(&proof_bytes, vk.log_circuit_size as usize).expect("proof parse");
+
+    let pis_total = (public_inputs.len() / 32) as u64 + PAIRING_POINTS_SIZE as u64;
+    let mut t = generate_transcript(
+        &env, &proof, &public_inputs, &vk_hash, 0,
+        vk.circuit_size, pis_total, vk.pub_inputs_offset,
+    );
+    t.rel_params.public_inputs_delta = compute_public_input_delta_debug(
+        &public_inputs, &proof.pairing_point_object,
+        t.rel_params.beta, t.rel_params.gamma,
+        vk.pub_inputs_offset, vk.circuit_size,
+    );
+    let (coms, scalars) = shplemini_msm_terms(&proof, &vk, &t).expect("shplemini terms");
+    let mut folded = scalars;
+    fold_shifted_scalars(&mut folded);
+
+    let cpu = || env.cost_estimate().budget().cpu_instruction_cost();
+    let before = cpu();
+    let naive = g1_msm(&env, &coms, &scalars).expect("naive msm");
+    let naive_cpu = cpu() - before;
+    let before = cpu();
+    let batched = g1_msm_batched(&env, &coms, &folded).expect("batched msm");
+    let batched_cpu = cpu() - before;
+
+    println!("shplemini MSM cpu: naive={} batched={}", naive_cpu, batched_cpu);
+    assert_eq!(batched.to_array(), naive.to_array());
+    assert!(batched_cpu < naive_cpu);
+}
+
+/// Generous bound: a regression past this means verification got much heavier.
+#[cfg(feature = "testutils")]
+const SIMPLE_CIRCUIT_CPU_CEILING: u64 = 400_000_000;
+
+#[cfg(feature = "testutils")]
+#[test]
+fn native_budget_report_simple_circuit() {
+    use rs_soroban_ultrahonk::{Verifier, VerifierClient};
+
+    let vk_bytes_raw: &[u8] = include_bytes!("simple_circuit/target/vk_with_hash");
+    let proof_bin: &[u8] = include_bytes!("simple_circuit/target/proof");
+    let pub_inputs_bin: &[u8] = include_bytes!("simple_circuit/target/public_inputs");
+
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let vk_bytes = Bytes::from_slice(&env, vk_bytes_raw);
+    let proof_bytes = Bytes::from_slice(&env, proof_bin);
+    let public_inputs = Bytes::from_slice(&env, pub_inputs_bin);
+
+    let id = env.register(Verifier, (vk_bytes,));
+    let client = VerifierClient::new(&env, &id);
+    let cpu = client.verify_with_budget_report(&proof_bytes, &public_inputs);
+    println!("simple_circuit verify_proof: ~{} CPU instructions (native estimate)", cpu);
+    assert!(cpu > 0);
+    assert!(cpu < SIMPLE_CIRCUIT_CPU_CEILING);
+}
+
+#[test]
+fn native_streamed_transcript_matches_buffered_eta() {
+    let vk_bytes_raw: &[u8] = include_bytes!("simple_circuit/target/vk_with_hash");
+    let proof_bin: &[u8] = include_bytes!("simple_circuit/target/proof");
+    let pub_inputs_bin: &[u8] = include_bytes!("simple_circuit/target/public_inputs");
+
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let proof_bytes = Bytes::from_slice(&env, proof_bin);
+    let public_inputs = Bytes::from_slice(&env, pub_inputs_bin);
+    let mut vk_hash = [0u8; 32];
+    vk_hash.copy_from_slice(&vk_bytes_raw[..32]);
+    let vk = load_vk_from_bytes(&Bytes::from_slice(&env, &vk_bytes_raw[32..])).expect("VK parse failed");
+    let proof = load_proof(&proof_bytes, vk.log_circuit_size as usize).expect("proof parse");
+
+    let pis_total = (public_inputs.len() / 32) as u64 + PAIRING_POINTS_SIZE as u64;
+    let t = generate_transcript(
+        &env, &proof, &public_inputs, &vk_hash, 0,
+        vk.circuit_size, pis_total, vk.pub_inputs_offset,
+    );
+
+    // The buffered construction: one host `Bytes` grown by appends, hashed once.
+    let mut buf = Bytes::from_array(&env, &vk_hash);
+    buf.append(&public_inputs);
+    for fr in &proof.pairing_point_object {
+        buf.append(&Bytes::from_array(&env, &fr.to_bytes()));
+    }
+    for pt in [&proof.w1, &proof.w2, &proof.w3] {
+        buf.append(&Bytes::from_array(&env, &pt.x));
+        buf.append(&Bytes::from_array(&env, &pt.y));
+    }
+    let h = Fr::from_bytes(&env.crypto().keccak256(&buf).to_array()).to_bytes();
+    let hi128 = u128::from_be_bytes(h[..16].try_into().unwrap());
+    let lo128 = u128::from_be_bytes(h[16..].try_into().unwrap());
+    let widen = |v: u128| {
+        let mut out = [0u8; 32];
+        out[16..].copy_from_slice(&v.to_be_bytes());
+        Fr::from_bytes(&out)
+    };
+    assert_eq!(t.rel_params.eta, widen(lo128 & ((1u128 << 127) - 1)));
+    assert_eq!(t.rel_params.eta_two, widen((hi128 << 1) | (lo128 >> 127)));
+}
+
 fn compute_public_input_delta_debug(
     public_inputs: &Bytes,
     pairing_point_object: &[Fr],
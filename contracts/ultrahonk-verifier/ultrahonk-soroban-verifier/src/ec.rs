@@ -1,4 +1,6 @@
 use crate::{field::Fr, types::G1Point};
+use ark_bn254::Fq;
+use ark_ff::{BigInteger256, Field, PrimeField};
 use soroban_sdk::{
     crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr as Bn254Fr},
     BytesN, Env, Vec,
@@ -48,6 +50,40 @@ pub fn lhs_g2_affine(env: &Env) -> Bn254G2Affine {
 
 const INFINITY_BYTES: [u8; 64] = [0u8; 64];
 
+/// Parse a 32-byte big-endian base-field element, rejecting values >= q.
+fn fq_from_be(bytes: &[u8; 32]) -> Option<Fq> {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let start = 32 - (i + 1) * 8;
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&bytes[start..start + 8]);
+        *limb = u64::from_be_bytes(buf);
+    }
+    Fq::from_bigint(BigInteger256::new(limbs))
+}
+
+/// Check that `p` satisfies y² = x³ + 3 over the BN254 base field.
+///
+/// The all-zero encoding is the point at infinity and is accepted.
+/// Coordinates that are not canonical (>= q) are rejected.
+pub fn g1_is_on_curve(p: &G1Point) -> bool {
+    if p.to_bytes() == INFINITY_BYTES {
+        return true;
+    }
+    let (Some(x), Some(y)) = (fq_from_be(&p.x), fq_from_be(&p.y)) else {
+        return false;
+    };
+    y.square() == x.square() * x + Fq::from(3u64)
+}
+
+/// Prime-order subgroup check for G1.
+///
+/// BN254 G1 has cofactor 1, so every on-curve point is in the subgroup and
+/// this always returns true. It exists for API symmetry with G2.
+pub fn g1_in_subgroup(_p: &G1Point) -> bool {
+    true
+}
+
 /// Multi-scalar multiplication on G1: ∑ sᵢ·Cᵢ
 ///
 /// Skips terms where the scalar is zero OR the commitment is the point at
@@ -72,6 +108,39 @@ pub fn g1_msm(env: &Env, coms: &[G1Point], scalars: &[Fr]) -> Result<Bn254G1Affi
     Ok(acc)
 }
 
+/// Same sum as [`g1_msm`] with fewer host calls, for the hot Shplemini path.
+///
+/// The SDK has no host MSM, and a bucket method built from `g1_add` would
+/// trade each `g1_mul` for hundreds of additions, so this saves calls instead:
+/// a scalar of one skips the `g1_mul`, and the first surviving term seeds the
+/// accumulator instead of being added to infinity. Repeated commitments are not
+/// merged here; Shplemini folds its known duplicates beforehand (see
+/// `shplemini::fold_shifted_scalars`). Group arithmetic is exact, so the result
+/// equals the naive loop.
+pub fn g1_msm_batched(env: &Env, coms: &[G1Point], scalars: &[Fr]) -> Result<Bn254G1Affine, &'static str> {
+    if coms.len() != scalars.len() {
+        return Err("msm len mismatch");
+    }
+    let bn = env.crypto().bn254();
+    let mut acc: Option<Bn254G1Affine> = None;
+    for (c, s) in coms.iter().zip(scalars.iter()) {
+        if s.is_zero() || c.to_bytes() == INFINITY_BYTES {
+            continue;
+        }
+        let p = g1_from_point(env, c);
+        let term = if *s == Fr::one() {
+            p
+        } else {
+            bn.g1_mul(&p, &fr_to_bn254(env, s))
+        };
+        acc = Some(match acc {
+            Some(a) => bn.g1_add(&a, &term),
+            None => term,
+        });
+    }
+    Ok(acc.unwrap_or_else(|| Bn254G1Affine::from_array(env, &INFINITY_BYTES)))
+}
+
 /// Pairing product check e(P0, rhs_g2) * e(P1, lhs_g2) == 1
 #[inline(always)]
 pub fn pairing_check(env: &Env, p0: &Bn254G1Affine, p1: &Bn254G1Affine) -> bool {
@@ -97,3 +166,68 @@ pub mod helpers {
         -g1_from_point(env, pt)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generator_is_on_curve() {
+        let g = G1Point::generator();
+        assert!(g1_is_on_curve(&g));
+        assert!(g1_in_subgroup(&g));
+    }
+
+    #[test]
+    fn infinity_is_accepted() {
+        assert!(g1_is_on_curve(&G1Point::infinity()));
+    }
+
+    #[test]
+    fn perturbed_point_is_rejected() {
+        let mut p = G1Point::generator();
+        p.y[31] = 3;
+        assert!(!g1_is_on_curve(&p));
+    }
+
+    #[test]
+    fn batched_msm_matches_naive_at_lower_cpu_cost() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let g = G1Point::generator();
+        let gh = helpers::to_affine(&env, &g);
+        let two_g = G1Point::from_bytes(env.crypto().bn254().g1_add(&gh, &gh).to_array());
+        let coms = [g, two_g, G1Point::infinity(), g, two_g, g];
+        let scalars = [
+            Fr::from_u64(5),
+            Fr::one(),
+            Fr::from_u64(9),
+            Fr::from_u64(7),
+            Fr::from_u64(3),
+            Fr::zero(),
+        ];
+
+        let cpu = || env.cost_estimate().budget().cpu_instruction_cost();
+        let before = cpu();
+        let naive = g1_msm(&env, &coms, &scalars).unwrap();
+        let naive_cpu = cpu() - before;
+        let before = cpu();
+        let batched = g1_msm_batched(&env, &coms, &scalars).unwrap();
+        let batched_cpu = cpu() - before;
+
+        assert_eq!(batched.to_array(), naive.to_array());
+        assert!(batched_cpu < naive_cpu);
+    }
+
+    #[test]
+    fn non_canonical_coordinate_is_rejected() {
+        // (1, 2 + q) is the generator with y pushed out of range.
+        let mut p = G1Point::generator();
+        p.y = [
+            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+            0x58, 0x5d, 0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16,
+            0xd8, 0x7c, 0xfd, 0x49,
+        ];
+        assert!(!g1_is_on_curve(&p));
+    }
+}
@@ -5,7 +5,7 @@ use core::ops::{Add, Mul, Neg, Sub};
 use hex;
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, string::String};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
 
 #[inline(always)]
 fn normalize_hex(s: &str) -> String {
@@ -47,6 +47,19 @@ impl Fr {
         Fr(ArkFr::from_le_bytes_mod_order(&tmp))
     }
 
+    /// Construct from a 32-byte big-endian array, returning `None` when the
+    /// value is not a canonical field element (>= r) instead of reducing it.
+    pub fn from_bytes_checked(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 32 - (i + 1) * 8;
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[start..start + 8]);
+            *limb = u64::from_be_bytes(buf);
+        }
+        ArkFr::from_bigint(BigInteger256::new(limbs)).map(Fr)
+    }
+
     /// Convert to 32-byte big-endian representation.
     #[inline(always)]
     pub fn to_bytes(&self) -> [u8; 32] {
@@ -81,6 +94,36 @@ impl Fr {
     }
 }
 
+/// `base^exp`.
+pub fn pow(base: Fr, exp: u64) -> Fr {
+    base.pow(exp as u128)
+}
+
+/// Inverts every element in place with Montgomery's trick: one field
+/// inversion plus 3(n-1) multiplications. Returns `None`, leaving `elems`
+/// untouched, if any element is zero.
+pub fn batch_inverse(elems: &mut [Fr]) -> Option<()> {
+    if elems.is_empty() {
+        return Some(());
+    }
+    // prefix[i] = elems[0] * ... * elems[i]
+    let mut prefix = Vec::with_capacity(elems.len());
+    let mut acc = Fr::one();
+    for e in elems.iter() {
+        acc = acc * *e;
+        prefix.push(acc);
+    }
+
+    let mut inv_acc = acc.inverse()?;
+    for i in (1..elems.len()).rev() {
+        let inv = inv_acc * prefix[i - 1];
+        inv_acc = inv_acc * elems[i];
+        elems[i] = inv;
+    }
+    elems[0] = inv_acc;
+    Some(())
+}
+
 impl Add for Fr {
     type Output = Fr;
     fn add(self, rhs: Fr) -> Fr {
@@ -108,3 +151,50 @@ impl Neg for Fr {
         Fr(-self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // r = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+    const MODULUS_BE: [u8; 32] = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58,
+        0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00,
+        0x00, 0x01,
+    ];
+
+    #[test]
+    fn from_bytes_checked_rejects_modulus() {
+        assert_eq!(Fr::from_bytes_checked(&MODULUS_BE), None);
+        assert_eq!(Fr::from_bytes_checked(&[0xffu8; 32]), None);
+    }
+
+    #[test]
+    fn batch_inverse_matches_individual_inverses() {
+        let mut elems = [Fr::from_u64(2), Fr::from_u64(3), Fr::from_u64(5)];
+        batch_inverse(&mut elems).unwrap();
+        for (inv, x) in elems.iter().zip([2u64, 3, 5]) {
+            assert_eq!(*inv, Fr::from_u64(x).inverse().unwrap());
+            assert_eq!(*inv * Fr::from_u64(x), Fr::one());
+        }
+
+        let mut with_zero = [Fr::from_u64(2), Fr::zero()];
+        assert_eq!(batch_inverse(&mut with_zero), None);
+        assert_eq!(with_zero, [Fr::from_u64(2), Fr::zero()]);
+    }
+
+    #[test]
+    fn pow_zero_is_one() {
+        let g = Fr::from_u64(5);
+        assert_eq!(pow(g, 0), Fr::one());
+        assert_eq!(pow(g, 3), Fr::from_u64(125));
+    }
+
+    #[test]
+    fn from_bytes_checked_accepts_canonical_values() {
+        let mut below = MODULUS_BE;
+        below[31] = 0x00; // r - 1
+        assert_eq!(Fr::from_bytes_checked(&below), Some(Fr::from_bytes(&below)));
+        assert_eq!(Fr::from_bytes_checked(&Fr::from_u64(7).to_bytes()), Some(Fr::from_u64(7)));
+    }
+}
@@ -1,7 +1,69 @@
-use soroban_sdk::Bytes;
+use soroban_sdk::{Bytes, Env};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// Compute Keccak-256 using the Soroban host function.
 #[inline(always)]
 pub fn hash32(data: &Bytes) -> [u8; 32] {
     data.env().crypto().keccak256(data).to_array()
 }
+
+/// Keccak-256 input absorbed field by field.
+///
+/// Absorbed bytes stay in guest memory and reach the host once, in `finalize`.
+/// Growing a host `Bytes` instead allocates a fresh host object on every
+/// append, and none of them are released before the invocation ends.
+pub struct KeccakStream<'a> {
+    env: &'a Env,
+    buf: Vec<u8>,
+}
+
+impl<'a> KeccakStream<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        Self { env, buf: Vec::new() }
+    }
+
+    #[inline(always)]
+    pub fn absorb(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Absorb host bytes with a single copy out of the host.
+    pub fn absorb_bytes(&mut self, data: &Bytes) {
+        let start = self.buf.len();
+        self.buf.resize(start + data.len() as usize, 0);
+        data.copy_into_slice(&mut self.buf[start..]);
+    }
+
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub fn finalize(self) -> [u8; 32] {
+        hash32(&Bytes::from_slice(self.env, &self.buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streamed_hash_matches_one_shot() {
+        let env = Env::default();
+        let data: [u8; 200] = core::array::from_fn(|i| (i * 7) as u8);
+
+        let mut stream = KeccakStream::new(&env);
+        stream.absorb(&data[..32]);
+        stream.absorb_bytes(&Bytes::from_slice(&env, &data[32..150]));
+        stream.absorb(&data[150..]);
+
+        assert_eq!(stream.finalize(), hash32(&Bytes::from_slice(&env, &data)));
+        assert_eq!(KeccakStream::new(&env).finalize(), hash32(&Bytes::new(&env)));
+    }
+}
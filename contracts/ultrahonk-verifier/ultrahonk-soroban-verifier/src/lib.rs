@@ -15,5 +15,5 @@ pub mod types;
 pub mod utils;
 pub mod verifier;
 
-pub use utils::proof_bytes_for_log_n;
+pub use utils::{proof_bytes_for_log_n, public_inputs_match_vk};
 pub use verifier::UltraHonkVerifier;
@@ -6,7 +6,44 @@
 //! bb v3.0.0: 28 subrelations (was 26). qAux split into qMemory + qNnf.
 
 use crate::field::Fr;
-use crate::types::{RelationParameters, Wire, NUMBER_OF_SUBRELATIONS};
+use crate::types::{G1Point, RelationParameters, VerificationKey, Wire, NUMBER_OF_SUBRELATIONS};
+
+/// Relation families, as bits of [`VerificationKey::active_relations`].
+pub const RELATION_ARITHMETIC: u32 = 1 << 0;
+pub const RELATION_PERMUTATION: u32 = 1 << 1;
+pub const RELATION_LOOKUP: u32 = 1 << 2;
+pub const RELATION_DELTA_RANGE: u32 = 1 << 3;
+pub const RELATION_ELLIPTIC: u32 = 1 << 4;
+pub const RELATION_MEMORY: u32 = 1 << 5;
+pub const RELATION_NNF: u32 = 1 << 6;
+pub const RELATION_POSEIDON_EXTERNAL: u32 = 1 << 7;
+pub const RELATION_POSEIDON_INTERNAL: u32 = 1 << 8;
+pub const ALL_RELATIONS: u32 = (1 << 9) - 1;
+
+/// Families whose selector commitment is not the point at infinity.
+///
+/// A selector committed as infinity is the zero polynomial (the PCS opening
+/// binds its claimed evaluation to zero), so its family contributes nothing to
+/// an honest proof. Lookup subrelations also read the lookup witness columns,
+/// but those appear in no other relation, so dropping them cannot weaken the
+/// remaining constraints. The permutation argument has no selector.
+pub fn active_relations(vk: &VerificationKey) -> u32 {
+    let inf = G1Point::infinity();
+    let selectors = [
+        (vk.q_arith, RELATION_ARITHMETIC),
+        (vk.q_lookup, RELATION_LOOKUP),
+        (vk.q_delta_range, RELATION_DELTA_RANGE),
+        (vk.q_elliptic, RELATION_ELLIPTIC),
+        (vk.q_memory, RELATION_MEMORY),
+        (vk.q_nnf, RELATION_NNF),
+        (vk.q_poseidon2_external, RELATION_POSEIDON_EXTERNAL),
+        (vk.q_poseidon2_internal, RELATION_POSEIDON_INTERNAL),
+    ];
+    selectors
+        .iter()
+        .filter(|(q, _)| *q != inf)
+        .fold(RELATION_PERMUTATION, |acc, (_, bit)| acc | bit)
+}
 
 /// Precomputed NEG_HALF = (p - 1)/2 in BN254 scalar field.
 fn neg_half() -> Fr {
@@ -401,48 +438,207 @@ fn scale_and_batch_subrelations(evaluations: &[Fr], subrelation_challenges: &[Fr
     accumulator
 }
 
-/// Main entrypoint: accumulate all subrelations and batch with alphas.
+/// Main entrypoint: accumulate the `active` relation families (see
+/// [`active_relations`]) and batch with alphas. Subrelations of inactive
+/// families stay zero in the batch.
 pub fn accumulate_relation_evaluations(
     purported_evaluations: &[Fr],
     rp: &RelationParameters,
     alphas: &[Fr],
     pow_partial_eval: Fr,
+    active: u32,
 ) -> Fr {
     let mut evaluations = [Fr::zero(); NUMBER_OF_SUBRELATIONS];
+    let on = |family: u32| active & family != 0;
 
-    accumulate_arithmetic_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
-    accumulate_permutation_relation(
-        purported_evaluations,
-        rp,
-        &mut evaluations,
-        pow_partial_eval,
-    );
-    accumulate_log_derivative_lookup_relation(
-        purported_evaluations,
-        rp,
-        &mut evaluations,
-        pow_partial_eval,
-    );
-    accumulate_delta_range_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
-    accumulate_elliptic_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
-    accumulate_memory_relation(
-        purported_evaluations,
-        rp,
-        &mut evaluations,
-        pow_partial_eval,
-    );
-    accumulate_nnf_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
-    accumulate_poseidon_external_relation(
-        purported_evaluations,
-        &mut evaluations,
-        pow_partial_eval,
-    );
-    accumulate_poseidon_internal_relation(
-        purported_evaluations,
-        &mut evaluations,
-        pow_partial_eval,
-    );
+    if on(RELATION_ARITHMETIC) {
+        accumulate_arithmetic_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
+    }
+    if on(RELATION_PERMUTATION) {
+        accumulate_permutation_relation(
+            purported_evaluations,
+            rp,
+            &mut evaluations,
+            pow_partial_eval,
+        );
+    }
+    if on(RELATION_LOOKUP) {
+        accumulate_log_derivative_lookup_relation(
+            purported_evaluations,
+            rp,
+            &mut evaluations,
+            pow_partial_eval,
+        );
+    }
+    if on(RELATION_DELTA_RANGE) {
+        accumulate_delta_range_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
+    }
+    if on(RELATION_ELLIPTIC) {
+        accumulate_elliptic_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
+    }
+    if on(RELATION_MEMORY) {
+        accumulate_memory_relation(
+            purported_evaluations,
+            rp,
+            &mut evaluations,
+            pow_partial_eval,
+        );
+    }
+    if on(RELATION_NNF) {
+        accumulate_nnf_relation(purported_evaluations, &mut evaluations, pow_partial_eval);
+    }
+    if on(RELATION_POSEIDON_EXTERNAL) {
+        accumulate_poseidon_external_relation(
+            purported_evaluations,
+            &mut evaluations,
+            pow_partial_eval,
+        );
+    }
+    if on(RELATION_POSEIDON_INTERNAL) {
+        accumulate_poseidon_internal_relation(
+            purported_evaluations,
+            &mut evaluations,
+            pow_partial_eval,
+        );
+    }
 
     let accumulator = scale_and_batch_subrelations(&evaluations, alphas);
     accumulator
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{NUMBER_OF_ALPHAS, NUMBER_OF_ENTITIES};
+
+    fn zero_params() -> RelationParameters {
+        RelationParameters {
+            eta: Fr::zero(),
+            eta_two: Fr::zero(),
+            eta_three: Fr::zero(),
+            beta: Fr::zero(),
+            gamma: Fr::zero(),
+            public_inputs_delta: Fr::zero(),
+        }
+    }
+
+    /// alphas[i] = i + 2, so every subrelation gets a distinct, easy-to-follow weight.
+    fn test_alphas() -> [Fr; NUMBER_OF_ALPHAS] {
+        core::array::from_fn(|i| Fr::from_u64(i as u64 + 2))
+    }
+
+    fn set(p: &mut [Fr; NUMBER_OF_ENTITIES], w: Wire, v: u64) {
+        p[w.index()] = Fr::from_u64(v);
+    }
+
+    #[test]
+    fn all_zero_evaluations_accumulate_to_zero() {
+        let p = [Fr::zero(); NUMBER_OF_ENTITIES];
+        let acc = accumulate_relation_evaluations(&p, &zero_params(), &test_alphas(), Fr::one(), ALL_RELATIONS);
+        assert_eq!(acc, Fr::zero());
+    }
+
+    #[test]
+    fn arithmetic_relation_with_q_arith_one_matches_reference() {
+        // q_arith = 1 disables relation 1 ((q-1) factor) and the W4Shift term of relation 0.
+        let mut p = [Fr::zero(); NUMBER_OF_ENTITIES];
+        set(&mut p, Wire::QArith, 1);
+        set(&mut p, Wire::Qm, 2);
+        set(&mut p, Wire::Wl, 3);
+        set(&mut p, Wire::Wr, 5);
+        set(&mut p, Wire::Ql, 7);
+        set(&mut p, Wire::Qr, 11);
+        set(&mut p, Wire::Qo, 13);
+        set(&mut p, Wire::Wo, 17);
+        set(&mut p, Wire::Qc, 19);
+
+        // (1 - 3) * qm * wr * wl * (-1/2) = -2 * 30 * (-1/2) = 30
+        // ql*wl + qr*wr + qo*wo + qc = 21 + 55 + 221 + 19 = 316
+        // (30 + 316) * q_arith * domain_sep(2) = 692
+        let acc = accumulate_relation_evaluations(&p, &zero_params(), &test_alphas(), Fr::from_u64(2), ALL_RELATIONS);
+        assert_eq!(acc, Fr::from_u64(692));
+    }
+
+    #[test]
+    fn arithmetic_relation_with_q_arith_three_matches_reference() {
+        // q_arith = 3 disables the qm product term and enables relation 1.
+        let mut p = [Fr::zero(); NUMBER_OF_ENTITIES];
+        set(&mut p, Wire::QArith, 3);
+        set(&mut p, Wire::Qm, 2);
+        set(&mut p, Wire::Wl, 3);
+        set(&mut p, Wire::Ql, 7);
+        set(&mut p, Wire::W4, 4);
+        set(&mut p, Wire::Q4, 5);
+        set(&mut p, Wire::W4Shift, 6);
+        set(&mut p, Wire::WlShift, 1);
+
+        // Relation 0: (ql*wl + q4*w4 + (3 - 1)*w4_shift) * 3 = (21 + 20 + 12) * 3 = 159
+        // Relation 1: (wl + w4 - wl_shift + qm) * (3-2)(3-1)(3) = (3 + 4 - 1 + 2) * 6 = 48
+        // Batched: 159 + 48 * alphas[0](2) = 255
+        let acc = accumulate_relation_evaluations(&p, &zero_params(), &test_alphas(), Fr::one(), ALL_RELATIONS);
+        assert_eq!(acc, Fr::from_u64(255));
+    }
+
+    #[test]
+    fn permutation_relation_matches_reference() {
+        let mut p = [Fr::zero(); NUMBER_OF_ENTITIES];
+        set(&mut p, Wire::Wl, 1);
+        set(&mut p, Wire::Wr, 2);
+        set(&mut p, Wire::Wo, 3);
+        set(&mut p, Wire::W4, 4);
+        set(&mut p, Wire::Id1, 5);
+        set(&mut p, Wire::Id2, 6);
+        set(&mut p, Wire::Id3, 7);
+        set(&mut p, Wire::Id4, 8);
+        set(&mut p, Wire::Sigma1, 8);
+        set(&mut p, Wire::Sigma2, 7);
+        set(&mut p, Wire::Sigma3, 6);
+        set(&mut p, Wire::Sigma4, 5);
+        set(&mut p, Wire::ZPerm, 1);
+        set(&mut p, Wire::ZPermShift, 1);
+        set(&mut p, Wire::LagrangeLast, 1);
+
+        let mut rp = zero_params();
+        rp.beta = Fr::one();
+        rp.gamma = Fr::one();
+        rp.public_inputs_delta = Fr::from_u64(2);
+
+        // numerator   = (1+5+1)(2+6+1)(3+7+1)(4+8+1) = 7*9*11*13 = 9009
+        // denominator = (1+8+1)(2+7+1)(3+6+1)(4+5+1) = 10^4     = 10000
+        // Relation 2: (z_perm + l_first) * num - (z_perm_shift + l_last * delta) * den
+        //           = 1 * 9009 - (1 + 2) * 10000 = -20991
+        // Relation 3: l_last * z_perm_shift = 1
+        // Batched: -20991 * alphas[1](3) + 1 * alphas[2](4) = -62969
+        let acc = accumulate_relation_evaluations(&p, &rp, &test_alphas(), Fr::one(), ALL_RELATIONS);
+        assert_eq!(acc, Fr::zero() - Fr::from_u64(62969));
+    }
+
+    #[test]
+    fn skipping_lookup_family_matches_full_sum_without_lookups() {
+        // Arbitrary non-zero evaluations for every entity, except that the
+        // circuit has no lookup gates and so an all-zero lookup witness.
+        let mut p: [Fr; NUMBER_OF_ENTITIES] = core::array::from_fn(|i| Fr::from_u64(i as u64 + 3));
+        for w in [Wire::QLookup, Wire::LookupInverses, Wire::LookupReadCounts, Wire::LookupReadTags] {
+            p[w.index()] = Fr::zero();
+        }
+        let rp = RelationParameters {
+            eta: Fr::from_u64(5),
+            eta_two: Fr::from_u64(25),
+            eta_three: Fr::from_u64(125),
+            beta: Fr::from_u64(7),
+            gamma: Fr::from_u64(11),
+            public_inputs_delta: Fr::from_u64(13),
+        };
+
+        let full = accumulate_relation_evaluations(&p, &rp, &test_alphas(), Fr::from_u64(3), ALL_RELATIONS);
+        let skipped = accumulate_relation_evaluations(
+            &p,
+            &rp,
+            &test_alphas(),
+            Fr::from_u64(3),
+            ALL_RELATIONS & !RELATION_LOOKUP,
+        );
+        assert_ne!(full, Fr::zero());
+        assert_eq!(full, skipped);
+    }
+}
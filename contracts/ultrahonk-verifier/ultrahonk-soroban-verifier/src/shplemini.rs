@@ -1,14 +1,23 @@
 //! Shplemini batch-opening verifier for BN254
 use crate::ec::helpers::negate;
-use crate::ec::{g1_msm, pairing_check};
+use crate::ec::{g1_msm_batched, pairing_check};
 use crate::field::Fr;
 use crate::trace;
 use crate::types::{
     G1Point, Proof, Transcript, VerificationKey, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ENTITIES,
-    NUMBER_TO_BE_SHIFTED, NUMBER_UNSHIFTED,
+    NUMBER_OF_VK_COMMITMENTS, NUMBER_TO_BE_SHIFTED, NUMBER_UNSHIFTED,
 };
 use soroban_sdk::Env;
 
+/// Number of `(point, scalar)` terms in the Shplemini MSM.
+/// Match Solidity sizing: NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 2
+pub const SHPLEMINI_MSM_TERMS: usize = 1 + NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 1;
+
+/// MSM slot of `w1`, the first of the wires that also appear shifted.
+const SHIFTABLE_START: usize = 1 + NUMBER_OF_VK_COMMITMENTS;
+/// MSM slot of the shifted `w1`; the shifted copies follow in the same order.
+const SHIFTED_START: usize = 1 + NUMBER_UNSHIFTED;
+
 /// Shplemini verification
 pub fn verify_shplemini(
     env: &Env,
@@ -16,6 +25,37 @@ pub fn verify_shplemini(
     vk: &VerificationKey,
     tp: &Transcript,
 ) -> Result<(), &'static str> {
+    let (coms, mut scalars) = shplemini_msm_terms(proof, vk, tp)?;
+    fold_shifted_scalars(&mut scalars);
+
+    // 12) MSM + pairing
+    let p0 = g1_msm_batched(env, &coms, &scalars)?;
+    let p1 = negate(env, &proof.kzg_quotient);
+    if pairing_check(env, &p0, &p1) {
+        Ok(())
+    } else {
+        Err("Shplonk pairing check failed")
+    }
+}
+
+/// Moves each shifted wire's scalar onto the unshifted slot holding the same
+/// commitment (`w1..w4`, `z_perm`) and zeroes the shifted slot, so the MSM
+/// multiplies each of those points once. The sum is unchanged.
+pub fn fold_shifted_scalars(scalars: &mut [Fr; SHPLEMINI_MSM_TERMS]) {
+    for k in 0..NUMBER_TO_BE_SHIFTED {
+        scalars[SHIFTABLE_START + k] = scalars[SHIFTABLE_START + k] + scalars[SHIFTED_START + k];
+        scalars[SHIFTED_START + k] = Fr::zero();
+    }
+}
+
+/// Steps 1–11 of Shplemini: the commitments and scalars whose MSM is the
+/// left pairing input. Exposed so tests can compare MSM strategies.
+#[allow(clippy::type_complexity)]
+pub fn shplemini_msm_terms(
+    proof: &Proof,
+    vk: &VerificationKey,
+    tp: &Transcript,
+) -> Result<([G1Point; SHPLEMINI_MSM_TERMS], [Fr; SHPLEMINI_MSM_TERMS]), &'static str> {
     // 1) r^{2^i}
     let log_n = vk.log_circuit_size as usize;
     let mut r_pows = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
@@ -24,14 +64,13 @@ pub fn verify_shplemini(
         r_pows[i] = r_pows[i - 1] * r_pows[i - 1];
     }
     // 2) allocate arrays
-    // Match Solidity sizing: NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 2
     // Layout:
     //   [0]                 = shplonk_Q
     //   [1..=40]            = VK + proof entities (NUMBER_OF_ENTITIES)
     //   [41..=67]           = gemini_fold_comms (CONST_PROOF_SIZE_LOG_N - 1 = 27)
     //   [68]                = generator (1,2) with const_acc scalar
     //   [69]                = kzg_quotient with scalar z
-    const TOTAL: usize = 1 + NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 1;
+    const TOTAL: usize = SHPLEMINI_MSM_TERMS;
     trace!("total = {}", TOTAL);
     let mut scalars = [Fr::zero(); TOTAL];
     let mut coms = [G1Point::infinity(); TOTAL];
@@ -72,43 +111,10 @@ pub fn verify_shplemini(
     }
     // 6) load VK & proof
     {
-        let mut j = 1;
-        macro_rules! push {
-            ($f:ident) => {{
-                coms[j] = vk.$f.clone();
-                j += 1;
-            }};
-        }
-        push!(qm);
-        push!(qc);
-        push!(ql);
-        push!(qr);
-        push!(qo);
-        push!(q4);
-        // Match bb v3.0.0 VK commitment order strictly
-        // 7..14: qLookup, qArith, qDeltaRange, qElliptic, qMemory, qNnf, qPoseidon2External, qPoseidon2Internal
-        push!(q_lookup);
-        push!(q_arith);
-        push!(q_delta_range);
-        push!(q_elliptic);
-        push!(q_memory);
-        push!(q_nnf);
-        push!(q_poseidon2_external);
-        push!(q_poseidon2_internal);
-        push!(s1);
-        push!(s2);
-        push!(s3);
-        push!(s4);
-        push!(id1);
-        push!(id2);
-        push!(id3);
-        push!(id4);
-        push!(t1);
-        push!(t2);
-        push!(t3);
-        push!(t4);
-        push!(lagrange_first);
-        push!(lagrange_last);
+        // bb v3.0.0 VK commitment order, see `VerificationKey::commitments`
+        let vk_coms = vk.commitments();
+        coms[1..=NUMBER_OF_VK_COMMITMENTS].copy_from_slice(&vk_coms);
+        let mut j = 1 + NUMBER_OF_VK_COMMITMENTS;
 
         coms[j] = proof.w1.clone();
         j += 1;
@@ -195,12 +201,44 @@ pub fn verify_shplemini(
     coms[q_idx] = proof.kzg_quotient.clone();
     scalars[q_idx] = tp.shplonk_z;
 
-    // 12) MSM + pairing
-    let p0 = g1_msm(env, &coms, &scalars)?;
-    let p1 = negate(env, &proof.kzg_quotient);
-    if pairing_check(env, &p0, &p1) {
-        Ok(())
-    } else {
-        Err("Shplonk pairing check failed")
+    Ok((coms, scalars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec::{g1_msm, helpers};
+
+    #[test]
+    fn folding_shifted_scalars_keeps_the_msm_at_lower_cpu_cost() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let bn = env.crypto().bn254();
+        let g = helpers::to_affine(&env, &G1Point::generator());
+        let mut coms = [G1Point::infinity(); SHPLEMINI_MSM_TERMS];
+        let mut scalars = [Fr::zero(); SHPLEMINI_MSM_TERMS];
+        // Distinct wire commitments, each listed again in its shifted slot.
+        let mut p = g.clone();
+        for k in 0..NUMBER_TO_BE_SHIFTED {
+            let point = G1Point::from_bytes(p.to_array());
+            coms[SHIFTABLE_START + k] = point;
+            coms[SHIFTED_START + k] = point;
+            scalars[SHIFTABLE_START + k] = Fr::from_u64(3 + k as u64);
+            scalars[SHIFTED_START + k] = Fr::from_u64(11 + k as u64);
+            p = bn.g1_add(&p, &g);
+        }
+        let mut folded = scalars;
+        fold_shifted_scalars(&mut folded);
+
+        let cpu = || env.cost_estimate().budget().cpu_instruction_cost();
+        let before = cpu();
+        let naive = g1_msm(&env, &coms, &scalars).unwrap();
+        let naive_cpu = cpu() - before;
+        let before = cpu();
+        let batched = g1_msm_batched(&env, &coms, &folded).unwrap();
+        let batched_cpu = cpu() - before;
+
+        assert_eq!(batched.to_array(), naive.to_array());
+        assert!(batched_cpu < naive_cpu);
     }
 }
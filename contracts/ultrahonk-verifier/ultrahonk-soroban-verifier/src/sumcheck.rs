@@ -1,62 +1,27 @@
 //! Sum-check verifier
+use ark_ff::MontFp;
 use crate::{
-    field::Fr,
+    field::{batch_inverse, Fr},
     relations::accumulate_relation_evaluations,
     trace,
     types::{Transcript, VerificationKey, BATCHED_RELATION_PARTIAL_LENGTH},
 };
 
+/// Error returned when a round's univariate does not sum to the round target.
+pub const ROUND_FAILED: &str = "round failed";
+
 /// Barycentric Lagrange denominators for evaluation points {0, 1, ..., 7}.
-/// d_i = ∏_{j≠i} (i - j) as BN254 scalar field elements.
-const BARY_BYTES: [[u8; 32]; BATCHED_RELATION_PARTIAL_LENGTH] = [
-    // d_0 = -5040 (p - 5040)
-    [
-        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-        0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
-        0xef, 0xff, 0xec, 0x51,
-    ],
-    // d_1 = 720
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x02, 0xd0,
-    ],
-    // d_2 = -240 (p - 240)
-    [
-        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-        0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
-        0xef, 0xff, 0xff, 0x11,
-    ],
-    // d_3 = 144
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x90,
-    ],
-    // d_4 = -144 (p - 144)
-    [
-        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-        0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
-        0xef, 0xff, 0xff, 0x71,
-    ],
-    // d_5 = 240
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0xf0,
-    ],
-    // d_6 = -720 (p - 720)
-    [
-        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-        0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
-        0xef, 0xff, 0xfd, 0x31,
-    ],
-    // d_7 = 5040
-    [
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x13, 0xb0,
-    ],
+/// d_i = ∏_{j≠i} (i - j) as BN254 scalar field elements, built at compile
+/// time so the sumcheck rounds never decode them.
+const BARY: [Fr; BATCHED_RELATION_PARTIAL_LENGTH] = [
+    Fr(MontFp!("-5040")),
+    Fr(MontFp!("720")),
+    Fr(MontFp!("-240")),
+    Fr(MontFp!("144")),
+    Fr(MontFp!("-144")),
+    Fr(MontFp!("240")),
+    Fr(MontFp!("-720")),
+    Fr(MontFp!("5040")),
 ];
 
 /// Check if the sum of two univariates equals the target value
@@ -68,10 +33,9 @@ fn check_sum(round_univariate: &[Fr], round_target: Fr) -> bool {
 
 /// Calculate next target value for the sum-check.
 ///
-/// Uses Montgomery's batch-inversion trick: instead of 8 individual field
-/// inversions we compute the product of all denominators, invert once, then
-/// unwind to recover each individual inverse.  This saves ~7 expensive modular
-/// inversions per round (196 inversions saved across 28 rounds).
+/// The 8 barycentric denominators are inverted together with
+/// [`batch_inverse`], saving ~7 modular inversions per round (196 across 28
+/// rounds).
 #[inline(always)]
 fn compute_next_target_sum(
     round_univariate: &[Fr],
@@ -85,32 +49,12 @@ fn compute_next_target_sum(
         b_poly = b_poly * chi_minus[i];
     }
 
-    // Compute all denominators: denom[i] = BARY[i] * (χ - i)
-    let mut denoms = [Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH];
-    for i in 0..BATCHED_RELATION_PARTIAL_LENGTH {
-        denoms[i] = Fr::from_bytes(&BARY_BYTES[i]) * chi_minus[i];
-    }
-
-    // Montgomery's batch inversion:
-    // 1) Forward pass: prefix[i] = denom[0] * denom[1] * ... * denom[i]
-    let mut prefix = [Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH];
-    prefix[0] = denoms[0];
-    for i in 1..BATCHED_RELATION_PARTIAL_LENGTH {
-        prefix[i] = prefix[i - 1] * denoms[i];
-    }
-
-    // 2) Single inversion of the full product
-    let mut inv_acc = prefix[BATCHED_RELATION_PARTIAL_LENGTH - 1]
-        .inverse()
-        .ok_or("denom zero")?;
-
-    // 3) Backward pass: recover individual inverses
+    // Compute all inverse denominators: 1 / (BARY[i] * (χ - i))
     let mut inv = [Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH];
-    for i in (1..BATCHED_RELATION_PARTIAL_LENGTH).rev() {
-        inv[i] = inv_acc * prefix[i - 1];
-        inv_acc = inv_acc * denoms[i];
+    for i in 0..BATCHED_RELATION_PARTIAL_LENGTH {
+        inv[i] = BARY[i] * chi_minus[i];
     }
-    inv[0] = inv_acc;
+    batch_inverse(&mut inv).ok_or("denom zero")?;
 
     // Σ u_i / (BARY[i] * (χ - i))
     let mut acc = Fr::zero();
@@ -134,6 +78,18 @@ pub fn verify_sumcheck(
     proof: &crate::types::Proof,
     tp: &Transcript,
     vk: &VerificationKey,
+) -> Result<(), &'static str> {
+    let mut rounds = 0u32;
+    verify_sumcheck_with_rounds(proof, tp, vk, &mut rounds)
+}
+
+/// Same as `verify_sumcheck`, additionally reporting the number of rounds
+/// executed (a failing round counts as executed).
+pub fn verify_sumcheck_with_rounds(
+    proof: &crate::types::Proof,
+    tp: &Transcript,
+    vk: &VerificationKey,
+    rounds: &mut u32,
 ) -> Result<(), &'static str> {
     let log_n = vk.log_circuit_size as usize;
     let mut round_target = Fr::zero();
@@ -141,6 +97,7 @@ pub fn verify_sumcheck(
 
     // 1) Each round sum check and next target/pow calculation
     for round in 0..log_n {
+        *rounds = round as u32 + 1;
         let round_univariate = &proof.sumcheck_univariates[round];
 
         trace!(
@@ -157,7 +114,7 @@ pub fn verify_sumcheck(
                 hex::encode(round_univariate[0].to_bytes()),
                 hex::encode(round_univariate[1].to_bytes())
             );
-            return Err("round failed");
+            return Err(ROUND_FAILED);
         }
 
         let round_challenge = tp.sumcheck_u_challenges[round];
@@ -181,6 +138,7 @@ pub fn verify_sumcheck(
         &tp.rel_params,
         &tp.alphas,
         pow_partial_evaluation,
+        vk.active_relations,
     );
 
     if grand_honk_relation_sum == round_target {
@@ -200,3 +158,67 @@ pub fn verify_sumcheck(
         Err("sumcheck final mismatch")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Big-endian reference encodings of the denominators above.
+    const BARY_BYTES: [[u8; 32]; BATCHED_RELATION_PARTIAL_LENGTH] = [
+        // d_0 = -5040 (p - 5040)
+        [
+            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+            0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
+            0xef, 0xff, 0xec, 0x51,
+        ],
+        // d_1 = 720
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x02, 0xd0,
+        ],
+        // d_2 = -240 (p - 240)
+        [
+            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+            0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
+            0xef, 0xff, 0xff, 0x11,
+        ],
+        // d_3 = 144
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x90,
+        ],
+        // d_4 = -144 (p - 144)
+        [
+            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+            0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
+            0xef, 0xff, 0xff, 0x71,
+        ],
+        // d_5 = 240
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0xf0,
+        ],
+        // d_6 = -720 (p - 720)
+        [
+            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+            0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
+            0xef, 0xff, 0xfd, 0x31,
+        ],
+        // d_7 = 5040
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x13, 0xb0,
+        ],
+    ];
+
+    #[test]
+    fn bary_constants_match_decoded_bytes() {
+        for i in 0..BATCHED_RELATION_PARTIAL_LENGTH {
+            assert_eq!(BARY[i], Fr::from_bytes(&BARY_BYTES[i]), "d_{i}");
+        }
+    }
+}
@@ -8,10 +8,13 @@
 //!   - alpha: single alpha from hash, then alpha^i powers
 //!   - gate challenges: first from hash, then gc[i] = gc[i-1]^2
 //!   - points serialized as raw (x, y) 32-byte coordinates (no limb splitting)
+//!
+//! Each round's hash input is absorbed through `hash::KeccakStream`, so the host
+//! sees one buffer per challenge rather than one new object per field.
 
 use crate::{
     field::Fr,
-    hash::hash32,
+    hash::KeccakStream,
     trace,
     types::{
         G1Point, Proof, RelationParameters, Transcript, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ALPHAS,
@@ -20,17 +23,17 @@ use crate::{
 };
 use soroban_sdk::{Bytes, Env};
 
-/// Helper: append a [u8; 32] slice to a Bytes buffer.
+/// Helper: absorb a [u8; 32] field into the hash input.
 #[inline(always)]
-fn push_32(env: &Env, buf: &mut Bytes, arr: &[u8; 32]) {
-    buf.append(&Bytes::from_array(env, arr));
+fn push_32(buf: &mut KeccakStream, arr: &[u8; 32]) {
+    buf.absorb(arr);
 }
 
 /// Push raw G1 point (x, y) as two 32-byte coordinates.
 /// Matches Solidity's `abi.encodePacked(pt.x, pt.y)`.
-fn push_point(env: &Env, buf: &mut Bytes, pt: &G1Point) {
-    push_32(env, buf, &pt.x);
-    push_32(env, buf, &pt.y);
+fn push_point(buf: &mut KeccakStream, pt: &G1Point) {
+    push_32(buf, &pt.x);
+    push_32(buf, &pt.y);
 }
 
 /// Split a challenge into two 127-bit halves.
@@ -70,51 +73,65 @@ fn split_challenge(challenge: Fr) -> (Fr, Fr) {
 }
 
 #[inline(always)]
-fn hash_to_fr(bytes: &Bytes) -> Fr {
-    Fr::from_bytes(&hash32(bytes))
+fn hash_to_fr(data: KeccakStream) -> Fr {
+    Fr::from_bytes(&data.finalize())
+}
+
+/// Hash of a single challenge, used to derive the next one.
+fn rehash(env: &Env, challenge: Fr) -> Fr {
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &challenge.to_bytes());
+    hash_to_fr(data)
 }
 
 /// Generate eta, eta_two, eta_three challenges.
 /// Matches Solidity: hash(vk_hash, public_inputs[..], pairing_point_object[..], w1, w2, w3)
+/// for domain 0. A non-zero `circuit_domain` is absorbed right after the VK hash.
 fn generate_eta_challenge(
     env: &Env,
     proof: &Proof,
     public_inputs: &Bytes,
     vk_hash: &[u8; 32],
+    circuit_domain: u32,
     public_inputs_size: u64,
 ) -> (Fr, Fr, Fr, Fr) {
-    let mut data = Bytes::new(env);
+    let mut data = KeccakStream::new(env);
 
     // 1) VK hash
-    push_32(env, &mut data, vk_hash);
+    push_32(&mut data, vk_hash);
+
+    // 1b) Circuit domain tag, as a 32-byte big-endian field element. Domain 0
+    // absorbs nothing so it stays byte-compatible with the bb reference.
+    if circuit_domain != 0 {
+        push_32(&mut data, &Fr::from_u64(circuit_domain as u64).to_bytes());
+    }
 
     // 2) User public inputs (raw bytes, already 32-byte aligned field elements)
     let user_inputs_count = public_inputs_size - PAIRING_POINTS_SIZE as u64;
     let user_input_bytes = (user_inputs_count as u32) * 32;
     if user_input_bytes > 0 && public_inputs.len() >= user_input_bytes {
         let pi_slice = public_inputs.slice(0..user_input_bytes);
-        data.append(&pi_slice);
+        data.absorb_bytes(&pi_slice);
     }
 
     // 3) Pairing point object
     for fr in &proof.pairing_point_object {
-        push_32(env, &mut data, &fr.to_bytes());
+        push_32(&mut data, &fr.to_bytes());
     }
 
     // 4) w1, w2, w3 as raw (x, y) coordinates
-    push_point(env, &mut data, &proof.w1);
-    push_point(env, &mut data, &proof.w2);
-    push_point(env, &mut data, &proof.w3);
+    push_point(&mut data, &proof.w1);
+    push_point(&mut data, &proof.w2);
+    push_point(&mut data, &proof.w3);
 
     trace!("[transcript] eta_data len = {}", data.len());
-    let previous_challenge = hash_to_fr(&data);
+    let previous_challenge = hash_to_fr(data);
     trace!("[transcript] eta_hash = 0x{}", hex::encode(previous_challenge.to_bytes()));
     let (eta, eta_two) = split_challenge(previous_challenge);
     trace!("[transcript] eta     = 0x{}", hex::encode(eta.to_bytes()));
     trace!("[transcript] eta_two = 0x{}", hex::encode(eta_two.to_bytes()));
 
-    let prev_bytes = Bytes::from_array(env, &previous_challenge.to_bytes());
-    let previous_challenge = hash_to_fr(&prev_bytes);
+    let previous_challenge = rehash(env, previous_challenge);
     let (eta_three, _) = split_challenge(previous_challenge);
     trace!("[transcript] eta_three = 0x{}", hex::encode(eta_three.to_bytes()));
 
@@ -128,13 +145,13 @@ fn generate_beta_and_gamma_challenges(
     previous_challenge: Fr,
     proof: &Proof,
 ) -> (Fr, Fr, Fr) {
-    let mut data = Bytes::new(env);
-    push_32(env, &mut data, &previous_challenge.to_bytes());
-    push_point(env, &mut data, &proof.lookup_read_counts);
-    push_point(env, &mut data, &proof.lookup_read_tags);
-    push_point(env, &mut data, &proof.w4);
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &previous_challenge.to_bytes());
+    push_point(&mut data, &proof.lookup_read_counts);
+    push_point(&mut data, &proof.lookup_read_tags);
+    push_point(&mut data, &proof.w4);
 
-    let next_previous_challenge = hash_to_fr(&data);
+    let next_previous_challenge = hash_to_fr(data);
     let (beta, gamma) = split_challenge(next_previous_challenge);
     trace!("[transcript] beta  = 0x{}", hex::encode(beta.to_bytes()));
     trace!("[transcript] gamma = 0x{}", hex::encode(gamma.to_bytes()));
@@ -148,12 +165,12 @@ fn generate_alpha_challenges(
     previous_challenge: Fr,
     proof: &Proof,
 ) -> ([Fr; NUMBER_OF_ALPHAS], Fr) {
-    let mut data = Bytes::new(env);
-    push_32(env, &mut data, &previous_challenge.to_bytes());
-    push_point(env, &mut data, &proof.lookup_inverses);
-    push_point(env, &mut data, &proof.z_perm);
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &previous_challenge.to_bytes());
+    push_point(&mut data, &proof.lookup_inverses);
+    push_point(&mut data, &proof.z_perm);
 
-    let next_previous_challenge = hash_to_fr(&data);
+    let next_previous_challenge = hash_to_fr(data);
     let (alpha, _) = split_challenge(next_previous_challenge);
     trace!("[transcript] alpha = 0x{}", hex::encode(alpha.to_bytes()));
 
@@ -173,8 +190,7 @@ fn generate_gate_challenges(
     env: &Env,
     previous_challenge: Fr,
 ) -> ([Fr; CONST_PROOF_SIZE_LOG_N], Fr) {
-    let prev_bytes = Bytes::from_array(env, &previous_challenge.to_bytes());
-    let next_previous_challenge = hash_to_fr(&prev_bytes);
+    let next_previous_challenge = rehash(env, previous_challenge);
     let (gc0, _) = split_challenge(next_previous_challenge);
     trace!("[transcript] gate_challenge[0] = 0x{}", hex::encode(gc0.to_bytes()));
 
@@ -198,12 +214,12 @@ fn generate_sumcheck_challenges(
     let mut next_previous_challenge = previous_challenge;
     let mut sumcheck_challenges = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
     for r in 0..log_n {
-        let mut data = Bytes::new(env);
-        push_32(env, &mut data, &next_previous_challenge.to_bytes());
+        let mut data = KeccakStream::new(env);
+        push_32(&mut data, &next_previous_challenge.to_bytes());
         for &c in proof.sumcheck_univariates[r].iter() {
-            push_32(env, &mut data, &c.to_bytes());
+            push_32(&mut data, &c.to_bytes());
         }
-        next_previous_challenge = hash_to_fr(&data);
+        next_previous_challenge = hash_to_fr(data);
         sumcheck_challenges[r] = split_challenge(next_previous_challenge).0;
         trace!(
             "[transcript] sumcheck_u[{}] = 0x{}",
@@ -217,12 +233,12 @@ fn generate_sumcheck_challenges(
 /// Generate rho challenge.
 /// Matches Solidity: hash(prev, sumcheck_evaluations[0..NUMBER_OF_ENTITIES])
 fn generate_rho_challenge(env: &Env, proof: &Proof, previous_challenge: Fr) -> (Fr, Fr) {
-    let mut data = Bytes::new(env);
-    push_32(env, &mut data, &previous_challenge.to_bytes());
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &previous_challenge.to_bytes());
     for &e in proof.sumcheck_evaluations.iter() {
-        push_32(env, &mut data, &e.to_bytes());
+        push_32(&mut data, &e.to_bytes());
     }
-    let next_previous_challenge = hash_to_fr(&data);
+    let next_previous_challenge = hash_to_fr(data);
     let rho = split_challenge(next_previous_challenge).0;
     (rho, next_previous_challenge)
 }
@@ -232,12 +248,12 @@ fn generate_rho_challenge(env: &Env, proof: &Proof, previous_challenge: Fr) -> (
 fn generate_gemini_r_challenge(
     env: &Env, proof: &Proof, previous_challenge: Fr, log_n: usize,
 ) -> (Fr, Fr) {
-    let mut data = Bytes::new(env);
-    push_32(env, &mut data, &previous_challenge.to_bytes());
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &previous_challenge.to_bytes());
     for i in 0..(log_n - 1) {
-        push_point(env, &mut data, &proof.gemini_fold_comms[i]);
+        push_point(&mut data, &proof.gemini_fold_comms[i]);
     }
-    let next_previous_challenge = hash_to_fr(&data);
+    let next_previous_challenge = hash_to_fr(data);
     let gemini_r = split_challenge(next_previous_challenge).0;
     (gemini_r, next_previous_challenge)
 }
@@ -247,12 +263,12 @@ fn generate_gemini_r_challenge(
 fn generate_shplonk_nu_challenge(
     env: &Env, proof: &Proof, previous_challenge: Fr, log_n: usize,
 ) -> (Fr, Fr) {
-    let mut data = Bytes::new(env);
-    push_32(env, &mut data, &previous_challenge.to_bytes());
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &previous_challenge.to_bytes());
     for i in 0..log_n {
-        push_32(env, &mut data, &proof.gemini_a_evaluations[i].to_bytes());
+        push_32(&mut data, &proof.gemini_a_evaluations[i].to_bytes());
     }
-    let next_previous_challenge = hash_to_fr(&data);
+    let next_previous_challenge = hash_to_fr(data);
     let shplonk_nu = split_challenge(next_previous_challenge).0;
     (shplonk_nu, next_previous_challenge)
 }
@@ -260,19 +276,24 @@ fn generate_shplonk_nu_challenge(
 /// Generate shplonk_z challenge.
 /// Matches Solidity: hash(prev, shplonkQ as raw x,y)
 fn generate_shplonk_z_challenge(env: &Env, proof: &Proof, previous_challenge: Fr) -> (Fr, Fr) {
-    let mut data = Bytes::new(env);
-    push_32(env, &mut data, &previous_challenge.to_bytes());
-    push_point(env, &mut data, &proof.shplonk_q);
-    let next_previous_challenge = hash_to_fr(&data);
+    let mut data = KeccakStream::new(env);
+    push_32(&mut data, &previous_challenge.to_bytes());
+    push_point(&mut data, &proof.shplonk_q);
+    let next_previous_challenge = hash_to_fr(data);
     let shplonk_z = split_challenge(next_previous_challenge).0;
     (shplonk_z, next_previous_challenge)
 }
 
+/// Full Fiat–Shamir transcript. `circuit_domain` separates circuits that share
+/// a VK hash (e.g. one verifier reused for ping and turn proofs): transcripts
+/// in different domains diverge from eta onwards. Domain 0 is the bb reference.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_transcript(
     env: &Env,
     proof: &Proof,
     public_inputs: &Bytes,
     vk_hash: &[u8; 32],
+    circuit_domain: u32,
     circuit_size: u64,
     public_inputs_size: u64,
     _pub_inputs_offset: u64,
@@ -289,7 +310,14 @@ pub fn generate_transcript(
 
     // 1) eta/beta/gamma
     let (rp, previous_challenge) =
-        generate_relation_parameters_challenges(env, proof, public_inputs, vk_hash, public_inputs_size);
+        generate_relation_parameters_challenges(
+            env,
+            proof,
+            public_inputs,
+            vk_hash,
+            circuit_domain,
+            public_inputs_size,
+        );
 
     // 2) alphas (powers of single alpha)
     let (alphas, previous_challenge) = generate_alpha_challenges(env, previous_challenge, proof);
@@ -334,10 +362,17 @@ fn generate_relation_parameters_challenges(
     proof: &Proof,
     public_inputs: &Bytes,
     vk_hash: &[u8; 32],
+    circuit_domain: u32,
     public_inputs_size: u64,
 ) -> (RelationParameters, Fr) {
-    let (eta, eta_two, eta_three, previous_challenge) =
-        generate_eta_challenge(env, proof, public_inputs, vk_hash, public_inputs_size);
+    let (eta, eta_two, eta_three, previous_challenge) = generate_eta_challenge(
+        env,
+        proof,
+        public_inputs,
+        vk_hash,
+        circuit_domain,
+        public_inputs_size,
+    );
     let (beta, gamma, next_previous_challenge) =
         generate_beta_and_gamma_challenges(env, previous_challenge, proof);
     let rp = RelationParameters {
@@ -350,3 +385,34 @@ fn generate_relation_parameters_challenges(
     };
     (rp, next_previous_challenge)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{load_proof, proof_bytes_for_log_n};
+
+    #[test]
+    fn circuit_domain_separates_transcripts() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let log_n = 3;
+        let zeros = [0u8; 32 * 512];
+        let proof_bytes = Bytes::from_slice(&env, &zeros[..proof_bytes_for_log_n(log_n as u64)]);
+        let proof = load_proof(&proof_bytes, log_n).unwrap();
+        let public_inputs = Bytes::from_slice(&env, &[7u8; 64]);
+        let vk_hash = [9u8; 32];
+        let pis_total = 2 + PAIRING_POINTS_SIZE as u64;
+
+        let transcript = |domain: u32| {
+            generate_transcript(&env, &proof, &public_inputs, &vk_hash, domain, 1 << log_n, pis_total, 1)
+        };
+        let ping = transcript(0);
+        let turn = transcript(1);
+        let mv = transcript(2);
+
+        assert_ne!(ping.rel_params.eta, turn.rel_params.eta);
+        assert_ne!(ping.alphas[0], turn.alphas[0]);
+        assert_ne!(turn.rel_params.eta, mv.rel_params.eta);
+        assert_eq!(ping.rel_params.eta, transcript(0).rel_params.eta);
+    }
+}
@@ -149,6 +149,47 @@ pub struct VerificationKey {
     // Fixed first/last
     pub lagrange_first: G1Point,
     pub lagrange_last: G1Point,
+    /// Relation families the circuit uses (`relations::RELATION_*` bits).
+    pub active_relations: u32,
+}
+
+/// Number of G1 commitments in a [`VerificationKey`].
+pub const NUMBER_OF_VK_COMMITMENTS: usize = 28;
+
+impl VerificationKey {
+    /// VK commitments in the order Shplemini feeds them to the MSM.
+    pub fn commitments(&self) -> [G1Point; NUMBER_OF_VK_COMMITMENTS] {
+        [
+            self.qm,
+            self.qc,
+            self.ql,
+            self.qr,
+            self.qo,
+            self.q4,
+            self.q_lookup,
+            self.q_arith,
+            self.q_delta_range,
+            self.q_elliptic,
+            self.q_memory,
+            self.q_nnf,
+            self.q_poseidon2_external,
+            self.q_poseidon2_internal,
+            self.s1,
+            self.s2,
+            self.s3,
+            self.s4,
+            self.id1,
+            self.id2,
+            self.id3,
+            self.id4,
+            self.t1,
+            self.t2,
+            self.t3,
+            self.t4,
+            self.lagrange_first,
+            self.lagrange_last,
+        ]
+    }
 }
 
 /// The Proof structure
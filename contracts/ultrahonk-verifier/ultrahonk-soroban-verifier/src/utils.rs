@@ -1,18 +1,14 @@
 //! Utilities for loading Proof and VerificationKey, plus byte↔field/point conversion.
 
+use crate::ec;
 use crate::field::Fr;
 use crate::types::{
     G1Point, Proof, VerificationKey, BATCHED_RELATION_PARTIAL_LENGTH, CONST_PROOF_SIZE_LOG_N,
     NUMBER_OF_ENTITIES, PAIRING_POINTS_SIZE,
 };
-use core::array;
+use crate::verifier::VerifyError;
 use soroban_sdk::Bytes;
 
-/// Convert a 32-byte big-endian array into an Fr.
-fn bytes32_to_fr(bytes: &[u8; 32]) -> Fr {
-    Fr::from_bytes(bytes)
-}
-
 /// Split a 32-byte big-endian field element into (low136, high) limbs.
 /// Used by the transcript to serialize G1 points in the Fiat–Shamir hash.
 pub fn coord_to_halves_be(coord: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
@@ -31,6 +27,14 @@ fn read_bytes<const N: usize>(bytes: &Bytes, idx: &mut u32) -> [u8; N] {
     out
 }
 
+/// Ensure at least `fields` 32-byte fields remain after `idx`.
+fn ensure_fields(bytes: &Bytes, idx: u32, fields: usize) -> Result<(), VerifyError> {
+    if (bytes.len() as usize) < idx as usize + fields * 32 {
+        return Err(VerifyError::InvalidInput("proof truncated"));
+    }
+    Ok(())
+}
+
 /// Compute expected proof byte size for a given log_n.
 ///
 /// bb v3.0.0 with keccak oracle hash:
@@ -46,78 +50,105 @@ pub fn proof_bytes_for_log_n(log_n: u64) -> usize {
     (75 + 11 * log_n as usize) * 32
 }
 
+/// Whether `provided` public inputs fit a VK declaring `vk_inputs` of them:
+/// either exactly (bb 3.0.0 root circuits) or without the pairing point
+/// object when the VK count includes it (recursive wrapper).
+pub fn public_inputs_match_vk(vk_inputs: u64, provided: u64) -> bool {
+    provided == vk_inputs || vk_inputs.checked_sub(PAIRING_POINTS_SIZE as u64) == Some(provided)
+}
+
 /// Load a Proof from a byte array.
 ///
 /// bb v3.0.0 with keccak oracle hash: G1 coordinates in the proof are
 /// native (x, y) — 2 fields per point. The proof size depends on
 /// the circuit's log_n. Arrays are zero-padded to CONST_PROOF_SIZE_LOG_N.
-pub fn load_proof(proof_bytes: &Bytes, log_n: usize) -> Proof {
-    assert!(log_n <= CONST_PROOF_SIZE_LOG_N, "log_n too large");
-    let expected = proof_bytes_for_log_n(log_n as u64);
-    assert_eq!(proof_bytes.len() as usize, expected, "proof bytes len");
+///
+/// Each section is bounds-checked before it is read, so a short buffer yields
+/// `VerifyError::InvalidInput` instead of a slice panic.
+pub fn load_proof(proof_bytes: &Bytes, log_n: usize) -> Result<Proof, VerifyError> {
+    if log_n == 0 || log_n > CONST_PROOF_SIZE_LOG_N {
+        return Err(VerifyError::InvalidInput("log_n out of range"));
+    }
     let mut boundary = 0u32;
 
-    fn bytes_to_g1_native(bytes: &Bytes, cur: &mut u32) -> G1Point {
+    fn bytes_to_g1_native(bytes: &Bytes, cur: &mut u32) -> Result<G1Point, VerifyError> {
         let x = read_bytes::<32>(bytes, cur);
         let y = read_bytes::<32>(bytes, cur);
-        G1Point { x, y }
+        let p = G1Point { x, y };
+        if !ec::g1_is_on_curve(&p) || !ec::g1_in_subgroup(&p) {
+            return Err(VerifyError::InvalidInput("G1 point not on curve"));
+        }
+        Ok(p)
     }
 
-    fn bytes_to_fr(bytes: &Bytes, cur: &mut u32) -> Fr {
+    fn bytes_to_fr(bytes: &Bytes, cur: &mut u32) -> Result<Fr, VerifyError> {
         let arr = read_bytes::<32>(bytes, cur);
-        bytes32_to_fr(&arr)
+        Fr::from_bytes_checked(&arr).ok_or(VerifyError::InvalidInput("field element out of range"))
     }
 
     // 0) pairing point object
-    let pairing_point_object: [Fr; PAIRING_POINTS_SIZE] =
-        array::from_fn(|_| bytes_to_fr(proof_bytes, &mut boundary));
+    ensure_fields(proof_bytes, boundary, PAIRING_POINTS_SIZE)?;
+    let mut pairing_point_object = [Fr::zero(); PAIRING_POINTS_SIZE];
+    for v in pairing_point_object.iter_mut() {
+        *v = bytes_to_fr(proof_bytes, &mut boundary)?;
+    }
+
+    // 1..4) 8 G1 commitments, 2 fields each
+    ensure_fields(proof_bytes, boundary, 8 * 2)?;
 
     // 1) w1, w2, w3
-    let w1 = bytes_to_g1_native(proof_bytes, &mut boundary);
-    let w2 = bytes_to_g1_native(proof_bytes, &mut boundary);
-    let w3 = bytes_to_g1_native(proof_bytes, &mut boundary);
+    let w1 = bytes_to_g1_native(proof_bytes, &mut boundary)?;
+    let w2 = bytes_to_g1_native(proof_bytes, &mut boundary)?;
+    let w3 = bytes_to_g1_native(proof_bytes, &mut boundary)?;
 
     // 2) lookup_read_counts, lookup_read_tags
-    let lookup_read_counts = bytes_to_g1_native(proof_bytes, &mut boundary);
-    let lookup_read_tags = bytes_to_g1_native(proof_bytes, &mut boundary);
+    let lookup_read_counts = bytes_to_g1_native(proof_bytes, &mut boundary)?;
+    let lookup_read_tags = bytes_to_g1_native(proof_bytes, &mut boundary)?;
 
     // 3) w4
-    let w4 = bytes_to_g1_native(proof_bytes, &mut boundary);
+    let w4 = bytes_to_g1_native(proof_bytes, &mut boundary)?;
 
     // 4) lookup_inverses, z_perm
-    let lookup_inverses = bytes_to_g1_native(proof_bytes, &mut boundary);
-    let z_perm = bytes_to_g1_native(proof_bytes, &mut boundary);
+    let lookup_inverses = bytes_to_g1_native(proof_bytes, &mut boundary)?;
+    let z_perm = bytes_to_g1_native(proof_bytes, &mut boundary)?;
 
     // 5) sumcheck_univariates — only log_n rounds present, rest zero
+    ensure_fields(proof_bytes, boundary, log_n * BATCHED_RELATION_PARTIAL_LENGTH)?;
     let mut sumcheck_univariates =
         [[Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH]; CONST_PROOF_SIZE_LOG_N];
     for r in 0..log_n {
         for i in 0..BATCHED_RELATION_PARTIAL_LENGTH {
-            sumcheck_univariates[r][i] = bytes_to_fr(proof_bytes, &mut boundary);
+            sumcheck_univariates[r][i] = bytes_to_fr(proof_bytes, &mut boundary)?;
         }
     }
 
     // 6) sumcheck_evaluations
-    let sumcheck_evaluations: [Fr; NUMBER_OF_ENTITIES] =
-        array::from_fn(|_| bytes_to_fr(proof_bytes, &mut boundary));
+    ensure_fields(proof_bytes, boundary, NUMBER_OF_ENTITIES)?;
+    let mut sumcheck_evaluations = [Fr::zero(); NUMBER_OF_ENTITIES];
+    for v in sumcheck_evaluations.iter_mut() {
+        *v = bytes_to_fr(proof_bytes, &mut boundary)?;
+    }
 
     // 7) gemini_fold_comms — only (log_n - 1) present, rest infinity
+    ensure_fields(proof_bytes, boundary, (log_n - 1) * 2)?;
     let mut gemini_fold_comms = [G1Point::infinity(); CONST_PROOF_SIZE_LOG_N - 1];
     for i in 0..(log_n - 1) {
-        gemini_fold_comms[i] = bytes_to_g1_native(proof_bytes, &mut boundary);
+        gemini_fold_comms[i] = bytes_to_g1_native(proof_bytes, &mut boundary)?;
     }
 
     // 8) gemini_a_evaluations — only log_n present, rest zero
+    ensure_fields(proof_bytes, boundary, log_n)?;
     let mut gemini_a_evaluations = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
     for i in 0..log_n {
-        gemini_a_evaluations[i] = bytes_to_fr(proof_bytes, &mut boundary);
+        gemini_a_evaluations[i] = bytes_to_fr(proof_bytes, &mut boundary)?;
     }
 
     // 9) shplonk_q, kzg_quotient
-    let shplonk_q = bytes_to_g1_native(proof_bytes, &mut boundary);
-    let kzg_quotient = bytes_to_g1_native(proof_bytes, &mut boundary);
+    ensure_fields(proof_bytes, boundary, 2 * 2)?;
+    let shplonk_q = bytes_to_g1_native(proof_bytes, &mut boundary)?;
+    let kzg_quotient = bytes_to_g1_native(proof_bytes, &mut boundary)?;
 
-    Proof {
+    Ok(Proof {
         pairing_point_object,
         w1,
         w2,
@@ -133,7 +164,7 @@ pub fn load_proof(proof_bytes: &Bytes, log_n: usize) -> Proof {
         gemini_a_evaluations,
         shplonk_q,
         kzg_quotient,
-    }
+    })
 }
 
 /// Load a VerificationKey from bb v3.0.0 binary format.
@@ -142,66 +173,88 @@ pub fn load_proof(proof_bytes: &Bytes, log_n: usize) -> Proof {
 ///   Header:  3 × 32 bytes = 96 bytes (log_circuit_size, num_public_inputs, pub_inputs_offset)
 ///   Points: 28 × 64 bytes = 1792 bytes (native G1 coordinates)
 ///   Total: 1888 bytes (keccak oracle) or 3680 bytes (poseidon2 oracle, with zero padding)
+///
+/// Header fields must be canonical and every point must be on the curve.
 pub fn load_vk_from_bytes(bytes: &Bytes) -> Option<VerificationKey> {
+    parse_vk(bytes, true)
+}
+
+/// Same layout as [`load_vk_from_bytes`] but skips the header/curve checks.
+/// Only for VK bytes that already passed `load_vk_from_bytes` (e.g. validated
+/// once at contract construction).
+pub fn load_vk_from_bytes_trusted(bytes: &Bytes) -> Option<VerificationKey> {
+    parse_vk(bytes, false)
+}
+
+fn parse_vk(bytes: &Bytes, validate: bool) -> Option<VerificationKey> {
     const MIN_LEN: usize = 96 + 28 * 64; // 1888 bytes
     let len = bytes.len() as usize;
     if len != MIN_LEN && len != MIN_LEN + 28 * 64 {
         return None;
     }
 
-    fn read_u64_from_field(bytes: &Bytes, idx: &mut u32) -> u64 {
-        // Read 32 byte field, take last 8 bytes (Big Endian)
-        let _padding = read_bytes::<24>(bytes, idx);
-        u64::from_be_bytes(read_bytes::<8>(bytes, idx))
+    fn read_u64_from_field(bytes: &Bytes, idx: &mut u32, validate: bool) -> Option<u64> {
+        // Read 32 byte field; it must be canonical and fit in the last 8 bytes (Big Endian)
+        let raw = read_bytes::<32>(bytes, idx);
+        if validate {
+            Fr::from_bytes_checked(&raw)?;
+            if raw[..24].iter().any(|b| *b != 0) {
+                return None;
+            }
+        }
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&raw[24..]);
+        Some(u64::from_be_bytes(low))
     }
 
-    fn read_point(bytes: &Bytes, idx: &mut u32) -> Option<G1Point> {
+    fn read_point(bytes: &Bytes, idx: &mut u32, validate: bool) -> Option<G1Point> {
         let x = read_bytes::<32>(bytes, idx);
         let y = read_bytes::<32>(bytes, idx);
-        Some(G1Point { x, y })
+        let p = G1Point { x, y };
+        (!validate || (ec::g1_is_on_curve(&p) && ec::g1_in_subgroup(&p))).then_some(p)
     }
 
     let mut idx = 0u32;
     // bb v3.0.0: 3 header fields
-    let log_circuit_size = read_u64_from_field(bytes, &mut idx);
-    let public_inputs_size = read_u64_from_field(bytes, &mut idx);
-    let pub_inputs_offset = read_u64_from_field(bytes, &mut idx);
+    let log_circuit_size = read_u64_from_field(bytes, &mut idx, validate)?;
+    let public_inputs_size = read_u64_from_field(bytes, &mut idx, validate)?;
+    let pub_inputs_offset = read_u64_from_field(bytes, &mut idx, validate)?;
 
     let circuit_size = 1u64 << log_circuit_size;
 
     // 28 G1 points in order: qm, qc, ql, qr, qo, q4, qLookup, qArith,
     // qDeltaRange, qElliptic, qMemory, qNnf, qPoseidon2External, qPoseidon2Internal,
     // s1-s4, id1-id4, t1-t4, lagrangeFirst, lagrangeLast
-    let qm = read_point(bytes, &mut idx)?;
-    let qc = read_point(bytes, &mut idx)?;
-    let ql = read_point(bytes, &mut idx)?;
-    let qr = read_point(bytes, &mut idx)?;
-    let qo = read_point(bytes, &mut idx)?;
-    let q4 = read_point(bytes, &mut idx)?;
-    let q_lookup = read_point(bytes, &mut idx)?;
-    let q_arith = read_point(bytes, &mut idx)?;
-    let q_delta_range = read_point(bytes, &mut idx)?;
-    let q_elliptic = read_point(bytes, &mut idx)?;
-    let q_memory = read_point(bytes, &mut idx)?;
-    let q_nnf = read_point(bytes, &mut idx)?;
-    let q_poseidon2_external = read_point(bytes, &mut idx)?;
-    let q_poseidon2_internal = read_point(bytes, &mut idx)?;
-    let s1 = read_point(bytes, &mut idx)?;
-    let s2 = read_point(bytes, &mut idx)?;
-    let s3 = read_point(bytes, &mut idx)?;
-    let s4 = read_point(bytes, &mut idx)?;
-    let id1 = read_point(bytes, &mut idx)?;
-    let id2 = read_point(bytes, &mut idx)?;
-    let id3 = read_point(bytes, &mut idx)?;
-    let id4 = read_point(bytes, &mut idx)?;
-    let t1 = read_point(bytes, &mut idx)?;
-    let t2 = read_point(bytes, &mut idx)?;
-    let t3 = read_point(bytes, &mut idx)?;
-    let t4 = read_point(bytes, &mut idx)?;
-    let lagrange_first = read_point(bytes, &mut idx)?;
-    let lagrange_last = read_point(bytes, &mut idx)?;
-
-    Some(VerificationKey {
+    let qm = read_point(bytes, &mut idx, validate)?;
+    let qc = read_point(bytes, &mut idx, validate)?;
+    let ql = read_point(bytes, &mut idx, validate)?;
+    let qr = read_point(bytes, &mut idx, validate)?;
+    let qo = read_point(bytes, &mut idx, validate)?;
+    let q4 = read_point(bytes, &mut idx, validate)?;
+    let q_lookup = read_point(bytes, &mut idx, validate)?;
+    let q_arith = read_point(bytes, &mut idx, validate)?;
+    let q_delta_range = read_point(bytes, &mut idx, validate)?;
+    let q_elliptic = read_point(bytes, &mut idx, validate)?;
+    let q_memory = read_point(bytes, &mut idx, validate)?;
+    let q_nnf = read_point(bytes, &mut idx, validate)?;
+    let q_poseidon2_external = read_point(bytes, &mut idx, validate)?;
+    let q_poseidon2_internal = read_point(bytes, &mut idx, validate)?;
+    let s1 = read_point(bytes, &mut idx, validate)?;
+    let s2 = read_point(bytes, &mut idx, validate)?;
+    let s3 = read_point(bytes, &mut idx, validate)?;
+    let s4 = read_point(bytes, &mut idx, validate)?;
+    let id1 = read_point(bytes, &mut idx, validate)?;
+    let id2 = read_point(bytes, &mut idx, validate)?;
+    let id3 = read_point(bytes, &mut idx, validate)?;
+    let id4 = read_point(bytes, &mut idx, validate)?;
+    let t1 = read_point(bytes, &mut idx, validate)?;
+    let t2 = read_point(bytes, &mut idx, validate)?;
+    let t3 = read_point(bytes, &mut idx, validate)?;
+    let t4 = read_point(bytes, &mut idx, validate)?;
+    let lagrange_first = read_point(bytes, &mut idx, validate)?;
+    let lagrange_last = read_point(bytes, &mut idx, validate)?;
+
+    let mut vk = VerificationKey {
         circuit_size,
         log_circuit_size,
         public_inputs_size,
@@ -234,5 +287,75 @@ pub fn load_vk_from_bytes(bytes: &Bytes) -> Option<VerificationKey> {
         t4,
         lagrange_first,
         lagrange_last,
-    })
+        active_relations: crate::relations::ALL_RELATIONS,
+    };
+    vk.active_relations = crate::relations::active_relations(&vk);
+    Some(vk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn load_proof_accepts_exact_length() {
+        let env = Env::default();
+        let log_n = 3;
+        let bytes = Bytes::from_slice(&env, &[0u8; 108 * 32]);
+        assert_eq!(proof_bytes_for_log_n(log_n as u64), 108 * 32);
+        assert!(load_proof(&bytes, log_n).is_ok());
+    }
+
+    #[test]
+    fn load_proof_rejects_truncated_evaluations_cleanly() {
+        let env = Env::default();
+        let log_n = 3;
+        // Cut the buffer in the middle of sumcheck_evaluations:
+        // 16 pairing + 16 commitment + 3*8 univariate fields, then 10 of 41 evaluations.
+        let fields = 16 + 16 + log_n * BATCHED_RELATION_PARTIAL_LENGTH + 10;
+        let bytes = Bytes::from_slice(&env, &[0u8; 66 * 32][..fields * 32]);
+        assert!(matches!(
+            load_proof(&bytes, log_n),
+            Err(VerifyError::InvalidInput("proof truncated"))
+        ));
+    }
+
+    #[test]
+    fn load_proof_rejects_off_curve_commitment() {
+        let env = Env::default();
+        let log_n = 3;
+        let mut raw = [0u8; 108 * 32];
+        // w1 = (1, 3): x is the generator's, y is not.
+        raw[16 * 32 + 31] = 1;
+        raw[17 * 32 + 31] = 3;
+        let bytes = Bytes::from_slice(&env, &raw);
+        assert!(matches!(
+            load_proof(&bytes, log_n),
+            Err(VerifyError::InvalidInput("G1 point not on curve"))
+        ));
+    }
+
+    #[test]
+    fn load_proof_rejects_non_canonical_scalar() {
+        let env = Env::default();
+        let mut raw = [0u8; 108 * 32];
+        raw[..32].copy_from_slice(&[0xffu8; 32]);
+        let bytes = Bytes::from_slice(&env, &raw);
+        assert!(matches!(
+            load_proof(&bytes, 3),
+            Err(VerifyError::InvalidInput("field element out of range"))
+        ));
+    }
+
+    #[test]
+    fn load_proof_rejects_out_of_range_log_n() {
+        let env = Env::default();
+        let bytes = Bytes::new(&env);
+        assert!(matches!(load_proof(&bytes, 0), Err(VerifyError::InvalidInput(_))));
+        assert!(matches!(
+            load_proof(&bytes, CONST_PROOF_SIZE_LOG_N + 1),
+            Err(VerifyError::InvalidInput(_))
+        ));
+    }
 }
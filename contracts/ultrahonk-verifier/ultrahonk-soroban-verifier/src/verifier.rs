@@ -3,19 +3,24 @@
 use crate::{
     field::Fr,
     shplemini::verify_shplemini,
-    sumcheck::verify_sumcheck,
+    sumcheck::{verify_sumcheck_with_rounds, ROUND_FAILED},
     transcript::generate_transcript,
     types::PAIRING_POINTS_SIZE,
-    utils::{load_proof, load_vk_from_bytes, proof_bytes_for_log_n},
+    utils::{
+        load_proof, load_vk_from_bytes, load_vk_from_bytes_trusted, proof_bytes_for_log_n,
+        public_inputs_match_vk,
+    },
 };
 use crate::trace;
-use soroban_sdk::{Bytes, Env, symbol_short};
+use soroban_sdk::{Bytes, BytesN, Env, Symbol, symbol_short};
 
 /// Error type describing the specific reason verification failed.
 #[derive(Debug)]
 pub enum VerifyError {
     InvalidInput(&'static str),
     SumcheckFailed(&'static str),
+    /// A sum-check round's univariate did not sum to the target; 0-based round.
+    SumcheckRoundFailed(u32),
     ShplonkFailed(&'static str),
 }
 
@@ -57,27 +62,116 @@ impl UltraHonkVerifier {
         })
     }
 
+    /// Like [`Self::new`] but skips VK validation. Use only for VK bytes that
+    /// were validated earlier (e.g. once at contract construction).
+    pub fn new_trusted(env: &Env, vk_bytes: &Bytes) -> Result<Self, VerifyError> {
+        if vk_bytes.len() < 32 {
+            return Err(VerifyError::InvalidInput("vk too short for hash prefix"));
+        }
+        let mut vk_hash = [0u8; 32];
+        vk_bytes.slice(0..32).copy_into_slice(&mut vk_hash);
+
+        let vk_data = vk_bytes.slice(32..vk_bytes.len());
+        let vk = load_vk_from_bytes_trusted(&vk_data)
+            .ok_or(VerifyError::InvalidInput("vk parse error"))?;
+
+        Ok(Self {
+            env: env.clone(),
+            vk,
+            vk_hash,
+        })
+    }
+
     /// Expose a reference to the parsed VK for debugging/inspection.
     pub fn get_vk(&self) -> &crate::types::VerificationKey {
         &self.vk
     }
 
     /// Top-level verify
+    ///
+    /// With the `metrics` feature, a single `("metric", "verify")` event is emitted
+    /// at the end carrying `(log_n, passed, sumcheck_rounds)`.
+    ///
+    /// When verification fails after the transcript was built, an
+    /// `("err", "chal")` event carries `(eta, beta, gamma, alpha_0)` as 32-byte
+    /// big-endian values, for comparison with the prover's transcript.
     pub fn verify(
         &self,
         proof_bytes: &Bytes,
         public_inputs_bytes: &Bytes,
+    ) -> Result<(), VerifyError> {
+        self.verify_in_domain(proof_bytes, public_inputs_bytes, 0)
+    }
+
+    /// `verify` with `circuit_domain` absorbed into the transcript (see
+    /// `generate_transcript`), so a proof only verifies in the domain it was
+    /// produced for. Domain 0 is plain `verify`.
+    pub fn verify_in_domain(
+        &self,
+        proof_bytes: &Bytes,
+        public_inputs_bytes: &Bytes,
+        circuit_domain: u32,
+    ) -> Result<(), VerifyError> {
+        let mut sumcheck_rounds = 0u32;
+        let mut challenges = None;
+        let res = self.verify_inner(
+            proof_bytes,
+            public_inputs_bytes,
+            circuit_domain,
+            &mut sumcheck_rounds,
+            &mut challenges,
+            true,
+        );
+
+        if let (Err(_), Some(chal)) = (&res, challenges) {
+            let b = |x: Fr| BytesN::from_array(&self.env, &x.to_bytes());
+            self.env.events().publish(
+                (symbol_short!("err"), symbol_short!("chal")),
+                (b(chal[0]), b(chal[1]), b(chal[2]), b(chal[3])),
+            );
+        }
+
+        #[cfg(feature = "metrics")]
+        self.env.events().publish(
+            (symbol_short!("metric"), symbol_short!("verify")),
+            (self.vk.log_circuit_size as u32, res.is_ok(), sumcheck_rounds),
+        );
+
+        res
+    }
+
+    /// Run every stage up to and including sum-check, skipping Shplemini.
+    /// For diagnostics: tells a sum-check failure apart from a PCS failure.
+    pub fn verify_sumcheck_only(
+        &self,
+        proof_bytes: &Bytes,
+        public_inputs_bytes: &Bytes,
+    ) -> Result<(), VerifyError> {
+        self.verify_inner(proof_bytes, public_inputs_bytes, 0, &mut 0, &mut None, false)
+    }
+
+    fn verify_inner(
+        &self,
+        proof_bytes: &Bytes,
+        public_inputs_bytes: &Bytes,
+        circuit_domain: u32,
+        sumcheck_rounds: &mut u32,
+        challenges: &mut Option<[Fr; 4]>,
+        run_shplemini: bool,
     ) -> Result<(), VerifyError> {
         let log_n = self.vk.log_circuit_size as usize;
 
-        // 1) parse proof (size depends on log_n)
-        let expected_proof_bytes = proof_bytes_for_log_n(self.vk.log_circuit_size);
-        if proof_bytes.len() as usize != expected_proof_bytes {
-            return Err(VerifyError::InvalidInput("proof size mismatch"));
+        // 0) reject an empty proof up front with a precise reason. Empty public
+        // inputs are legal (a circuit whose only public inputs are the pairing
+        // points); their count is checked against the VK below.
+        if proof_bytes.is_empty() {
+            return Err(VerifyError::InvalidInput("empty proof"));
         }
-        let proof = load_proof(proof_bytes, log_n);
 
-        // 2) sanity on public inputs (length and VK metadata if present)
+        // 1) sanity on public inputs (length, VK metadata, canonical encoding).
+        // Checked before the proof so a wrong public-input shape is reported as
+        // such. A wrong *value* of the right shape is only caught by the
+        // transcript and surfaces as a failed sum-check round.
         if public_inputs_bytes.len() % 32 != 0 {
             return Err(VerifyError::InvalidInput(
                 "public inputs must be 32-byte aligned",
@@ -91,26 +185,27 @@ impl UltraHonkVerifier {
             (provided, vk_inputs),
         );
 
-        // Allowed interpretations of VK public inputs count:
-        // 1. VK includes pairing fields (recursive wrapper expectation): expected = vk_inputs - 16
-        // 2. VK excludes pairing fields (raw circuit output): expected = vk_inputs
-        let expected_recursive = vk_inputs.checked_sub(PAIRING_POINTS_SIZE as u64);
-
-        let valid = if vk_inputs == provided {
-            true // VK count matches user inputs exactly (bb 3.0.0 behavior for root circuits)
-        } else if let Some(exp) = expected_recursive {
-            exp == provided // VK count includes recursion fields
-        } else {
-            false
-        };
-
-        if !valid {
+        if !public_inputs_match_vk(vk_inputs, provided) {
             self.env.events().publish(
                 (symbol_short!("err"), symbol_short!("pi_len")),
                 (provided, vk_inputs),
             );
             return Err(VerifyError::InvalidInput("public inputs mismatch (vk vs provided)"));
         }
+        for i in 0..provided as u32 {
+            let mut pi = [0u8; 32];
+            public_inputs_bytes.slice(i * 32..(i + 1) * 32).copy_into_slice(&mut pi);
+            if Fr::from_bytes_checked(&pi).is_none() {
+                return Err(VerifyError::InvalidInput("public input is not a canonical field element"));
+            }
+        }
+
+        // 2) parse proof (size depends on log_n)
+        let expected_proof_bytes = proof_bytes_for_log_n(self.vk.log_circuit_size);
+        if proof_bytes.len() as usize != expected_proof_bytes {
+            return Err(VerifyError::InvalidInput("proof size mismatch"));
+        }
+        let proof = load_proof(proof_bytes, log_n)?;
 
         // 3) Fiat–Shamir transcript
         // Use pub_inputs_offset from VK, and total public inputs = provided + pairing size
@@ -125,10 +220,12 @@ impl UltraHonkVerifier {
             &proof,
             public_inputs_bytes,
             &self.vk_hash,
+            circuit_domain,
             self.vk.circuit_size,
             pis_total,
             pub_inputs_offset,
         );
+        *challenges = Some([t.rel_params.eta, t.rel_params.beta, t.rel_params.gamma, t.alphas[0]]);
 
         trace!("[verifier] public_inputs_delta computing...");
         // 4) Public delta
@@ -148,13 +245,26 @@ impl UltraHonkVerifier {
         );
 
         // 5) Sum-check
-        if let Err(e) = verify_sumcheck(&proof, &t, &self.vk) {
+        if let Err(e) = verify_sumcheck_with_rounds(&proof, &t, &self.vk, sumcheck_rounds) {
+             if e == ROUND_FAILED {
+                 let round = sumcheck_rounds.saturating_sub(1);
+                 self.env.events().publish(
+                     (symbol_short!("err"), Symbol::new(&self.env, "sumcheck_round")),
+                     round,
+                 );
+                 return Err(VerifyError::SumcheckRoundFailed(round));
+             }
              self.env.events().publish((symbol_short!("err"), symbol_short!("sumcheck")), ());
              return Err(VerifyError::SumcheckFailed(e));
         }
+        if !run_shplemini {
+            return Ok(());
+        }
 
         // 6) Shplonk
-        if let Err(e) = verify_shplemini(&self.env, &proof, &self.vk, &t) {
+        if let Err(e) =
+            verify_shplemini(&self.env, &proof, &self.vk, &t)
+        {
              self.env.events().publish((symbol_short!("err"), symbol_short!("shplonk")), ());
              return Err(VerifyError::ShplonkFailed(e));
         }
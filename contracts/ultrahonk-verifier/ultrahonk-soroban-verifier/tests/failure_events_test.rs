@@ -0,0 +1,87 @@
+//! Transcript challenges are published only when verification fails.
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Events, Ledger},
+    xdr, Bytes, BytesN, Env, Symbol, TryFromVal, Val,
+};
+use std::{fs, path::Path};
+use ultrahonk_soroban_verifier::UltraHonkVerifier;
+
+/// Events are only recorded inside a contract frame.
+#[contract]
+pub struct Harness;
+
+#[contractimpl]
+impl Harness {
+    pub fn noop(_env: Env) {}
+}
+
+/// Verifies the fixture (optionally with the first public input flipped) and
+/// returns the data of every `("err", "chal")` event.
+fn challenge_events(env: &Env, tamper: bool) -> Result<std::vec::Vec<Val>, String> {
+    let path = Path::new("circuits/simple_circuit/target");
+    let proof_bytes = fs::read(path.join("proof")).map_err(|e| e.to_string())?;
+    let vk_bytes = fs::read(path.join("vk_with_hash")).map_err(|e| e.to_string())?;
+    let mut pi_bytes = fs::read(path.join("public_inputs")).map_err(|e| e.to_string())?;
+    if tamper {
+        pi_bytes[31] ^= 1;
+    }
+
+    let harness = env.register(Harness, ());
+    let passed = env.as_contract(&harness, || {
+        let verifier = UltraHonkVerifier::new(env, &Bytes::from_slice(env, &vk_bytes))
+            .map_err(|e| format!("{e:?}"))?;
+        let res = verifier.verify(
+            &Bytes::from_slice(env, &proof_bytes),
+            &Bytes::from_slice(env, &pi_bytes),
+        );
+        Ok::<_, String>(res.is_ok())
+    })?;
+    assert_eq!(passed, !tamper);
+
+    let sym = |v: &xdr::ScVal| -> Option<Symbol> {
+        let v = Val::try_from_val(env, v).ok()?;
+        Symbol::try_from_val(env, &v).ok()
+    };
+    let all = env.events().all();
+    Ok(all
+        .events()
+        .iter()
+        .filter_map(|e| {
+            let xdr::ContractEventBody::V0(body) = &e.body;
+            let is_chal = body.topics.len() == 2
+                && sym(&body.topics[0]) == Some(symbol_short!("err"))
+                && sym(&body.topics[1]) == Some(symbol_short!("chal"));
+            is_chal.then(|| Val::try_from_val(env, &body.data).unwrap())
+        })
+        .collect())
+}
+
+#[test]
+fn failing_proof_emits_challenges() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    env.cost_estimate().budget().reset_unlimited();
+
+    let events = challenge_events(&env, true)?;
+    assert_eq!(events.len(), 1);
+    let (eta, beta, gamma, alpha) =
+        <(BytesN<32>, BytesN<32>, BytesN<32>, BytesN<32>)>::try_from_val(&env, &events[0])
+            .map_err(|e| format!("{e:?}"))?;
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    for c in [eta, beta, gamma, alpha] {
+        assert_ne!(c, zero);
+    }
+    Ok(())
+}
+
+#[test]
+fn passing_proof_emits_no_challenges() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    env.cost_estimate().budget().reset_unlimited();
+
+    assert!(challenge_events(&env, false)?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Metric event emitted by `verify` when built with the `metrics` feature.
+//! Run with `cargo test --features metrics --test metrics_test`.
+#![cfg(feature = "metrics")]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Events, Ledger},
+    xdr, Bytes, Env, Symbol, TryFromVal, Val,
+};
+use std::{fs, path::Path};
+use ultrahonk_soroban_verifier::UltraHonkVerifier;
+
+/// Events are only recorded inside a contract frame.
+#[contract]
+pub struct Harness;
+
+#[contractimpl]
+impl Harness {
+    pub fn noop(_env: Env) {}
+}
+
+#[test]
+fn verify_emits_metric_event_with_log_n() -> Result<(), String> {
+    let path = Path::new("circuits/simple_circuit/target");
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    env.cost_estimate().budget().reset_unlimited();
+
+    let proof_bytes = fs::read(path.join("proof")).map_err(|e| e.to_string())?;
+    let vk_bytes = fs::read(path.join("vk_with_hash")).map_err(|e| e.to_string())?;
+    let pi_bytes = fs::read(path.join("public_inputs")).map_err(|e| e.to_string())?;
+
+    let harness = env.register(Harness, ());
+    let (expected_log_n, passed) = env.as_contract(&harness, || {
+        let verifier = UltraHonkVerifier::new(&env, &Bytes::from_slice(&env, &vk_bytes))
+            .map_err(|e| format!("{e:?}"))?;
+        let res = verifier.verify(
+            &Bytes::from_slice(&env, &proof_bytes),
+            &Bytes::from_slice(&env, &pi_bytes),
+        );
+        Ok::<_, String>((verifier.get_vk().log_circuit_size as u32, res.is_ok()))
+    })?;
+
+    let all = env.events().all();
+    let last = all.events().last().ok_or("no events")?;
+    let xdr::ContractEventBody::V0(body) = &last.body;
+
+    let topic = |i: usize| -> Symbol {
+        let v = Val::try_from_val(&env, &body.topics[i]).unwrap();
+        Symbol::try_from_val(&env, &v).unwrap()
+    };
+    assert_eq!(topic(0), symbol_short!("metric"));
+    assert_eq!(topic(1), symbol_short!("verify"));
+
+    let data = Val::try_from_val(&env, &body.data).map_err(|e| format!("{e:?}"))?;
+    let (log_n, ok, rounds) =
+        <(u32, bool, u32)>::try_from_val(&env, &data).map_err(|e| format!("{e:?}"))?;
+    assert_eq!(log_n, expected_log_n);
+    assert_eq!(ok, passed);
+    assert!(passed);
+    assert_eq!(rounds, log_n);
+    Ok(())
+}
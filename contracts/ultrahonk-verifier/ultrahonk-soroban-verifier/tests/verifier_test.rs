@@ -1,6 +1,6 @@
 use soroban_sdk::{testutils::Ledger, Bytes, Env};
 use std::{fs, path::Path};
-use ultrahonk_soroban_verifier::UltraHonkVerifier;
+use ultrahonk_soroban_verifier::{verifier::VerifyError, UltraHonkVerifier};
 
 fn run(dir: &str) -> Result<(), String> {
     let path = Path::new(dir);
@@ -34,3 +34,102 @@ fn simple_circuit_proof_verifies() -> Result<(), String> {
 fn fib_chain_proof_verifies() -> Result<(), String> {
     run("circuits/fib_chain/target")
 }
+
+fn load_fixture(env: &Env, dir: &str) -> Result<(UltraHonkVerifier, Bytes, Vec<u8>), String> {
+    let path = Path::new(dir);
+    let proof_bytes = fs::read(path.join("proof")).map_err(|e| e.to_string())?;
+    let vk_bytes = fs::read(path.join("vk_with_hash")).map_err(|e| e.to_string())?;
+    let verifier = UltraHonkVerifier::new(env, &Bytes::from_slice(env, &vk_bytes))
+        .map_err(|e| format!("{e:?}"))?;
+    let public_inputs = fs::read(path.join("public_inputs")).map_err(|e| e.to_string())?;
+    Ok((verifier, Bytes::from_slice(env, &proof_bytes), public_inputs))
+}
+
+/// Right length, wrong value: the verifier cannot tell this apart from a bad
+/// proof, because public inputs are only bound through the transcript.
+#[test]
+fn wrong_public_input_value_fails_in_sumcheck() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    let (verifier, proof, mut public_inputs) = load_fixture(&env, "circuits/simple_circuit/target")?;
+    assert!(!public_inputs.is_empty());
+    public_inputs[31] ^= 1;
+
+    let res = verifier.verify(&proof, &Bytes::from_slice(&env, &public_inputs));
+    assert!(matches!(res, Err(VerifyError::SumcheckRoundFailed(_))), "{res:?}");
+    Ok(())
+}
+
+#[test]
+fn non_canonical_public_input_is_invalid_input() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    let (verifier, proof, mut public_inputs) = load_fixture(&env, "circuits/simple_circuit/target")?;
+    public_inputs[..32].copy_from_slice(&[0xffu8; 32]);
+
+    let res = verifier.verify(&proof, &Bytes::from_slice(&env, &public_inputs));
+    assert!(matches!(
+        res,
+        Err(VerifyError::InvalidInput("public input is not a canonical field element"))
+    ));
+    Ok(())
+}
+
+#[test]
+fn short_public_inputs_are_invalid_input() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    let (verifier, proof, public_inputs) = load_fixture(&env, "circuits/simple_circuit/target")?;
+    let short = &public_inputs[..public_inputs.len() - 32];
+
+    let res = verifier.verify(&proof, &Bytes::from_slice(&env, short));
+    assert!(matches!(
+        res,
+        Err(VerifyError::InvalidInput("public inputs mismatch (vk vs provided)"))
+    ));
+    Ok(())
+}
+
+#[test]
+fn empty_proof_is_invalid_input() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    let (verifier, _proof, public_inputs) = load_fixture(&env, "circuits/simple_circuit/target")?;
+
+    let res = verifier.verify(&Bytes::new(&env), &Bytes::from_slice(&env, &public_inputs));
+    assert!(matches!(res, Err(VerifyError::InvalidInput("empty proof"))), "{res:?}");
+    Ok(())
+}
+
+/// Empty public inputs are only wrong when the VK expects some.
+#[test]
+fn empty_public_inputs_are_checked_against_vk_count() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    let (verifier, proof, _public_inputs) = load_fixture(&env, "circuits/simple_circuit/target")?;
+
+    let res = verifier.verify(&proof, &Bytes::new(&env));
+    assert!(
+        matches!(res, Err(VerifyError::InvalidInput("public inputs mismatch (vk vs provided)"))),
+        "{res:?}"
+    );
+    Ok(())
+}
+
+/// The public-input count is reconciled before the proof is parsed: an
+/// unparseable proof of the right size still reports the input mismatch.
+#[test]
+fn public_input_mismatch_fails_before_proof_parsing() -> Result<(), String> {
+    let env = Env::default();
+    env.ledger().set_protocol_version(25);
+    let (verifier, proof, public_inputs) = load_fixture(&env, "circuits/simple_circuit/target")?;
+    let short = &public_inputs[..public_inputs.len() - 32];
+    let garbage = Bytes::from_slice(&env, &std::vec![0xffu8; proof.len() as usize]);
+
+    let res = verifier.verify(&garbage, &Bytes::from_slice(&env, short));
+    assert!(matches!(
+        res,
+        Err(VerifyError::InvalidInput("public inputs mismatch (vk vs provided)"))
+    ));
+    Ok(())
+}
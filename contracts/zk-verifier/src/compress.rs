@@ -0,0 +1,458 @@
+//! Point decompression for BN254 G1/G2.
+//!
+//! Compressed encoding is arkworks' `serialize_compressed` (ark-bn254 0.4),
+//! little-endian, so VKs exported by arkworks-based tooling load unchanged:
+//! - G1: 32 bytes, le(X)
+//! - G2: 64 bytes, le(X.c0) || le(X.c1)
+//!
+//! The two top bits of the last byte are flags (p < 2²⁵⁴ leaves them free):
+//! - `0x80`: Y is the lexicographically largest root (Y > −Y; for G2 compare
+//!   c1 first, then c0 when c1 = 0)
+//! - `0x40`: point at infinity; every other bit must be zero
+//!
+//! The decompressed output keeps the big-endian uncompressed form of the host.
+//!
+//! Y is recovered from the curve equation with a square root in Fp or Fp2.
+//! Only curve membership is checked here; the host rejects points outside the
+//! G2 subgroup when they reach the pairing.
+
+use core::cmp::Ordering;
+
+const FLAG_LARGEST: u8 = 0x80;
+const FLAG_INFINITY: u8 = 0x40;
+const FLAG_MASK: u8 = FLAG_LARGEST | FLAG_INFINITY;
+
+/// BN254 base field modulus, little-endian limbs.
+const P: [u64; 4] = [
+    0x3c20_8c16_d87c_fd47,
+    0x9781_6a91_6871_ca8d,
+    0xb850_45b6_8181_585d,
+    0x3064_4e72_e131_a029,
+];
+
+/// −p⁻¹ mod 2⁶⁴ (Newton iteration).
+const INV: u64 = {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(P[0].wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+};
+
+/// R² mod p with R = 2²⁵⁶, by doubling 1 modulo p 512 times.
+const R2: [u64; 4] = {
+    let mut r = [1u64, 0, 0, 0];
+    let mut i = 0;
+    while i < 512 {
+        r = double_mod(r);
+        i += 1;
+    }
+    r
+};
+
+/// (p + 1) / 4: square root exponent for p ≡ 3 (mod 4).
+const SQRT_EXP: [u64; 4] = shr2(add_small(P, 1));
+/// (p − 3) / 4
+const FP2_SQRT_EXP: [u64; 4] = shr2(sub_small(P, 3));
+/// (p − 1) / 2
+const HALF_EXP: [u64; 4] = shr1(sub_small(P, 1));
+/// p − 2: inversion exponent.
+const INV_EXP: [u64; 4] = sub_small(P, 2);
+
+const fn geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+const fn sub_raw(a: [u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut r = [0u64; 4];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < 4 {
+        let (d1, b1) = a[i].overflowing_sub(b[i]);
+        let (d2, b2) = d1.overflowing_sub(borrow);
+        r[i] = d2;
+        borrow = (b1 | b2) as u64;
+        i += 1;
+    }
+    r
+}
+
+const fn add_raw(a: [u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut r = [0u64; 4];
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < 4 {
+        let (s1, c1) = a[i].overflowing_add(b[i]);
+        let (s2, c2) = s1.overflowing_add(carry);
+        r[i] = s2;
+        carry = (c1 | c2) as u64;
+        i += 1;
+    }
+    (r, carry != 0)
+}
+
+const fn double_mod(a: [u64; 4]) -> [u64; 4] {
+    let (r, carry) = add_raw(a, &a);
+    if carry || geq(&r, &P) {
+        sub_raw(r, &P)
+    } else {
+        r
+    }
+}
+
+const fn add_small(a: [u64; 4], v: u64) -> [u64; 4] {
+    add_raw(a, &[v, 0, 0, 0]).0
+}
+
+const fn sub_small(a: [u64; 4], v: u64) -> [u64; 4] {
+    sub_raw(a, &[v, 0, 0, 0])
+}
+
+const fn shr1(a: [u64; 4]) -> [u64; 4] {
+    [
+        (a[0] >> 1) | (a[1] << 63),
+        (a[1] >> 1) | (a[2] << 63),
+        (a[2] >> 1) | (a[3] << 63),
+        a[3] >> 1,
+    ]
+}
+
+const fn shr2(a: [u64; 4]) -> [u64; 4] {
+    shr1(shr1(a))
+}
+
+/// Element of Fp in Montgomery form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fp([u64; 4]);
+
+impl Fp {
+    pub(crate) const ZERO: Fp = Fp([0; 4]);
+
+    /// Canonical big-endian bytes; `None` if the value is >= p.
+    pub(crate) fn from_be_bytes(bytes: &[u8; 32]) -> Option<Fp> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 32 - (i + 1) * 8;
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[start..start + 8]);
+            *limb = u64::from_be_bytes(buf);
+        }
+        if geq(&limbs, &P) {
+            return None;
+        }
+        Some(Fp(limbs).mul(&Fp(R2)))
+    }
+
+    pub(crate) fn to_be_bytes(self) -> [u8; 32] {
+        let limbs = self.to_canonical();
+        let mut out = [0u8; 32];
+        for (i, limb) in limbs.iter().enumerate() {
+            let start = 32 - (i + 1) * 8;
+            out[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        out
+    }
+
+    pub(crate) fn from_u64(v: u64) -> Fp {
+        Fp([v, 0, 0, 0]).mul(&Fp(R2))
+    }
+
+    fn to_canonical(self) -> [u64; 4] {
+        self.mul(&Fp([1, 0, 0, 0])).0
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    pub(crate) fn add(&self, rhs: &Fp) -> Fp {
+        let (r, carry) = add_raw(self.0, &rhs.0);
+        if carry || geq(&r, &P) {
+            Fp(sub_raw(r, &P))
+        } else {
+            Fp(r)
+        }
+    }
+
+    pub(crate) fn sub(&self, rhs: &Fp) -> Fp {
+        if geq(&self.0, &rhs.0) {
+            Fp(sub_raw(self.0, &rhs.0))
+        } else {
+            Fp(add_raw(sub_raw(self.0, &rhs.0), &P).0)
+        }
+    }
+
+    pub(crate) fn neg(&self) -> Fp {
+        Fp::ZERO.sub(self)
+    }
+
+    /// Montgomery multiplication (CIOS).
+    pub(crate) fn mul(&self, rhs: &Fp) -> Fp {
+        let a = &self.0;
+        let b = &rhs.0;
+        let mut t = [0u64; 6];
+        for &bi in b.iter() {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let v = t[j] as u128 + (a[j] as u128) * (bi as u128) + carry;
+                t[j] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[4] as u128 + carry;
+            t[4] = v as u64;
+            t[5] = (v >> 64) as u64;
+
+            let m = t[0].wrapping_mul(INV);
+            let mut carry = (t[0] as u128 + (m as u128) * (P[0] as u128)) >> 64;
+            for j in 1..4 {
+                let v = t[j] as u128 + (m as u128) * (P[j] as u128) + carry;
+                t[j - 1] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[4] as u128 + carry;
+            t[3] = v as u64;
+            t[4] = t[5] + (v >> 64) as u64;
+        }
+        let r = [t[0], t[1], t[2], t[3]];
+        if t[4] != 0 || geq(&r, &P) {
+            Fp(sub_raw(r, &P))
+        } else {
+            Fp(r)
+        }
+    }
+
+    pub(crate) fn square(&self) -> Fp {
+        self.mul(self)
+    }
+
+    fn pow(&self, exp: &[u64; 4]) -> Fp {
+        let mut acc = Fp::from_u64(1);
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> bit) & 1 == 1 {
+                    acc = acc.mul(self);
+                }
+            }
+        }
+        acc
+    }
+
+    fn inverse(&self) -> Fp {
+        self.pow(&INV_EXP)
+    }
+
+    pub(crate) fn sqrt(&self) -> Option<Fp> {
+        let root = self.pow(&SQRT_EXP);
+        (root.square() == *self).then_some(root)
+    }
+
+    /// Ordering of the canonical integer values.
+    fn cmp_canonical(&self, rhs: &Fp) -> Ordering {
+        let (a, b) = (self.to_canonical(), rhs.to_canonical());
+        for i in (0..4).rev() {
+            match a[i].cmp(&b[i]) {
+                Ordering::Equal => continue,
+                other => return other,
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn is_lex_largest(&self) -> bool {
+        self.cmp_canonical(&self.neg()) == Ordering::Greater
+    }
+}
+
+/// Element c0 + c1·i of Fp2 = Fp[i] / (i² + 1).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fp2 {
+    pub(crate) c0: Fp,
+    pub(crate) c1: Fp,
+}
+
+impl Fp2 {
+    fn new(c0: Fp, c1: Fp) -> Fp2 {
+        Fp2 { c0, c1 }
+    }
+
+    fn one() -> Fp2 {
+        Fp2::new(Fp::from_u64(1), Fp::ZERO)
+    }
+
+    fn add(&self, rhs: &Fp2) -> Fp2 {
+        Fp2::new(self.c0.add(&rhs.c0), self.c1.add(&rhs.c1))
+    }
+
+    pub(crate) fn neg(&self) -> Fp2 {
+        Fp2::new(self.c0.neg(), self.c1.neg())
+    }
+
+    fn conjugate(&self) -> Fp2 {
+        Fp2::new(self.c0, self.c1.neg())
+    }
+
+    fn mul(&self, rhs: &Fp2) -> Fp2 {
+        let c0 = self.c0.mul(&rhs.c0).sub(&self.c1.mul(&rhs.c1));
+        let c1 = self.c0.mul(&rhs.c1).add(&self.c1.mul(&rhs.c0));
+        Fp2::new(c0, c1)
+    }
+
+    fn square(&self) -> Fp2 {
+        self.mul(self)
+    }
+
+    fn inverse(&self) -> Fp2 {
+        let norm = self.c0.square().add(&self.c1.square()).inverse();
+        Fp2::new(self.c0.mul(&norm), self.c1.neg().mul(&norm))
+    }
+
+    fn pow(&self, exp: &[u64; 4]) -> Fp2 {
+        let mut acc = Fp2::one();
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> bit) & 1 == 1 {
+                    acc = acc.mul(self);
+                }
+            }
+        }
+        acc
+    }
+
+    /// Square root for p ≡ 3 (mod 4) (Adj & Rodríguez-Henríquez, Alg. 9).
+    fn sqrt(&self) -> Option<Fp2> {
+        let minus_one = Fp2::one().neg();
+        let a1 = self.pow(&FP2_SQRT_EXP);
+        let alpha = a1.square().mul(self);
+        if alpha.conjugate().mul(&alpha) == minus_one {
+            return None;
+        }
+        let x0 = a1.mul(self);
+        let root = if alpha == minus_one {
+            Fp2::new(x0.c1.neg(), x0.c0)
+        } else {
+            alpha.add(&Fp2::one()).pow(&HALF_EXP).mul(&x0)
+        };
+        (root.square() == *self).then_some(root)
+    }
+
+    fn is_lex_largest(&self) -> bool {
+        if self.c1.is_zero() {
+            self.c0.is_lex_largest()
+        } else {
+            self.c1.is_lex_largest()
+        }
+    }
+}
+
+/// Split the flag bits off the last byte. `None` for a malformed infinity.
+fn take_flags<const N: usize>(bytes: &[u8; N]) -> Option<(bool, bool, [u8; N])> {
+    let largest = bytes[N - 1] & FLAG_LARGEST != 0;
+    let infinity = bytes[N - 1] & FLAG_INFINITY != 0;
+    let mut body = *bytes;
+    body[N - 1] &= !FLAG_MASK;
+    if infinity && (largest || body.iter().any(|b| *b != 0)) {
+        return None;
+    }
+    Some((largest, infinity, body))
+}
+
+/// Read a little-endian field element from `bytes[offset..offset + 32]`.
+fn fp_from_le(bytes: &[u8], offset: usize) -> Option<Fp> {
+    let mut be = [0u8; 32];
+    be.copy_from_slice(&bytes[offset..offset + 32]);
+    be.reverse();
+    Fp::from_be_bytes(&be)
+}
+
+/// Twist coefficient: y² = x³ + 3 / (9 + i) on G2.
+fn twist_b() -> Fp2 {
+    Fp2::new(Fp::from_u64(9), Fp::from_u64(1))
+        .inverse()
+        .mul(&Fp2::new(Fp::from_u64(3), Fp::ZERO))
+}
+
+/// Whether a 64-byte be(X) || be(Y) point has canonical coordinates and lies on
+/// y² = x³ + 3. All zeros (infinity) is accepted.
+pub(crate) fn g1_on_curve(bytes: &[u8; 64]) -> bool {
+    if bytes.iter().all(|b| *b == 0) {
+        return true;
+    }
+    let mut x = [0u8; 32];
+    let mut y = [0u8; 32];
+    x.copy_from_slice(&bytes[..32]);
+    y.copy_from_slice(&bytes[32..]);
+    let (Some(x), Some(y)) = (Fp::from_be_bytes(&x), Fp::from_be_bytes(&y)) else {
+        return false;
+    };
+    y.square() == x.square().mul(&x).add(&Fp::from_u64(3))
+}
+
+/// [`g1_on_curve`] for a 128-byte G2 point on the twist. Subgroup membership is
+/// left to the host, as for decompressed points.
+pub(crate) fn g2_on_curve(bytes: &[u8; 128]) -> bool {
+    if bytes.iter().all(|b| *b == 0) {
+        return true;
+    }
+    let fp = |i: usize| {
+        let mut limb = [0u8; 32];
+        limb.copy_from_slice(&bytes[i * 32..(i + 1) * 32]);
+        Fp::from_be_bytes(&limb)
+    };
+    let (Some(x1), Some(x0), Some(y1), Some(y0)) = (fp(0), fp(1), fp(2), fp(3)) else {
+        return false;
+    };
+    let x = Fp2::new(x0, x1);
+    let y = Fp2::new(y0, y1);
+    y.square() == x.square().mul(&x).add(&twist_b())
+}
+
+/// Decompress a 32-byte G1 point into the 64-byte be(X) || be(Y) form.
+pub(crate) fn decompress_g1(bytes: &[u8; 32]) -> Option<[u8; 64]> {
+    let (largest, infinity, body) = take_flags(bytes)?;
+    if infinity {
+        return Some([0u8; 64]);
+    }
+    let x = fp_from_le(&body, 0)?;
+    // y² = x³ + 3
+    let rhs = x.square().mul(&x).add(&Fp::from_u64(3));
+    let mut y = rhs.sqrt()?;
+    if y.is_lex_largest() != largest {
+        y = y.neg();
+    }
+    let mut out = [0u8; 64];
+    out[..32].copy_from_slice(&x.to_be_bytes());
+    out[32..].copy_from_slice(&y.to_be_bytes());
+    Some(out)
+}
+
+/// Decompress a 64-byte G2 point into the 128-byte
+/// be(X.c1) || be(X.c0) || be(Y.c1) || be(Y.c0) form.
+pub(crate) fn decompress_g2(bytes: &[u8; 64]) -> Option<[u8; 128]> {
+    let (largest, infinity, body) = take_flags(bytes)?;
+    if infinity {
+        return Some([0u8; 128]);
+    }
+    let x = Fp2::new(fp_from_le(&body, 0)?, fp_from_le(&body, 32)?);
+    let rhs = x.square().mul(&x).add(&twist_b());
+    let mut y = rhs.sqrt()?;
+    if y.is_lex_largest() != largest {
+        y = y.neg();
+    }
+    let mut out = [0u8; 128];
+    out[..32].copy_from_slice(&x.c1.to_be_bytes());
+    out[32..64].copy_from_slice(&x.c0.to_be_bytes());
+    out[64..96].copy_from_slice(&y.c1.to_be_bytes());
+    out[96..].copy_from_slice(&y.c0.to_be_bytes());
+    Some(out)
+}
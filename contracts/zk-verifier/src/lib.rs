@@ -26,9 +26,11 @@
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype,
     crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
-    vec, Address, BytesN, Env, Vec,
+    vec, Address, Bytes, BytesN, Env, Vec,
 };
 
+mod compress;
+
 #[cfg(test)]
 mod test;
 
@@ -44,6 +46,14 @@ pub enum VerifierError {
     VkNotFound = 2,
     PublicInputCountMismatch = 3,
     InvalidProof = 4,
+    /// A compressed point is not on the curve or its flags are malformed.
+    InvalidPoint = 5,
+    /// The stored VK is not the version the caller generated the proof for.
+    VkVersionMismatch = 6,
+    /// A VK point is off-curve or non-canonical, or the VK has no IC points.
+    InvalidVk = 7,
+    /// A public-input blob is not a whole number of 32-byte field elements.
+    MisalignedInputs = 8,
 }
 
 // ============================================================================
@@ -71,6 +81,28 @@ pub struct Groth16Vk {
     pub ic: Vec<BytesN<64>>,
 }
 
+/// A stored [`Groth16Vk`]. `version` starts at 1 and is bumped every time the
+/// circuit's VK is replaced, so provers can detect a recompiled circuit.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VersionedVk {
+    pub version: u32,
+    pub vk: Groth16Vk,
+}
+
+/// [`Groth16Vk`] with compressed points in arkworks' encoding (see `compress`):
+/// - G1 point: 32 bytes  (le(X))
+/// - G2 point: 64 bytes  (le(X.c0) || le(X.c1))
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Groth16VkCompressed {
+    pub alpha_g1: BytesN<32>,
+    pub beta_g2: BytesN<64>,
+    pub gamma_g2: BytesN<64>,
+    pub delta_g2: BytesN<64>,
+    pub ic: Vec<BytesN<32>>,
+}
+
 /// A Groth16 proof over BN254 (3 curve elements).
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -91,11 +123,14 @@ pub struct Groth16Proof {
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
-    /// VK stored per circuit identifier (0 = ping_distance, 1 = turn_status, 2 = move_proof).
+    /// [`VersionedVk`] stored per circuit identifier (0 = ping_distance, 1 = turn_status, 2 = move_proof).
     Vk(u32),
+    /// Successful verification, keyed on keccak256(circuit_id, proof, public_inputs).
+    ProofCache(BytesN<32>),
 }
 
 const VK_TTL_LEDGERS: u32 = 518_400; // ~30 days
+const PROOF_CACHE_TTL_LEDGERS: u32 = 17_280; // ~1 day
 
 // ============================================================================
 // Contract
@@ -114,37 +149,66 @@ impl ZkVerifierContract {
     // ----- Admin: store verification keys -----------------------------------
 
     /// Store (or replace) the verification key for a given circuit.
+    /// Bumps the circuit's VK version.
+    ///
+    /// Every point is checked here, so a malformed VK fails with `InvalidVk`
+    /// now rather than trapping inside `verify_groth16` later.
     pub fn store_vk(
         env: Env,
         admin: Address,
         circuit_id: u32,
         vk: Groth16Vk,
     ) -> Result<(), VerifierError> {
-        admin.require_auth();
-        let stored: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("admin not set");
-        if admin != stored {
-            return Err(VerifierError::NotAdmin);
-        }
+        Self::require_admin(&env, &admin)?;
+        Self::validate_vk(&vk)?;
+        Self::put_vk(&env, circuit_id, &vk);
+        Ok(())
+    }
 
-        let key = DataKey::Vk(circuit_id);
-        env.storage().persistent().set(&key, &vk);
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, VK_TTL_LEDGERS, VK_TTL_LEDGERS);
+    /// Same as `store_vk` for a VK with compressed points. Each point is
+    /// decompressed and the result is validated and stored as a regular
+    /// [`Groth16Vk`].
+    pub fn store_vk_compressed(
+        env: Env,
+        admin: Address,
+        circuit_id: u32,
+        vk: Groth16VkCompressed,
+    ) -> Result<(), VerifierError> {
+        Self::require_admin(&env, &admin)?;
+        let g1 = |p: BytesN<32>| {
+            compress::decompress_g1(&p.to_array())
+                .map(|b| BytesN::from_array(&env, &b))
+                .ok_or(VerifierError::InvalidPoint)
+        };
+        let g2 = |p: BytesN<64>| {
+            compress::decompress_g2(&p.to_array())
+                .map(|b| BytesN::from_array(&env, &b))
+                .ok_or(VerifierError::InvalidPoint)
+        };
+        let mut ic = Vec::new(&env);
+        for p in vk.ic.iter() {
+            ic.push_back(g1(p)?);
+        }
+        let full = Groth16Vk {
+            alpha_g1: g1(vk.alpha_g1)?,
+            beta_g2: g2(vk.beta_g2)?,
+            gamma_g2: g2(vk.gamma_g2)?,
+            delta_g2: g2(vk.delta_g2)?,
+            ic,
+        };
+        Self::validate_vk(&full)?;
+        Self::put_vk(&env, circuit_id, &full);
         Ok(())
     }
 
     /// Read back a stored VK (for inspection / debugging).
     pub fn get_vk(env: Env, circuit_id: u32) -> Result<Groth16Vk, VerifierError> {
-        let key = DataKey::Vk(circuit_id);
-        env.storage()
-            .persistent()
-            .get(&key)
-            .ok_or(VerifierError::VkNotFound)
+        Ok(Self::load_vk(&env, circuit_id)?.vk)
+    }
+
+    /// Version of the VK currently stored for `circuit_id`.
+    pub fn get_vk_version(env: Env, circuit_id: u32) -> Result<u32, VerifierError> {
+        Ok(Self::load_vk(&env, circuit_id)?.version)
     }
 
     // ----- Verification -----------------------------------------------------
@@ -155,32 +219,42 @@ impl ZkVerifierContract {
     /// encoded as a big-endian `BytesN<32>`.
     ///
     /// Returns `true` if the proof is valid.
+    ///
+    /// A nonzero `expected_version` must match `get_vk_version`, otherwise the
+    /// call fails with `VkVersionMismatch` before any cache lookup or pairing.
+    /// Pass 0 to accept whichever VK is stored.
+    ///
+    /// Accepted proofs are cached per VK version, so resubmitting the same
+    /// proof skips the pairing until the VK is replaced. Rejections are never
+    /// cached.
     pub fn verify_groth16(
         env: Env,
         circuit_id: u32,
         proof: Groth16Proof,
         public_inputs: Vec<BytesN<32>>,
+        expected_version: u32,
     ) -> Result<bool, VerifierError> {
-        let vk = Self::get_vk(env.clone(), circuit_id)?;
+        let stored = Self::load_vk(&env, circuit_id)?;
+        if expected_version != 0 && stored.version != expected_version {
+            return Err(VerifierError::VkVersionMismatch);
+        }
 
-        // IC length must be public_inputs.len() + 1
-        let n_inputs = public_inputs.len();
-        if vk.ic.len() != n_inputs + 1 {
-            return Err(VerifierError::PublicInputCountMismatch);
+        // Keyed by VK version, so replacing the VK retires every cached acceptance.
+        let cache_key =
+            DataKey::ProofCache(Self::proof_hash(&env, circuit_id, stored.version, &proof, &public_inputs));
+        if env.storage().persistent().has(&cache_key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&cache_key, PROOF_CACHE_TTL_LEDGERS, PROOF_CACHE_TTL_LEDGERS);
+            return Ok(true);
         }
 
-        let bn254 = env.crypto().bn254();
+        let vk = stored.vk;
 
         // --- Step 1: compute vk_x = IC[0] + Σ(input[i] · IC[i+1]) ----------
 
-        let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get(0).unwrap());
-
-        for i in 0..n_inputs {
-            let scalar = Fr::from_bytes(public_inputs.get(i).unwrap());
-            let ic_point = Bn254G1Affine::from_bytes(vk.ic.get(i + 1).unwrap());
-            let term = bn254.g1_mul(&ic_point, &scalar);
-            vk_x = bn254.g1_add(&vk_x, &term);
-        }
+        let vk_x = Self::compute_vk_x_point(&env, &vk, &public_inputs)?;
+        let bn254 = env.crypto().bn254();
 
         // --- Step 2: pairing check ------------------------------------------
         //
@@ -207,6 +281,128 @@ impl ZkVerifierContract {
         let g2_vec: Vec<Bn254G2Affine> = vec![&env, b, beta, gamma, delta];
 
         let ok = bn254.pairing_check(g1_vec, g2_vec);
+        if ok {
+            env.storage().persistent().set(&cache_key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&cache_key, PROOF_CACHE_TTL_LEDGERS, PROOF_CACHE_TTL_LEDGERS);
+        }
         Ok(ok)
     }
+
+    /// `verify_groth16` for callers holding the public inputs as one
+    /// contiguous blob of big-endian 32-byte field elements (e.g. a bb
+    /// `public_inputs` file). Accepts whichever VK is stored.
+    pub fn verify_groth16_blob(
+        env: Env,
+        circuit_id: u32,
+        proof: Groth16Proof,
+        public_inputs: Bytes,
+    ) -> Result<bool, VerifierError> {
+        if !public_inputs.len().is_multiple_of(32) {
+            return Err(VerifierError::MisalignedInputs);
+        }
+        let mut inputs = Vec::new(&env);
+        for i in 0..public_inputs.len() / 32 {
+            let mut field = [0u8; 32];
+            public_inputs.slice(i * 32..(i + 1) * 32).copy_into_slice(&mut field);
+            inputs.push_back(BytesN::from_array(&env, &field));
+        }
+        Self::verify_groth16(env, circuit_id, proof, inputs, 0)
+    }
+
+    /// Debugging aid: step 1 of `verify_groth16` only. Returns the
+    /// public-input combination `vk_x = IC[0] + Σ input[i]·IC[i+1]` as
+    /// uncompressed G1 bytes, to compare against an off-chain computation.
+    pub fn compute_vk_x(
+        env: Env,
+        circuit_id: u32,
+        public_inputs: Vec<BytesN<32>>,
+    ) -> Result<BytesN<64>, VerifierError> {
+        let vk = Self::get_vk(env.clone(), circuit_id)?;
+        Ok(Self::compute_vk_x_point(&env, &vk, &public_inputs)?.to_bytes())
+    }
+
+    fn compute_vk_x_point(
+        env: &Env,
+        vk: &Groth16Vk,
+        public_inputs: &Vec<BytesN<32>>,
+    ) -> Result<Bn254G1Affine, VerifierError> {
+        // IC length must be public_inputs.len() + 1
+        let n_inputs = public_inputs.len();
+        if vk.ic.len() != n_inputs + 1 {
+            return Err(VerifierError::PublicInputCountMismatch);
+        }
+
+        let bn254 = env.crypto().bn254();
+        let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get(0).unwrap());
+
+        for i in 0..n_inputs {
+            let scalar = Fr::from_bytes(public_inputs.get(i).unwrap());
+            let ic_point = Bn254G1Affine::from_bytes(vk.ic.get(i + 1).unwrap());
+            let term = bn254.g1_mul(&ic_point, &scalar);
+            vk_x = bn254.g1_add(&vk_x, &term);
+        }
+        Ok(vk_x)
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), VerifierError> {
+        admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("admin not set");
+        if *admin != stored {
+            return Err(VerifierError::NotAdmin);
+        }
+        Ok(())
+    }
+
+    fn validate_vk(vk: &Groth16Vk) -> Result<(), VerifierError> {
+        let g1_ok = compress::g1_on_curve(&vk.alpha_g1.to_array())
+            && vk.ic.iter().all(|p| compress::g1_on_curve(&p.to_array()));
+        let g2_ok = [&vk.beta_g2, &vk.gamma_g2, &vk.delta_g2]
+            .iter()
+            .all(|p| compress::g2_on_curve(&p.to_array()));
+        if vk.ic.is_empty() || !g1_ok || !g2_ok {
+            return Err(VerifierError::InvalidVk);
+        }
+        Ok(())
+    }
+
+    fn load_vk(env: &Env, circuit_id: u32) -> Result<VersionedVk, VerifierError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vk(circuit_id))
+            .ok_or(VerifierError::VkNotFound)
+    }
+
+    fn put_vk(env: &Env, circuit_id: u32, vk: &Groth16Vk) {
+        let key = DataKey::Vk(circuit_id);
+        let version = Self::load_vk(env, circuit_id).map_or(0, |v| v.version) + 1;
+        env.storage().persistent().set(&key, &VersionedVk { version, vk: vk.clone() });
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, VK_TTL_LEDGERS, VK_TTL_LEDGERS);
+    }
+
+    /// keccak256(be(circuit_id) || be(vk_version) || A || B || C || public_inputs).
+    fn proof_hash(
+        env: &Env,
+        circuit_id: u32,
+        vk_version: u32,
+        proof: &Groth16Proof,
+        public_inputs: &Vec<BytesN<32>>,
+    ) -> BytesN<32> {
+        let mut data = Bytes::from_array(env, &circuit_id.to_be_bytes());
+        data.append(&Bytes::from_array(env, &vk_version.to_be_bytes()));
+        data.append(&proof.a.clone().into());
+        data.append(&proof.b.clone().into());
+        data.append(&proof.c.clone().into());
+        for input in public_inputs.iter() {
+            data.append(&input.into());
+        }
+        env.crypto().keccak256(&data).into()
+    }
 }
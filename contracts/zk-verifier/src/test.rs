@@ -1,8 +1,12 @@
 #![cfg(test)]
 
-use crate::{Groth16Proof, Groth16Vk, VerifierError, ZkVerifierContract, ZkVerifierContractClient};
+use crate::compress::{decompress_g1, decompress_g2};
+use crate::{
+    DataKey, Groth16Proof, Groth16Vk, Groth16VkCompressed, VerifierError, ZkVerifierContract,
+    ZkVerifierContractClient,
+};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, Vec};
 
 // ============================================================================
 // BN254 Generator Constants (Ethereum / Soroban convention, uncompressed)
@@ -146,7 +150,7 @@ fn valid_proof_is_accepted() {
     let proof = valid_proof(&env);
     let public_inputs: Vec<BytesN<32>> = vec![&env]; // zero public inputs
 
-    let result = client.verify_groth16(&0u32, &proof, &public_inputs);
+    let result = client.verify_groth16(&0u32, &proof, &public_inputs, &0u32);
     assert!(result, "valid proof must be accepted");
 }
 
@@ -173,7 +177,7 @@ fn tampered_proof_a_is_rejected() {
     };
     let public_inputs: Vec<BytesN<32>> = vec![&env];
 
-    let result = client.verify_groth16(&0u32, &bad_proof, &public_inputs);
+    let result = client.verify_groth16(&0u32, &bad_proof, &public_inputs, &0u32);
     assert!(!result, "tampered proof.a must be rejected");
 }
 
@@ -192,7 +196,7 @@ fn tampered_proof_c_is_rejected() {
     };
     let public_inputs: Vec<BytesN<32>> = vec![&env];
 
-    let result = client.verify_groth16(&0u32, &bad_proof, &public_inputs);
+    let result = client.verify_groth16(&0u32, &bad_proof, &public_inputs, &0u32);
     assert!(!result, "tampered proof.c must be rejected");
 }
 
@@ -207,7 +211,7 @@ fn wrong_public_input_count_is_rejected() {
     // Supply 1 public input when VK expects 0.
     let bad_inputs: Vec<BytesN<32>> = vec![&env, BytesN::from_array(&env, &[1u8; 32])];
 
-    let res = client.try_verify_groth16(&0u32, &proof, &bad_inputs);
+    let res = client.try_verify_groth16(&0u32, &proof, &bad_inputs, &0u32);
     match res {
         Err(Ok(e)) => assert_eq!(e, VerifierError::PublicInputCountMismatch),
         _ => panic!("expected PublicInputCountMismatch error"),
@@ -257,7 +261,7 @@ fn vk_with_public_inputs_works() {
     let zero_input = BytesN::from_array(&env, &[0u8; 32]);
     let inputs_ok: Vec<BytesN<32>> = vec![&env, zero_input];
     assert!(
-        client.verify_groth16(&0u32, &proof, &inputs_ok),
+        client.verify_groth16(&0u32, &proof, &inputs_ok, &0u32),
         "input=0 must pass"
     );
 
@@ -267,7 +271,351 @@ fn vk_with_public_inputs_works() {
     let one_input = BytesN::from_array(&env, &one_bytes);
     let inputs_bad: Vec<BytesN<32>> = vec![&env, one_input];
     assert!(
-        !client.verify_groth16(&0u32, &proof, &inputs_bad),
+        !client.verify_groth16(&0u32, &proof, &inputs_bad, &0u32),
         "input=1 must fail"
     );
 }
+
+#[test]
+fn accepted_proof_is_served_from_cache() {
+    let (env, client, admin) = setup_test();
+
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    let proof = valid_proof(&env);
+    let public_inputs: Vec<BytesN<32>> = vec![&env];
+    let key = DataKey::ProofCache(ZkVerifierContract::proof_hash(&env, 0, 1, &proof, &public_inputs));
+
+    assert!(client.verify_groth16(&0u32, &proof, &public_inputs, &0u32));
+    let cached = env.as_contract(&client.address, || env.storage().persistent().has(&key));
+    assert!(cached, "accepted proof must be cached");
+    assert!(client.verify_groth16(&0u32, &proof, &public_inputs, &1u32));
+}
+
+#[test]
+fn cached_proof_fails_after_vk_swap() {
+    let (env, client, admin) = setup_test();
+
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    let proof = valid_proof(&env);
+    let public_inputs: Vec<BytesN<32>> = vec![&env];
+    assert!(client.verify_groth16(&0u32, &proof, &public_inputs, &0u32));
+
+    // Replace the VK with one that no longer accepts the proof: the cached
+    // acceptance belongs to version 1 and must not carry over.
+    let mut vk = degenerate_vk(&env);
+    vk.alpha_g1 = g1_zero(&env);
+    client.store_vk(&admin, &0u32, &vk);
+    assert!(!client.verify_groth16(&0u32, &proof, &public_inputs, &0u32));
+    assert!(!client.verify_groth16(&0u32, &proof, &public_inputs, &2u32));
+}
+
+#[test]
+fn rejected_proof_is_not_cached() {
+    let (env, client, admin) = setup_test();
+
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    let bad_proof = Groth16Proof {
+        a: g1_generator(&env),
+        b: g2_generator(&env),
+        c: g1_generator(&env),
+    };
+    let public_inputs: Vec<BytesN<32>> = vec![&env];
+    let key = DataKey::ProofCache(ZkVerifierContract::proof_hash(&env, 0, 1, &bad_proof, &public_inputs));
+
+    assert!(!client.verify_groth16(&0u32, &bad_proof, &public_inputs, &0u32));
+    let cached = env.as_contract(&client.address, || env.storage().persistent().has(&key));
+    assert!(!cached);
+}
+
+// ============================================================================
+// Compressed VK
+// ============================================================================
+
+// Vectors below are `serialize_compressed` output from ark-bn254 0.4.0.
+
+fn hex_bytes<const N: usize>(hex: &str) -> [u8; N] {
+    let digit = |c: u8| (c as char).to_digit(16).unwrap() as u8;
+    let hex = hex.as_bytes();
+    assert_eq!(hex.len(), 2 * N);
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = digit(hex[2 * i]) << 4 | digit(hex[2 * i + 1]);
+    }
+    out
+}
+
+fn g1_generator_compressed(env: &Env) -> BytesN<32> {
+    BytesN::from_array(
+        env,
+        &hex_bytes("0100000000000000000000000000000000000000000000000000000000000000"),
+    )
+}
+
+fn g2_generator_compressed(env: &Env) -> BytesN<64> {
+    BytesN::from_array(
+        env,
+        &hex_bytes(
+            "edf692d95cbdde46ddda5ef7d422436779445c5e66006a42761e1f12efde0018\
+             c212f3aeb785e49712e7a9353349aaf1255dfb31b7bf60723a480d9293938e19",
+        ),
+    )
+}
+
+fn g1_infinity_compressed(env: &Env) -> BytesN<32> {
+    BytesN::from_array(
+        env,
+        &hex_bytes("0000000000000000000000000000000000000000000000000000000000000040"),
+    )
+}
+
+#[test]
+fn arkworks_generators_decompress() {
+    let env = Env::default();
+
+    let g1 = g1_generator(&env).to_array();
+    assert_eq!(decompress_g1(&g1_generator_compressed(&env).to_array()), Some(g1));
+    let g2 = g2_generator(&env).to_array();
+    assert_eq!(decompress_g2(&g2_generator_compressed(&env).to_array()), Some(g2));
+    assert_eq!(decompress_g1(&g1_infinity_compressed(&env).to_array()), Some([0u8; 64]));
+    let g2_infinity = hex_bytes::<64>(
+        "0000000000000000000000000000000000000000000000000000000000000000\
+         0000000000000000000000000000000000000000000000000000000000000040",
+    );
+    assert_eq!(decompress_g2(&g2_infinity), Some([0u8; 128]));
+}
+
+#[test]
+fn arkworks_negated_generators_decompress() {
+    let env = Env::default();
+
+    // -G1: sign flag set, Y = p - 2.
+    let neg_g1 = hex_bytes::<32>("0100000000000000000000000000000000000000000000000000000000000080");
+    let expected: [u8; 64] = hex_bytes(
+        "0000000000000000000000000000000000000000000000000000000000000001\
+         30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45",
+    );
+    assert_eq!(decompress_g1(&neg_g1), Some(expected));
+
+    let neg_g2 = hex_bytes::<64>(
+        "edf692d95cbdde46ddda5ef7d422436779445c5e66006a42761e1f12efde0018\
+         c212f3aeb785e49712e7a9353349aaf1255dfb31b7bf60723a480d9293938e99",
+    );
+    let g2 = g2_generator(&env).to_array();
+    let mut expected = [0u8; 128];
+    expected[..64].copy_from_slice(&g2[..64]);
+    expected[64..].copy_from_slice(&hex_bytes::<64>(
+        "275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec\
+         1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d",
+    ));
+    assert_eq!(decompress_g2(&neg_g2), Some(expected));
+}
+
+#[test]
+fn arkworks_generator_multiples_decompress() {
+    // 5·G1 and 5·G2: full-width coordinates with non-trivial roots.
+    let g1_5 = hex_bytes::<32>("a93f16faa7a849e89ca35389d8dee46243772b760402bc66f7e0fe0edf39c117");
+    let expected: [u8; 64] = hex_bytes(
+        "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa9\
+         01e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c",
+    );
+    assert_eq!(decompress_g1(&g1_5), Some(expected));
+
+    let g2_5 = hex_bytes::<64>(
+        "d81064a090837013919a8aaef85ddbf5ea4839603c77e5f4132d303b429c532e\
+         a1b228e410e57106d55937afc57ab5572416e1de08121c9dd95fb561f5cc098a",
+    );
+    let expected: [u8; 128] = hex_bytes(
+        "0a09ccf561b55fd99d1c1208dee1162457b57ac5af3759d50671e510e428b2a1\
+         2e539c423b302d13f4e5773c603948eaf5db5df8ae8a9a9113708390a06410d8\
+         19b763513924a736e4eebd0d78c91c1bc1d657fee4214057d21414011cfcc763\
+         2f8d9f9ab83727c77a2fec063cb7b6e5eb23044ccf535ad49d46d394fb6f6bf6",
+    );
+    assert_eq!(decompress_g2(&g2_5), Some(expected));
+}
+
+#[test]
+fn compressed_vk_verifies_proof() {
+    let (env, client, admin) = setup_test();
+
+    let vk = Groth16VkCompressed {
+        alpha_g1: g1_generator_compressed(&env),
+        beta_g2: g2_generator_compressed(&env),
+        gamma_g2: g2_generator_compressed(&env),
+        delta_g2: g2_generator_compressed(&env),
+        ic: vec![&env, g1_infinity_compressed(&env)],
+    };
+    client.store_vk_compressed(&admin, &0u32, &vk);
+
+    let stored = client.get_vk(&0u32);
+    let expected = degenerate_vk(&env);
+    assert_eq!(stored.alpha_g1, expected.alpha_g1);
+    assert_eq!(stored.beta_g2, expected.beta_g2);
+    assert_eq!(stored.ic, expected.ic);
+
+    let public_inputs: Vec<BytesN<32>> = vec![&env];
+    assert!(client.verify_groth16(&0u32, &valid_proof(&env), &public_inputs, &0u32));
+}
+
+#[test]
+fn compressed_vk_with_bad_point_is_rejected() {
+    let (env, client, admin) = setup_test();
+
+    // X >= p once the flag bits are cleared.
+    let bad = BytesN::from_array(&env, &[0x3fu8; 32]);
+    let vk = Groth16VkCompressed {
+        alpha_g1: bad,
+        beta_g2: g2_generator_compressed(&env),
+        gamma_g2: g2_generator_compressed(&env),
+        delta_g2: g2_generator_compressed(&env),
+        ic: vec![&env, g1_infinity_compressed(&env)],
+    };
+    let res = client.try_store_vk_compressed(&admin, &0u32, &vk);
+    assert_eq!(res, Err(Ok(VerifierError::InvalidPoint)));
+    assert!(matches!(client.try_get_vk(&0u32), Err(Ok(VerifierError::VkNotFound))));
+}
+
+#[test]
+fn compressed_vk_without_ic_is_rejected() {
+    let (env, client, admin) = setup_test();
+
+    let vk = Groth16VkCompressed {
+        alpha_g1: g1_generator_compressed(&env),
+        beta_g2: g2_generator_compressed(&env),
+        gamma_g2: g2_generator_compressed(&env),
+        delta_g2: g2_generator_compressed(&env),
+        ic: Vec::new(&env),
+    };
+    let res = client.try_store_vk_compressed(&admin, &0u32, &vk);
+    assert_eq!(res, Err(Ok(VerifierError::InvalidVk)));
+    assert!(matches!(client.try_get_vk(&0u32), Err(Ok(VerifierError::VkNotFound))));
+}
+
+// ============================================================================
+// VK versioning
+// ============================================================================
+
+#[test]
+fn store_vk_bumps_version() {
+    let (env, client, admin) = setup_test();
+
+    assert_eq!(client.try_get_vk_version(&0u32), Err(Ok(VerifierError::VkNotFound)));
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    assert_eq!(client.get_vk_version(&0u32), 1);
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    assert_eq!(client.get_vk_version(&0u32), 2);
+    // Versions are per circuit.
+    client.store_vk(&admin, &1u32, &degenerate_vk(&env));
+    assert_eq!(client.get_vk_version(&1u32), 1);
+}
+
+#[test]
+fn matching_vk_version_verifies() {
+    let (env, client, admin) = setup_test();
+
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    let public_inputs: Vec<BytesN<32>> = vec![&env];
+    assert!(client.verify_groth16(&0u32, &valid_proof(&env), &public_inputs, &1u32));
+}
+
+#[test]
+fn stale_vk_version_is_rejected_before_pairing() {
+    let (env, client, admin) = setup_test();
+
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    let public_inputs: Vec<BytesN<32>> = vec![&env];
+
+    // The proof itself is valid; only the version is stale.
+    let res = client.try_verify_groth16(&0u32, &valid_proof(&env), &public_inputs, &1u32);
+    assert_eq!(res, Err(Ok(VerifierError::VkVersionMismatch)));
+    assert!(client.verify_groth16(&0u32, &valid_proof(&env), &public_inputs, &2u32));
+}
+
+// ============================================================================
+// VK validation
+// ============================================================================
+
+#[test]
+fn store_vk_rejects_off_curve_ic() {
+    let (env, client, admin) = setup_test();
+
+    // (1, 3) is not on y² = x³ + 3.
+    let mut bytes = [0u8; 64];
+    bytes[31] = 1;
+    bytes[63] = 3;
+    let mut vk = degenerate_vk(&env);
+    vk.ic = vec![&env, BytesN::from_array(&env, &bytes)];
+
+    let res = client.try_store_vk(&admin, &0u32, &vk);
+    assert_eq!(res, Err(Ok(VerifierError::InvalidVk)));
+    assert!(matches!(client.try_get_vk(&0u32), Err(Ok(VerifierError::VkNotFound))));
+}
+
+#[test]
+fn store_vk_rejects_off_curve_g2() {
+    let (env, client, admin) = setup_test();
+
+    let mut bytes = g2_generator(&env).to_array();
+    bytes[127] ^= 1;
+    let mut vk = degenerate_vk(&env);
+    vk.delta_g2 = BytesN::from_array(&env, &bytes);
+
+    let res = client.try_store_vk(&admin, &0u32, &vk);
+    assert_eq!(res, Err(Ok(VerifierError::InvalidVk)));
+}
+
+// ============================================================================
+// vk_x debugging helper
+// ============================================================================
+
+#[test]
+fn compute_vk_x_without_inputs_is_ic0() {
+    let (env, client, admin) = setup_test();
+
+    let mut vk = degenerate_vk(&env);
+    vk.ic = vec![&env, g1_generator(&env)];
+    client.store_vk(&admin, &0u32, &vk);
+
+    assert_eq!(client.compute_vk_x(&0u32, &Vec::new(&env)), g1_generator(&env));
+
+    let res = client.try_compute_vk_x(&0u32, &vec![&env, BytesN::from_array(&env, &[0u8; 32])]);
+    assert_eq!(res, Err(Ok(VerifierError::PublicInputCountMismatch)));
+}
+
+// ============================================================================
+// Contiguous public-input blobs
+// ============================================================================
+
+#[test]
+fn aligned_blob_verifies_like_vector() {
+    let (env, client, admin) = setup_test();
+
+    // IC = [O, G1]: one public input, accepted only when it is zero.
+    let mut vk = degenerate_vk(&env);
+    vk.ic = vec![&env, g1_zero(&env), g1_generator(&env)];
+    client.store_vk(&admin, &0u32, &vk);
+    let proof = valid_proof(&env);
+
+    assert!(client.verify_groth16_blob(&0u32, &proof, &Bytes::from_array(&env, &[0u8; 32])));
+
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    assert!(!client.verify_groth16_blob(&0u32, &proof, &Bytes::from_array(&env, &one)));
+
+    // Two fields for a one-input VK.
+    let res = client.try_verify_groth16_blob(&0u32, &proof, &Bytes::from_array(&env, &[0u8; 64]));
+    assert_eq!(res, Err(Ok(VerifierError::PublicInputCountMismatch)));
+}
+
+#[test]
+fn misaligned_blob_is_rejected() {
+    let (env, client, admin) = setup_test();
+
+    client.store_vk(&admin, &0u32, &degenerate_vk(&env));
+    let proof = valid_proof(&env);
+
+    let res = client.try_verify_groth16_blob(&0u32, &proof, &Bytes::from_array(&env, &[0u8; 33]));
+    assert_eq!(res, Err(Ok(VerifierError::MisalignedInputs)));
+    // An empty blob is zero inputs, which the degenerate VK accepts.
+    assert!(client.verify_groth16_blob(&0u32, &proof, &Bytes::new(&env)));
+}
@@ -1333,6 +1333,8 @@ export function ProofOfLifeGame(props: {
 
             if (devModeSessionRef.current) {
               try {
+                // Needs the admin plus both players: the dispatcher signs with this wallet,
+                // a different assassin only when its dev key is configured.
                 const r2 = await bootstrapBackend.setInsecureMode({ sessionId: sid, enabled: true });
                 setChainLog((l2) => appendChainLog(l2, { ts: Date.now(), level: 'INFO', msg: `TX ${r2.txHash ?? 'UNKNOWN'} ok set_insecure_mode=true (DEV MODE)` }));
               } catch (e2) {
@@ -2259,6 +2261,7 @@ export function ProofOfLifeGame(props: {
                 x_new: step.x,
                 y_new: step.y,
                 salt_new: salt0,
+                steps_claimed: i + 1,
                 session_id: sessionId0,
                 turn: turn0,
               }));
@@ -2346,6 +2349,7 @@ export function ProofOfLifeGame(props: {
                 x_new: step.x,
                 y_new: step.y,
                 salt_new: salt0,
+                steps_claimed: i + 1,
                 session_id: sessionId0,
                 turn: turn0,
               }));
@@ -3008,6 +3012,7 @@ export function ProofOfLifeGame(props: {
           x_new: step.x,
           y_new: step.y,
           salt_new: s0.salt,
+          steps_claimed: i + 1,
           session_id: sessionId0,
           turn: turn0,
         }));
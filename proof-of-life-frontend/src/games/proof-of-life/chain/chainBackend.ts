@@ -12,7 +12,9 @@ import type { ChainConfig } from './config';
 import type { ContractSigner } from '@/types/signer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { TxQueue } from './txQueue';
-import { Keypair, TransactionBuilder } from '@stellar/stellar-sdk';
+import { Keypair, TransactionBuilder, hash } from '@stellar/stellar-sdk';
+import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
+import { DEFAULT_AUTH_TTL_MINUTES } from '@/utils/constants';
 
 import { Client as ProofOfLifeClient, Role as ChainRole, type Session as ChainSession } from '@/bindings/proof_of_life';
 
@@ -59,12 +61,14 @@ export type SessionKeyScopeView = {
 export class ChainBackend implements Backend {
   private config: ChainConfig;
   private client: ProofOfLifeClient;
+  private signer: ContractSigner;
   private txq = new TxQueue();
   /** When using a session key, this is the delegate's public key (used as the actor in auth calls). */
   readonly actorPublicKey: string;
 
   constructor(config: ChainConfig, signer: ContractSigner, publicKey: string) {
     this.config = config;
+    this.signer = signer;
     this.actorPublicKey = publicKey;
 
     if (!publicKey) {
@@ -178,7 +182,7 @@ export class ChainBackend implements Backend {
         })
       );
     }
-    // Enable insecure_mode when requested (requires admin auth, same as setInsecureMode).
+    // Enable insecure_mode when requested. The contract requires the admin and both players to sign.
     if (params.insecureMode) {
       await this.setInsecureMode({ sessionId: params.sessionId, enabled: true });
     }
@@ -260,6 +264,18 @@ export class ChainBackend implements Backend {
             session_id: params.sessionId >>> 0,
             enabled: params.enabled,
           });
+          // Enabling also needs the dispatcher's and assassin's auth entries.
+          const pending = await tx.needsNonInvokerSigningBy();
+          if (pending.length > 0) {
+            const expiration = await calculateValidUntilLedger(this.config.rpcUrl, DEFAULT_AUTH_TTL_MINUTES);
+            for (const address of pending) {
+              await tx.signAuthEntries({
+                address,
+                signAuthEntry: this.playerAuthSigner(address),
+                expiration,
+              });
+            }
+          }
           const sent = await tx.signAndSend();
           await ensureSentTransactionSucceeded(sent);
           const hash = await tryExtractTxHash(sent);
@@ -272,7 +288,8 @@ export class ChainBackend implements Backend {
       }
     }
 
-    // Fallback for production or missing admin secret (likely to fail auth if not admin)
+    // Fallback for production or missing admin secret (likely to fail auth if not admin).
+    // Enabling this way also needs the other player's auth, so it only works solo.
     const res = await this.write(() =>
       (this.client as any).set_insecure_mode({
         session_id: params.sessionId >>> 0,
@@ -282,6 +299,26 @@ export class ChainBackend implements Backend {
     return { success: true, txHash: res.txHash };
   }
 
+  /**
+   * Auth-entry signer for a session player: the connected wallet, or a dev
+   * player key from the environment. Other players cannot sign from here.
+   */
+  private playerAuthSigner(address: string): ContractSigner['signAuthEntry'] {
+    if (address === this.actorPublicKey) return this.signer.signAuthEntry;
+    for (const raw of [import.meta.env.VITE_DEV_PLAYER1_SECRET, import.meta.env.VITE_DEV_PLAYER2_SECRET]) {
+      const secret = String(raw ?? '').trim();
+      if (!secret.startsWith('S')) continue;
+      const kp = Keypair.fromSecret(secret);
+      if (kp.publicKey() !== address) continue;
+      // `authorizeEntry` expects a signature over the hash of the preimage.
+      return async (preimageXdr: string) => ({
+        signedAuthEntry: kp.sign(hash(Buffer.from(preimageXdr, 'base64'))).toString('base64'),
+        signerAddress: address,
+      });
+    }
+    throw new Error(`set_insecure_mode needs auth from ${address}, but no signer is available for it`);
+  }
+
   async getSession(sessionId: number): Promise<SessionState> {
     const tx = await this.client.get_session({ session_id: sessionId >>> 0 });
     const sim = await tx.simulate();
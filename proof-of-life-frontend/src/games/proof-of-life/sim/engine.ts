@@ -671,7 +671,7 @@ function applyChadCommandRoomLevel(
         case 'D': return { pos: { x: 8, y: 6 }, hidden: false, note: "I'm moving into the dining room now." };
         case 'K': return { pos: { x: 8, y: 8 }, hidden: false, note: "I'm moving into the kitchen now." };
         case 'E': return { pos: { x: 4, y: 8 }, hidden: false, note: "I'm moving into the grand hall now." };
-        default: return { pos: { x: 4, y: 5 }, hidden: false, note: "I'm moving into the hallway now." };
+        default: return { pos: { x: 4, y: 6 }, hidden: false, note: "I'm moving into the hallway now." };
       }
     }
     case 'STAY':
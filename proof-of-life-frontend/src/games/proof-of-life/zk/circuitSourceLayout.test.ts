@@ -49,10 +49,10 @@ describe('Noir circuit source layout (v3 public field ordering)', () => {
     expect(sig.pubOutputCount).toBe(2);
   });
 
-  test('move_proof pub inputs are (session_id, turn) and has 2 pub outputs', () => {
+  test('move_proof pub inputs are (session_id, turn) and has 5 pub outputs', () => {
     const noir = readCircuitMain('move_proof');
     const sig = parseNoirMainSignature(noir);
     expect(sig.pubInputs).toEqual(['session_id', 'turn']);
-    expect(sig.pubOutputCount).toBe(2);
+    expect(sig.pubOutputCount).toBe(5);
   });
 });
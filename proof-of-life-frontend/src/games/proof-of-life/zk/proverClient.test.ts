@@ -34,16 +34,21 @@ describe('zk/proverClient public inputs handling', () => {
     (c as any).post = async () => ({
       circuit: 'move_proof',
       proof_hex: '00',
-      // [session_id, turn, commitment_old, commitment_new]
-      public_inputs_fields: [u32FieldHex(sessionId), u32FieldHex(9), '0x' + '22'.repeat(32), '0x' + '33'.repeat(32)],
+      // [session_id, turn, commitment_old, commitment_new, floorplan_slot, steps_claimed, step_distance]
+      public_inputs_fields: [
+        u32FieldHex(sessionId), u32FieldHex(9), '0x' + '22'.repeat(32), '0x' + '33'.repeat(32),
+        u32FieldHex(0), u32FieldHex(1), u32FieldHex(1),
+      ],
     });
     const out = await c.moveProof({
-      x_old: 1, y_old: 1, salt_old: 1, x_new: 2, y_new: 2, salt_new: 2, session_id: sessionId, turn: 9,
+      x_old: 1, y_old: 1, salt_old: 1, x_new: 2, y_new: 1, salt_new: 2, steps_claimed: 1, session_id: sessionId, turn: 9,
     });
     expect(out.commitmentOld).toBe('0x' + '22'.repeat(32));
     expect(out.commitmentNew).toBe('0x' + '33'.repeat(32));
-    // [session_id, turn, commitment_old, commitment_new]
-    expect(out.publicInputs).toEqual([u32FieldHex(sessionId), u32FieldHex(9), '0x' + '22'.repeat(32), '0x' + '33'.repeat(32)]);
+    expect(out.publicInputs).toEqual([
+      u32FieldHex(sessionId), u32FieldHex(9), '0x' + '22'.repeat(32), '0x' + '33'.repeat(32),
+      u32FieldHex(0), u32FieldHex(1), u32FieldHex(1),
+    ]);
   });
 
   it('preserves turn_status beta9 layout (V2)', async () => {
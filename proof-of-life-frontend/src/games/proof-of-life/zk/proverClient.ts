@@ -27,6 +27,8 @@ export type MoveProofReq = {
   x_new: number;
   y_new: number;
   salt_new: number;
+  // Moves this turn including this step.
+  steps_claimed: number;
   session_id: number;
   turn: number;
 };
@@ -99,9 +101,9 @@ function parseTurnStatusLayout(raw: string[], req: TurnStatusReq): { commitment:
   };
 }
 
-// Contract checks: [session_id, turn, commitment_old, commitment_new]
+// Contract checks: [session_id, turn, commitment_old, commitment_new, floorplan_slot, steps_claimed, step_distance]
 function parseMoveLayout(raw: string[], req: MoveProofReq): { commitmentOld: string; commitmentNew: string; normalized: string[] } {
-  ensureLen(raw, 4, 'move_proof');
+  ensureLen(raw, 7, 'move_proof');
 
   const sidField = fieldToU32(raw[0]!);
   const turnField = fieldToU32(raw[1]!);
@@ -83,6 +83,19 @@ function toBitset(tiles: { x: number; y: number }[]): number[] {
   return bitset;
 }
 
+// Fallback spawn tiles used by the contract's GoRoom when no door connects the rooms.
+// Must stay in sync with the sim engine's GO_* fallback.
+const ROOM_SPAWNS: { room: string; x: number; y: number }[] = [
+  { room: 'G', x: 5, y: 1 },
+  { room: 'H', x: 4, y: 6 },
+  { room: 'L', x: 1, y: 4 },
+  { room: 'S', x: 8, y: 4 },
+  { room: 'B', x: 1, y: 7 },
+  { room: 'D', x: 8, y: 6 },
+  { room: 'K', x: 8, y: 8 },
+  { room: 'E', x: 4, y: 8 },
+];
+
 const blockedBitset = toBitset(blockedTiles);
 const hideBitset = toBitset(hideTiles);
 
@@ -106,9 +119,16 @@ const rustCode = `//! Floorplan constants for Proof of Life
 pub const BOARD_W: u32 = ${BOARD_W};
 pub const BOARD_H: u32 = ${BOARD_H};
 
+/// Grid dimensions used for coordinate validation (same as the board).
+pub const GRID_W: u32 = BOARD_W;
+pub const GRID_H: u32 = BOARD_H;
+
 /// Checksum of floorplan data (for sync verification)
 pub const FLOORPLAN_CHECKSUM: &str = "${checksum}";
 
+/// Checksum as a number; wall-aware move proofs expose it as a public input.
+pub const FLOORPLAN_ID: u32 = 0x${checksum};
+
 /// Blocked tiles bitset (10 rows, each u32 represents a row with bits for columns)
 /// Bit N in row Y is set if tile (N, Y) is blocked
 pub const BLOCKED_TILES: [u32; ${BOARD_H}] = [
@@ -127,6 +147,12 @@ pub const ROOM_GRID: [[u8; ${BOARD_W}]; ${BOARD_H}] = [
 ${roomGrid.map(row => `    [${row.split('').map(c => `b'${c}'`).join(', ')}],`).join('\n')}
 ];
 
+/// Fallback spawn tile per room (room code, x, y), used when no door connects to the target room.
+/// Every entry is a walkable tile inside its room. Winter (W) is sealed and has no spawn.
+pub const ROOM_SPAWNS: [(u8, u32, u32); ${ROOM_SPAWNS.length}] = [
+${ROOM_SPAWNS.map(r => `    (b'${r.room}', ${r.x}, ${r.y}),`).join('\n')}
+];
+
 /// Open doors (edges that connect rooms)
 pub const DOORS_OPEN: [(u32, u32, u32, u32); ${doorsOpen.length}] = [
 ${doorsOpen.map(d => `    (${d.ax}, ${d.ay}, ${d.bx}, ${d.by}),`).join('\n')}
@@ -134,7 +160,12 @@ ${doorsOpen.map(d => `    (${d.ax}, ${d.ay}, ${d.bx}, ${d.by}),`).join('\n')}
 
 /// Check if coordinates are in bounds
 pub fn in_bounds(x: u32, y: u32) -> bool {
-    x < BOARD_W && y < BOARD_H
+    x < GRID_W && y < GRID_H
+}
+
+/// Largest squared distance between any two tiles on the board
+pub fn max_dist_sq() -> u32 {
+    (BOARD_W - 1) * (BOARD_W - 1) + (BOARD_H - 1) * (BOARD_H - 1)
 }
 
 /// Check if a tile is blocked (inaccessible)
@@ -191,10 +222,69 @@ pub fn get_room_code(x: u32, y: u32) -> u8 {
     ROOM_GRID[y as usize][x as usize]
 }
 
+/// Get the fallback spawn tile for a room code
+pub fn room_spawn(code: u8) -> Option<(u32, u32)> {
+    for &(room, x, y) in &ROOM_SPAWNS {
+        if room == code {
+            return Some((x, y));
+        }
+    }
+    None
+}
+
+/// Check if door N of DOORS_OPEN is closed in a `closed_doors` bitset (bit N set)
+pub fn is_door_closed(closed_doors: u32, index: usize) -> bool {
+    index < 32 && (closed_doors >> index) & 1 == 1
+}
+
+/// Fewest open doors crossed to get from one room to another, or `None` if no
+/// chain of doors connects them (e.g. the sealed Winter garden). Doors in the
+/// `closed_doors` bitset count as walls.
+pub fn room_distance(from_room: u8, to_room: u8, closed_doors: u32) -> Option<u32> {
+    if !from_room.is_ascii_uppercase() || !to_room.is_ascii_uppercase() {
+        return None;
+    }
+    if from_room == to_room {
+        return Some(0);
+    }
+    // Room codes are capital letters, so a u32 bitmask holds a set of rooms.
+    let bit = |code: u8| 1u32 << (code - b'A');
+    let mut visited = bit(from_room);
+    let mut frontier = visited;
+    let mut dist = 0;
+    while frontier != 0 {
+        dist += 1;
+        let mut next = 0u32;
+        for (i, &(ax, ay, bx, by)) in DOORS_OPEN.iter().enumerate() {
+            if is_door_closed(closed_doors, i) {
+                continue;
+            }
+            let a = get_room_code(ax, ay);
+            let b = get_room_code(bx, by);
+            if frontier & bit(a) != 0 {
+                next |= bit(b);
+            }
+            if frontier & bit(b) != 0 {
+                next |= bit(a);
+            }
+        }
+        next &= !visited;
+        if next & bit(to_room) != 0 {
+            return Some(dist);
+        }
+        visited |= next;
+        frontier = next;
+    }
+    None
+}
+
 /// Check if two adjacent cells are connected (no wall between them)
-fn are_connected(ax: u32, ay: u32, bx: u32, by: u32) -> bool {
-    // Check if there's a door connecting these cells
-    for &(dx1, dy1, dx2, dy2) in &DOORS_OPEN {
+fn are_connected(ax: u32, ay: u32, bx: u32, by: u32, closed_doors: u32) -> bool {
+    // Check if there's an open door connecting these cells
+    for (i, &(dx1, dy1, dx2, dy2)) in DOORS_OPEN.iter().enumerate() {
+        if is_door_closed(closed_doors, i) {
+            continue;
+        }
         if (dx1 == ax && dy1 == ay && dx2 == bx && dy2 == by)
             || (dx1 == bx && dy1 == by && dx2 == ax && dy2 == ay)
         {
@@ -214,8 +304,8 @@ fn are_connected(ax: u32, ay: u32, bx: u32, by: u32) -> bool {
     false
 }
 
-/// Check if 4-directional movement is valid
-pub fn can_move4(ax: u32, ay: u32, bx: u32, by: u32) -> bool {
+/// Check if 4-directional movement is valid; doors in `closed_doors` are walls
+pub fn can_move4(ax: u32, ay: u32, bx: u32, by: u32, closed_doors: u32) -> bool {
     // Must be in bounds
     if !in_bounds(ax, ay) || !in_bounds(bx, by) {
         return false;
@@ -234,7 +324,7 @@ pub fn can_move4(ax: u32, ay: u32, bx: u32, by: u32) -> bool {
     }
     
     // Must be connected (no wall or has door)
-    are_connected(ax, ay, bx, by)
+    are_connected(ax, ay, bx, by, closed_doors)
 }
 `;
 
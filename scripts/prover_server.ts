@@ -50,6 +50,7 @@ type MoveReq = {
   x_new: number;
   y_new: number;
   salt_new: number;
+  steps_claimed: number;
   session_id: number;
   turn: number;
 };
@@ -161,6 +162,7 @@ function proverTomlForCircuit(name: CircuitName, req: any): string {
         `x_new = "${r.x_new}"`,
         `y_new = "${r.y_new}"`,
         `salt_new = "${r.salt_new}"`,
+        `steps_claimed = "${r.steps_claimed}"`,
         `session_id = "${r.session_id}"`,
         `turn = "${r.turn}"`,
         '',